@@ -0,0 +1,278 @@
+use super::komodo::AddressParams;
+use super::komodorpcutil::{self, KomodoClient, KomodoError, KomodoRPC};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/**
+ * ChainRegistry holds one KomodoClient per Komodo chain, keyed by the chain
+ * name (KMD, KENNYCOIN, ...), so that one app can talk to the daemons of
+ * several smart chains. Chain names are matched without regard to case.
+ * Each chain also has the AddressParams its addresses are parsed with;
+ * they are those of KMD unless set_address_params says otherwise.
+ *
+ * let chains = ChainRegistry::discover();
+ * let balance = chains.chain("KENNYCOIN")?.wallet().get_balance(None, None)?;
+ * let address = Address::parse(text, chains.select_address_params(Some("KENNYCOIN"))?)?;
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ChainRegistry {
+    clients: BTreeMap<String, KomodoClient>,
+    address_params: BTreeMap<String, AddressParams>,
+    default_chain: Option<String>,
+}
+
+impl ChainRegistry {
+    /**
+     * The new method of ChainRegistry creates a registry without any chain.
+     */
+    pub fn new() -> ChainRegistry {
+        ChainRegistry::default()
+    }
+
+    /**
+     *Function Name: discover
+     *@params: none
+     *Output: a registry with every chain found in the komodod data directory
+     *           of the current user (see komodorpcutil::komodo_data_dir)
+     */
+    pub fn discover() -> ChainRegistry {
+        match komodorpcutil::komodo_data_dir() {
+            Some(data_dir) => ChainRegistry::discover_in(&data_dir),
+            None => ChainRegistry::new(),
+        }
+    }
+
+    /**
+     *Function Name: discover_in
+     *@params: data_dir - a komodod data directory, e.g. ~/.komodo
+     *Output: a registry with KMD when data_dir/komodo.conf exists and every smart chain
+     *           that has a data_dir/<CHAIN>/<CHAIN>.conf file. Files without rpcuser and
+     *           rpcpassword are skipped, as the daemon could not be called anyway.
+     */
+    pub fn discover_in(data_dir: &Path) -> ChainRegistry {
+        let mut registry = ChainRegistry::new();
+        if let Ok(rpc) = KomodoRPC::from_conf(data_dir.join("komodo.conf")) {
+            registry.insert("KMD", rpc);
+        }
+        let entries = match fs::read_dir(data_dir) {
+            Ok(entries) => entries,
+            Err(_) => return registry,
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let chain = entry.file_name().to_string_lossy().into_owned();
+            let conf = entry.path().join(format!("{}.conf", chain));
+            if let Ok(rpc) = KomodoRPC::from_conf(conf) {
+                registry.insert(&chain, rpc);
+            }
+        }
+        registry
+    }
+
+    /**
+    *Function Name: insert
+    *@params: chain - the name of the chain
+                rpc - the configuration of the chain's daemon
+    *Output: none; a client for the chain replaces any previous one.
+    *           The first chain inserted becomes the default chain.
+    *           The chain's address parameters are kept, or are those of KMD for a new chain.
+    */
+    pub fn insert(&mut self, chain: &str, rpc: KomodoRPC) {
+        let key = chain.to_uppercase();
+        if self.default_chain.is_none() {
+            self.default_chain = Some(key.clone());
+        }
+        self.address_params
+            .entry(key.clone())
+            .or_insert(AddressParams::KOMODO);
+        self.clients.insert(key, KomodoClient::new(rpc));
+    }
+
+    /**
+    *Function Name: set_address_params
+    *@params: chain - the name of a registered chain
+                params - the version bytes and Sapling prefix of the chain's addresses
+    *Output: none; addresses of the chain are parsed with params from now on
+    *           KomodoError::UnknownChain - when the chain is not registered
+    */
+    pub fn set_address_params(
+        &mut self,
+        chain: &str,
+        params: AddressParams,
+    ) -> Result<(), KomodoError> {
+        let key = chain.to_uppercase();
+        if !self.clients.contains_key(&key) {
+            return Err(KomodoError::UnknownChain(chain.to_string()));
+        }
+        self.address_params.insert(key, params);
+        Ok(())
+    }
+
+    /**
+     *Function Name: set_default
+     *@params: chain - the name of a registered chain
+     *Output: none; select(None) returns this chain from now on
+     *           KomodoError::UnknownChain - when the chain is not registered
+     */
+    pub fn set_default(&mut self, chain: &str) -> Result<(), KomodoError> {
+        let key = chain.to_uppercase();
+        if !self.clients.contains_key(&key) {
+            return Err(KomodoError::UnknownChain(chain.to_string()));
+        }
+        self.default_chain = Some(key);
+        Ok(())
+    }
+
+    /**
+     *Function Name: default_chain
+     *@params: the instance of the struct ChainRegistry
+     *Output: the name of the default chain, if any chain is registered
+     */
+    pub fn default_chain(&self) -> Option<&str> {
+        self.default_chain.as_deref()
+    }
+
+    /**
+     *Function Name: chains
+     *@params: the instance of the struct ChainRegistry
+     *Output: the names of all registered chains in alphabetical order
+     */
+    pub fn chains(&self) -> Vec<&str> {
+        self.clients.keys().map(String::as_str).collect()
+    }
+
+    /**
+     *Function Name: get
+     *@params: chain - the name of the chain
+     *Output: the client of the chain, or None when it is not registered
+     */
+    pub fn get(&self, chain: &str) -> Option<&KomodoClient> {
+        self.clients.get(&chain.to_uppercase())
+    }
+
+    /**
+     *Function Name: chain
+     *@params: chain - the name of the chain
+     *Output: the client of the chain
+     *           KomodoError::UnknownChain - when the chain is not registered
+     */
+    pub fn chain(&self, chain: &str) -> Result<&KomodoClient, KomodoError> {
+        self.get(chain)
+            .ok_or_else(|| KomodoError::UnknownChain(chain.to_string()))
+    }
+
+    /**
+     *Function Name: select
+     *@params: chain - the name of the chain, or None for the default chain
+     *Output: the client of the chain
+     *           KomodoError::UnknownChain - when the chain is not registered
+     */
+    pub fn select(&self, chain: Option<&str>) -> Result<&KomodoClient, KomodoError> {
+        match chain.or_else(|| self.default_chain()) {
+            Some(chain) => self.chain(chain),
+            None => Err(KomodoError::UnknownChain(String::new())),
+        }
+    }
+
+    /**
+     *Function Name: select_address_params
+     *@params: chain - the name of the chain, or None for the default chain
+     *Output: the parameters the chain's addresses are parsed with
+     *           KomodoError::UnknownChain - when the chain is not registered
+     */
+    pub fn select_address_params(
+        &self,
+        chain: Option<&str>,
+    ) -> Result<&AddressParams, KomodoError> {
+        let chain = match chain.or_else(|| self.default_chain()) {
+            Some(chain) => chain,
+            None => return Err(KomodoError::UnknownChain(String::new())),
+        };
+        self.address_params
+            .get(&chain.to_uppercase())
+            .ok_or_else(|| KomodoError::UnknownChain(chain.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::path::PathBuf;
+
+    const CONF: &str = "rpcuser=user\nrpcpassword=secret\n";
+
+    // A data directory with a KMD conf and a KENNYCOIN conf; each test uses its own name.
+    fn data_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("chainregistry-{}-{}", std::process::id(), name));
+        fs::create_dir_all(dir.join("KENNYCOIN")).unwrap();
+        fs::create_dir_all(dir.join("blocks")).unwrap();
+        fs::write(dir.join("komodo.conf"), format!("{}rpcport=7771\n", CONF)).unwrap();
+        fs::write(
+            dir.join("KENNYCOIN").join("KENNYCOIN.conf"),
+            format!("{}rpcport=51234\n", CONF),
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn discover_in_finds_kmd_and_the_smart_chains() {
+        let dir = data_dir("discover");
+        let chains = ChainRegistry::discover_in(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(chains.chains(), vec!["KENNYCOIN", "KMD"]);
+        assert_eq!(chains.default_chain(), Some("KMD"));
+        assert_eq!(
+            chains
+                .chain("KENNYCOIN")
+                .unwrap()
+                .get_rpc()
+                .get_request_url(),
+            "http://127.0.0.1:51234/"
+        );
+    }
+
+    #[test]
+    fn select_ignores_case_and_falls_back_to_the_default() {
+        let mut chains = ChainRegistry::new();
+        assert!(chains.select(None).is_err());
+        chains.insert("kmd", KomodoRPC::default());
+        chains.insert("KennyCoin", KomodoRPC::default());
+        assert_eq!(chains.default_chain(), Some("KMD"));
+        chains.set_default("kennycoin").unwrap();
+        assert_eq!(chains.default_chain(), Some("KENNYCOIN"));
+        assert!(chains.select(Some("Kmd")).is_ok());
+        assert!(chains.select(None).is_ok());
+        match chains.select(Some("DOGE")) {
+            Err(KomodoError::UnknownChain(chain)) => assert_eq!(chain, "DOGE"),
+            other => panic!("expected an unknown chain, got {:?}", other),
+        }
+        assert!(chains.set_default("DOGE").is_err());
+    }
+
+    #[test]
+    fn address_params_are_kept_per_chain() {
+        let mut chains = ChainRegistry::new();
+        chains.insert("KMD", KomodoRPC::default());
+        chains.insert("BTCFORK", KomodoRPC::default());
+        let params = AddressParams {
+            pubkey_prefix: 0,
+            script_prefix: 5,
+            ..AddressParams::KOMODO
+        };
+        chains.set_address_params("btcfork", params).unwrap();
+        // inserting the chain again keeps its params
+        chains.insert("BTCFORK", KomodoRPC::default());
+        assert_eq!(
+            chains.select_address_params(Some("BTCFORK")).unwrap(),
+            &params
+        );
+        assert_eq!(
+            chains.select_address_params(None).unwrap(),
+            &AddressParams::KOMODO
+        );
+        assert!(chains.select_address_params(Some("DOGE")).is_err());
+        assert!(chains.set_address_params("DOGE", params).is_err());
+    }
+}
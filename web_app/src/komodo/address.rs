@@ -1,338 +1,310 @@
 #![allow(warnings)]
 use super::komodorpcutil;
-use komodorpcutil::KomodoRPC;
-
-/*getaddressbalance
-getaddressbalance '{ "addresses" : [ "address" , ... ] }'
-
-The getaddressbalance method returns the confirmed balance for an address, or addresses. It requires addressindex to be enabled.
-
-#Arguments
-Name	     Type      	Description
-"address"	(string)	the address
-
-# Response
-Name	    Type	     Description
-"balance"	(number)	the current confirmed balance in satoshis
-"received"	(number)	the total confirmed number of satoshis received (including change)
-*/
-pub fn get_address_balance(
-    someUser: komodorpcutil::KomodoRPC,
-    v_address: Vec<String>,
-) -> Result<String, reqwest::Error> {
-    // implement for vec of addrs
-    let mut addr_list = String::from("[");
-    for addr in &v_address {
-        addr_list = addr_list + "\"" + addr + "\"" + &","; //parsing error
-    }
-
-    if (v_address.len() > 0)
-    // >1
-    {
-        addr_list = addr_list[0..(addr_list.len() - 1)].to_string(); // cutting the last ,
-    }
-
-    addr_list = addr_list + &"]"; //& for String -> &string
-
-    let params = String::from("[{\"addresses\": ") + &addr_list + "}]";
-
-    let method_name: String = String::from("getaddressbalance");
-    let method_body: String = String::from(params);
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(someUser.clone(), data);
-    return result;
-}
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 /*
-
-getaddressdeltas
-getaddressdeltas '{ "addresses" : [ "address" , ... ] }'
-
-getaddressdeltas '{ "addresses" : [ "address" , ... ] , "start": start, "end": end, "chainInfo": boolean }'
-
-The getaddressdeltas method returns all confirmed balance changes of an address. The user can optionally limit the response to a given interval of blocks. The method requires addressindex to be enabled.
-
-Arguments
-Name	    Type	    Description
-"address"	(string)	the address
-"start"  	(number)	the start block height
-"end"	    (number)	the end block height
-"chainInfo"	(boolean)	include chain info in results (only applies if start and end specified)
-
-# Response
-Name	    Type	    Description
-"satoshis"	(number)	the difference in satoshis
-"txid"	(string)	the related transaction id
-"index"	(number)	the related input or output index
-"height"	(number)	the block height
-"address"	(string)	the address
-
+The addressindex methods share one json object as their only parameter:
+{ "addresses" : [ "address" , ... ], "start": start, "end": end, "chainInfo": boolean }
+Fields that are None are left out of the request.
 */
-pub fn get_address_deltas(
-    someUser: komodorpcutil::KomodoRPC,
-    v_address: Vec<String>,
-    start: u32,
-    end: u32,
-    chainInfo: bool,
-) -> Result<String, reqwest::Error> {
-    let mut addr_list = String::from("[");
-
-    for addr in &v_address {
-        addr_list = addr_list + "\"" + addr + "\"" + &","; //parsing error
-    }
+#[derive(Serialize)]
+struct AddressQuery {
+    addresses: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<u32>,
+    #[serde(rename = "chainInfo", skip_serializing_if = "Option::is_none")]
+    chain_info: Option<bool>,
+}
 
-    if (v_address.len() > 0)
-    // >1
-    {
-        // why need to cut last substring
-        addr_list = addr_list[0..(addr_list.len() - 1)].to_string();
-    }
+/// One entry of the array returned by getaddressutxos when chainInfo is false.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddressUtxo {
+    /// the address
+    pub address: String,
+    /// the output txid
+    pub txid: String,
+    /// the output index
+    #[serde(rename = "outputIndex")]
+    pub output_index: u32,
+    /// the script, hex encoded
+    pub script: String,
+    /// the value of the output, in satoshis
+    pub satoshis: i64,
+    /// the block height of the output
+    pub height: u64,
+}
 
-    addr_list = addr_list + &"]"; //& for String -> &string
-
-    let params = String::from("[{\"addresses\": ")
-        + &addr_list
-        + ",\"start\":"
-        + &start.to_string()
-        + ",\"end\":"
-        + &end.to_string()
-        + ",\"chainInfo\":"
-        + &chainInfo.to_string()
-        + "}]";
-
-    let method_name: String = String::from("getaddressdeltas");
-    let method_body: String = String::from(params);
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(someUser.clone(), data);
-    return result;
+/// The result of getaddressutxos when chainInfo is true: the outputs and the tip they were read at.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AddressUtxosWithChainInfo {
+    /// the unspent outputs
+    pub utxos: Vec<AddressUtxo>,
+    /// the hash of the tip of the chain
+    pub hash: String,
+    /// the height of the tip of the chain
+    pub height: u64,
 }
 
-/*
-getaddressmempool '{ "addresses" : [ "address" , ... ] }'
+/// Calls of the address index API, borrowed from a KomodoClient with `client.address_index()`.
+pub struct AddressIndex<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
 
-The getaddressmempool method returns all mempool deltas for an address, or addresses. The method requires addressindex to be enabled.
+impl<'a, C> AddressIndex<'a, C> {
+    /*getaddressbalance
+    getaddressbalance '{ "addresses" : [ "address" , ... ] }'
 
-# Arguments
-Name	    Type	    Description
-"address"	(string)	the address
+    The getaddressbalance method returns the confirmed balance for an address, or addresses. It requires addressindex to be enabled.
 
-# Response
-Name	    Type     	Description
-"address"	(string)	the address
-"txid"	    (string)	the related txid
-"index"	    (number)	the related input or output index
-"satoshis"	(number)	the difference in satoshis
-"timestamp"	(number)	the time the transaction entered the mempool (seconds)
-"prevtxid"	(string)	the previous txid (if spending)
-"prevout"	(string)	the previous transaction output index (if spending)
+    #Arguments
+    Name	     Type      	Description
+    "address"	(string)	the address
 
-*/
-pub fn get_address_mem_pool(
-    someUser: komodorpcutil::KomodoRPC,
-    v_address: Vec<String>,
-) -> Result<String, reqwest::Error> {
-    let mut addr_list = String::from("[");
-
-    for addr in &v_address {
-        addr_list = addr_list + "\"" + addr + "\"" + &","; //parsing error
+    # Response
+    Name	    Type	     Description
+    "balance"	(number)	the current confirmed balance in satoshis
+    "received"	(number)	the total confirmed number of satoshis received (including change)
+    */
+    pub fn get_address_balance(&self, v_address: Vec<String>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getaddressbalance");
+        let method_body = vec![json!(AddressQuery {
+            addresses: v_address,
+            start: None,
+            end: None,
+            chain_info: None,
+        })];
+        self.client.call(&method_name, method_body)
     }
 
-    if (v_address.len() > 0)
-    // >1
+    /*
+
+    getaddressdeltas
+    getaddressdeltas '{ "addresses" : [ "address" , ... ] }'
+
+    getaddressdeltas '{ "addresses" : [ "address" , ... ] , "start": start, "end": end, "chainInfo": boolean }'
+
+    The getaddressdeltas method returns all confirmed balance changes of an address. The user can optionally limit the response to a given interval of blocks. The method requires addressindex to be enabled.
+
+    Arguments
+    Name	    Type	    Description
+    "address"	(string)	the address
+    "start"  	(number)	the start block height
+    "end"	    (number)	the end block height
+    "chainInfo"	(boolean)	include chain info in results (only applies if start and end specified)
+
+    # Response
+    Name	    Type	    Description
+    "satoshis"	(number)	the difference in satoshis
+    "txid"	(string)	the related transaction id
+    "index"	(number)	the related input or output index
+    "height"	(number)	the block height
+    "address"	(string)	the address
+
+    */
+    pub fn get_address_deltas(
+        &self,
+        v_address: Vec<String>,
+        start: u32,
+        end: u32,
+        chainInfo: bool,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
     {
-        // why need to cut last substring
-        addr_list = addr_list[0..(addr_list.len() - 1)].to_string();
+        let method_name: String = String::from("getaddressdeltas");
+        let method_body = vec![json!(AddressQuery {
+            addresses: v_address,
+            start: Some(start),
+            end: Some(end),
+            chain_info: Some(chainInfo),
+        })];
+        self.client.call(&method_name, method_body)
     }
 
-    addr_list = addr_list + &"]"; //& for String -> &string
-
-    let params = String::from("[{\"addresses\": ") + &addr_list + "}]";
-
-    let method_name: String = String::from("getaddressmempool");
-    let method_body: String = String::from(params);
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-
-    let result = komodorpcutil::request(someUser.clone(), data);
-    return result;
-}
-/*
-#getaddresstxids
-getaddresstxids '{ "addresses" : [ "address" , ... ] }'
-
-The getaddresstxids method returns the txids for an address, or addresses. It requires addressindex to be enabled.
-
-# Arguments
-Name	    Type	    Description
-"address"	(string)	the address
-"start"	    (number)	the start block height
-"end"	    (number)	the end block height
-
-# Response
-Name	            Type	    Description
-"transaction_id"	(string)	the transaction id
-
-
-*/
-pub fn get_address_tx_ids(
-    someUser: komodorpcutil::KomodoRPC,
-    v_address: Vec<String>,
-    start: u32,
-    end: u32,
-) -> Result<String, reqwest::Error> {
-    let mut addr_list = String::from("[");
-
-    for addr in &v_address {
-        addr_list = addr_list + "\"" + addr + "\"" + &","; //parsing error
+    /*
+    getaddressmempool '{ "addresses" : [ "address" , ... ] }'
+
+    The getaddressmempool method returns all mempool deltas for an address, or addresses. The method requires addressindex to be enabled.
+
+    # Arguments
+    Name	    Type	    Description
+    "address"	(string)	the address
+
+    # Response
+    Name	    Type     	Description
+    "address"	(string)	the address
+    "txid"	    (string)	the related txid
+    "index"	    (number)	the related input or output index
+    "satoshis"	(number)	the difference in satoshis
+    "timestamp"	(number)	the time the transaction entered the mempool (seconds)
+    "prevtxid"	(string)	the previous txid (if spending)
+    "prevout"	(string)	the previous transaction output index (if spending)
+
+    */
+    pub fn get_address_mem_pool(&self, v_address: Vec<String>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getaddressmempool");
+        let method_body = vec![json!(AddressQuery {
+            addresses: v_address,
+            start: None,
+            end: None,
+            chain_info: None,
+        })];
+        self.client.call(&method_name, method_body)
     }
-
-    if (v_address.len() > 0)
-    // >1
+    /*
+    #getaddresstxids
+    getaddresstxids '{ "addresses" : [ "address" , ... ] }'
+
+    The getaddresstxids method returns the txids for an address, or addresses. It requires addressindex to be enabled.
+
+    # Arguments
+    Name	    Type	    Description
+    "address"	(string)	the address
+    "start"	    (number)	the start block height
+    "end"	    (number)	the end block height
+
+    # Response
+    Name	            Type	    Description
+    "transaction_id"	(string)	the transaction id
+
+
+    */
+    pub fn get_address_tx_ids(
+        &self,
+        v_address: Vec<String>,
+        start: u32,
+        end: u32,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
     {
-        // why need to cut last substring
-        addr_list = addr_list[0..(addr_list.len() - 1)].to_string();
+        let method_name: String = String::from("getaddresstxids");
+        let method_body = vec![json!(AddressQuery {
+            addresses: v_address,
+            start: Some(start),
+            end: Some(end),
+            chain_info: None,
+        })];
+        self.client.call(&method_name, method_body)
     }
+    /*
+    #getaddressutxos
+    getaddressutxos '{ "addresses" : [ "address" , ... ], "chainInfo": false }'
 
-    addr_list = addr_list + &"]"; //& for String -> &string
-
-    let params = String::from("[{\"addresses\": ")
-        + &addr_list
-        + ",\"start\":"
-        + &start.to_string()
-        + ",\"end\":"
-        + &end.to_string()
-        + "}]";
-    //let params = format!("[{\"addresses\": {0}}]", addr_list); //need to fix to use format
-
-    let method_name: String = String::from("getaddresstxids");
-    let method_body: String = String::from(params);
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(someUser.clone(), data);
-    return result;
-}
-/*
-#getaddressutxos
-getaddressutxos '{ "addresses" : [ "address" , ... ], "chaininfo" }'
+    The getaddressutxos method returns all unspent outputs for an address. It requires addressindex to be enabled.
+    The outputs can be handed to the TransactionBuilder with `Utxo::from`.
 
-The getaddressutxos method returns all unspent outputs for an address. It requires addressindex to be enabled.
+    # Arguments
+    Name	    Type	    Description
+    "address"	(string)	the address
 
-# Arguments
-Name	    Type	    Description
-"address"	(string)	the address
-"chainInfo"	(boolean)	include chain info with results
+    # Response
+    Name	        Type     	Description
+    "address"	   (string)  	the address
+    "txid"	       (string) 	the output txid
+    "height"	   (number) 	the block height
+    "outputIndex"  (number) 	the output index
+    "script"	   (string) 	the script hex encoded
+    "satoshis"     (number)	    the number of satoshis of the output
 
-# Response
-Name	        Type     	Description
-"address"	   (string)  	the address
-"txid"	       (string) 	the output txid
-"height"	   (number) 	the block height
-"outputIndex"  (number) 	the output index
-"script"	   (string) 	the script hex encoded
-"satoshis"     (number)	    the number of satoshis of the output
 
+            */
 
-        */
-
-pub fn get_address_utxos(
-    someUser: komodorpcutil::KomodoRPC,
-    v_address: Vec<String>,
-    chainInfo: bool,
-) -> Result<String, reqwest::Error> {
-    let mut addr_list = String::from("[");
-
-    for addr in &v_address {
-        addr_list = addr_list + "\"" + addr + "\"" + &","; //parsing error
+    pub fn get_address_utxos(&self, v_address: Vec<String>) -> Response<C, Vec<AddressUtxo>>
+    where
+        C: Transport<Vec<AddressUtxo>>,
+    {
+        let method_name: String = String::from("getaddressutxos");
+        let method_body = vec![json!(AddressQuery {
+            addresses: v_address,
+            start: None,
+            end: None,
+            chain_info: Some(false),
+        })];
+        self.client.call(&method_name, method_body)
     }
-
-    if (v_address.len() > 0)
-    // >1
+    /*
+    #getaddressutxos
+    getaddressutxos '{ "addresses" : [ "address" , ... ], "chainInfo": true }'
+
+    The getaddressutxos method with chainInfo returns the unspent outputs of get_address_utxos together with the tip of the chain they were read at.
+
+    # Arguments
+    Name	    Type	    Description
+    "address"	(string)	the address
+
+    # Response
+    Name	        Type     	        Description
+    "utxos"	       (array of jsons)	    the unspent outputs, as returned by get_address_utxos
+    "hash"	       (string) 	        the block hash of the tip
+    "height"	   (number) 	        the block height of the tip
+
+            */
+
+    pub fn get_address_utxos_with_chain_info(
+        &self,
+        v_address: Vec<String>,
+    ) -> Response<C, AddressUtxosWithChainInfo>
+    where
+        C: Transport<AddressUtxosWithChainInfo>,
     {
-        // why need to cut last substring
-        addr_list = addr_list[0..(addr_list.len() - 1)].to_string();
+        let method_name: String = String::from("getaddressutxos");
+        let method_body = vec![json!(AddressQuery {
+            addresses: v_address,
+            start: None,
+            end: None,
+            chain_info: Some(true),
+        })];
+        self.client.call(&method_name, method_body)
     }
-
-    addr_list = addr_list + &"]"; //& for String -> &string
-
-    let params = String::from("[{\"addresses\": ")
-        + &addr_list
-        + ",\"chainInfo\":"
-        + &chainInfo.to_string()
-        + "}]";
-    let method_name: String = String::from("getaddressutxos");
-    let method_body: String = String::from(params);
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-
-    let result = komodorpcutil::request(someUser.clone(), data);
-    return result;
-}
-/*
-#getsnapshot
-getsnapshot top
-
-The getsnapshot method returns a snapshot of addresses and their amounts at the Smart Chain's current height.
-
-The method requires addressindex to be enabled.
-
-# Arguments
-Name	 Type	            Description
-"top"	(number, optional)	Only return this many addresses, i.e. top N rich list
-
-# Response
-Name	            Type            	Description
-"addresses"	        (array of jsons)	the array containing the address and amount details
-"addr"	            (string)        	an address
-"amount"        	(number)	        the amount of coins in the above address
-"total"	            (numeric)         	the total amount in snapshot
-"average"	        (numeric)	        the average amount in each address
-"utxos"	            (number)    	    the total number of utxos in snapshot
-"total_addresses"	(number)	        the total number of addresses in snapshot,
-"start_height"	    (number)	        the block height snapshot began
-"ending_height"	    (number)	        the block height snapshot finished,
-"start_time"	    (number)	        the unix epoch time snapshot started
-"end_time"	        (number)	        the unix epoch time snapshot finished
-
- */
-pub fn get_snapshot(
-    someUser: komodorpcutil::KomodoRPC,
-    top: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    //TODO: need to implement optinal arg
-    let method_body: String;
-    let temp_top = top.unwrap_or(0); //Default value is 0
-    if (temp_top > 0) {
-        method_body = String::from("[\"") + &temp_top.to_string() + "\"]"; // String::from(format!("[/"{0}/"]",temp_top));
-    } else {
-        method_body = String::from("[]");
+    /*
+    #getsnapshot
+    getsnapshot top
+
+    The getsnapshot method returns a snapshot of addresses and their amounts at the Smart Chain's current height.
+
+    The method requires addressindex to be enabled.
+
+    # Arguments
+    Name	 Type	            Description
+    "top"	(number, optional)	Only return this many addresses, i.e. top N rich list
+
+    # Response
+    Name	            Type            	Description
+    "addresses"	        (array of jsons)	the array containing the address and amount details
+    "addr"	            (string)        	an address
+    "amount"        	(number)	        the amount of coins in the above address
+    "total"	            (numeric)         	the total amount in snapshot
+    "average"	        (numeric)	        the average amount in each address
+    "utxos"	            (number)    	    the total number of utxos in snapshot
+    "total_addresses"	(number)	        the total number of addresses in snapshot,
+    "start_height"	    (number)	        the block height snapshot began
+    "ending_height"	    (number)	        the block height snapshot finished,
+    "start_time"	    (number)	        the unix epoch time snapshot started
+    "end_time"	        (number)	        the unix epoch time snapshot finished
+
+     */
+    pub fn get_snapshot(&self, top: Option<u32>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getsnapshot");
+        let mut method_body = Vec::new();
+        let temp_top = top.unwrap_or(0); //Default value is 0
+        if temp_top > 0 {
+            // the daemon reads top as a string
+            method_body.push(json!(temp_top.to_string()));
+        }
+        self.client.call(&method_name, method_body)
     }
-
-    //let params = String::from("[") + &top.to_string()+"]";
-
-    let method_name: String = String::from("getsnapshot");
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    //println!("the payload is{}",payload);
-    let result = komodorpcutil::request(someUser.clone(), data);
-    return result;
 }
@@ -0,0 +1,476 @@
+//!
+//! Komodo addresses, parsed and checked without the daemon.
+//!
+//! # Remarks
+//!
+//! * Transparent addresses are base58check: a version byte, the 20-byte hash of a public key
+//! or of a script, and the first 4 bytes of the double sha256 of both. Komodo's version bytes
+//! are 60 for public keys ("R..." addresses) and 85 for scripts ("b..." addresses); smart chains
+//! keep them unless they were launched with other ones, which `AddressParams` describes.
+//!
+//! * Sapling addresses are bech32 with the "zs" prefix, holding an 11-byte diversifier and the
+//! 32-byte transmission key pk_d.
+//!
+//! * Parsing checks the encoding, checksum, version byte and length only; whether the wallet
+//! owns the address is still a question for `util::validate_address`.
+//!
+//! # Examples
+//! ```
+//! let address: Address = "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse()?;
+//! assert!(!address.is_shielded());
+//! let script_pub_key = address.script_pub_key();
+//! ```
+//!
+
+use super::komodorpcutil::KomodoError;
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The version bytes and prefix that tell the addresses of a chain apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressParams {
+    /// the version byte of pay-to-public-key-hash addresses
+    pub pubkey_prefix: u8,
+    /// the version byte of pay-to-script-hash addresses
+    pub script_prefix: u8,
+    /// the human-readable part of Sapling addresses
+    pub sapling_hrp: &'static str,
+}
+
+impl AddressParams {
+    /// The parameters of KMD, shared by the smart chains that do not set their own.
+    pub const KOMODO: AddressParams = AddressParams {
+        pubkey_prefix: 60,
+        script_prefix: 85,
+        sapling_hrp: "zs",
+    };
+}
+
+impl Default for AddressParams {
+    fn default() -> AddressParams {
+        AddressParams::KOMODO
+    }
+}
+
+/// What an address pays to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AddressKind {
+    /// a transparent address paying to the hash160 of a public key
+    PubKeyHash([u8; 20]),
+    /// a transparent address paying to the hash160 of a script
+    ScriptHash([u8; 20]),
+    /// a shielded Sapling address
+    Sapling {
+        /// the diversifier of the address
+        diversifier: [u8; 11],
+        /// the transmission key of the address
+        pk_d: [u8; 32],
+    },
+}
+
+/// A Komodo address whose encoding and checksum were checked.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Address {
+    text: String,
+    kind: AddressKind,
+}
+
+impl Address {
+    /**
+     *Function Name: parse
+     *@params: text - the address, e.g. "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4"
+     *         params - the version bytes and Sapling prefix of the chain
+     *Output: the address, or KomodoError::InvalidAddress with the reason it was rejected
+     */
+    pub fn parse(text: &str, params: &AddressParams) -> Result<Address, KomodoError> {
+        let invalid = |reason: &'static str| KomodoError::InvalidAddress {
+            address: text.to_string(),
+            reason,
+        };
+        let sapling_prefix = format!("{}1", params.sapling_hrp);
+        // bech32 may be written in upper case; the address is kept in lower case so that
+        // both spellings compare, sort and serialize the same
+        let lower = text.to_lowercase();
+        let shielded = lower.starts_with(&sapling_prefix);
+        let kind = if shielded {
+            let payload = bech32_decode(text, params.sapling_hrp).map_err(invalid)?;
+            if payload.len() != 43 {
+                return Err(invalid("a Sapling address holds 43 bytes"));
+            }
+            let mut diversifier = [0; 11];
+            let mut pk_d = [0; 32];
+            diversifier.copy_from_slice(&payload[..11]);
+            pk_d.copy_from_slice(&payload[11..]);
+            AddressKind::Sapling { diversifier, pk_d }
+        } else {
+            let payload = base58check_decode(text).map_err(invalid)?;
+            if payload.len() != 21 {
+                return Err(invalid("a transparent address holds 21 bytes"));
+            }
+            let mut hash = [0; 20];
+            hash.copy_from_slice(&payload[1..]);
+            if payload[0] == params.pubkey_prefix {
+                AddressKind::PubKeyHash(hash)
+            } else if payload[0] == params.script_prefix {
+                AddressKind::ScriptHash(hash)
+            } else {
+                return Err(invalid("unknown version byte"));
+            }
+        };
+        Ok(Address {
+            text: if shielded { lower } else { text.to_string() },
+            kind,
+        })
+    }
+
+    /**
+     *Function Name: from_kind
+     *@params: kind - what the address pays to
+     *         params - the version bytes and Sapling prefix of the chain
+     *Output: the address, encoded for the chain
+     */
+    pub fn from_kind(kind: AddressKind, params: &AddressParams) -> Address {
+        let text = match kind {
+            AddressKind::PubKeyHash(hash) => base58check_encode(params.pubkey_prefix, &hash),
+            AddressKind::ScriptHash(hash) => base58check_encode(params.script_prefix, &hash),
+            AddressKind::Sapling { diversifier, pk_d } => {
+                let mut payload = diversifier.to_vec();
+                payload.extend_from_slice(&pk_d);
+                bech32_encode(params.sapling_hrp, &payload)
+            }
+        };
+        Address { text, kind }
+    }
+
+    /**
+     *Function Name: kind
+     *@params: the instance of the struct Address
+     *Output: what the address pays to
+     */
+    pub fn kind(&self) -> &AddressKind {
+        &self.kind
+    }
+
+    /**
+     *Function Name: is_shielded
+     *@params: the instance of the struct Address
+     *Output: true for a Sapling address
+     */
+    pub fn is_shielded(&self) -> bool {
+        match self.kind {
+            AddressKind::Sapling { .. } => true,
+            _ => false,
+        }
+    }
+
+    /**
+     *Function Name: as_str
+     *@params: the instance of the struct Address
+     *Output: the address as it was given; Sapling addresses in lower case
+     */
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /**
+     *Function Name: script_pub_key
+     *@params: the instance of the struct Address
+     *Output: the output script paying the address, or None for a Sapling address
+     */
+    pub fn script_pub_key(&self) -> Option<Vec<u8>> {
+        match self.kind {
+            // OP_DUP OP_HASH160 <hash> OP_EQUALVERIFY OP_CHECKSIG
+            AddressKind::PubKeyHash(hash) => {
+                let mut script = vec![0x76, 0xa9, 0x14];
+                script.extend_from_slice(&hash);
+                script.extend_from_slice(&[0x88, 0xac]);
+                Some(script)
+            }
+            // OP_HASH160 <hash> OP_EQUAL
+            AddressKind::ScriptHash(hash) => {
+                let mut script = vec![0xa9, 0x14];
+                script.extend_from_slice(&hash);
+                script.push(0x87);
+                Some(script)
+            }
+            AddressKind::Sapling { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl FromStr for Address {
+    type Err = KomodoError;
+
+    /// Parses an address of KMD or of a smart chain that kept its version bytes.
+    fn from_str(text: &str) -> Result<Address, KomodoError> {
+        Address::parse(text, &AddressParams::KOMODO)
+    }
+}
+
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.text)
+    }
+}
+
+fn double_sha256(data: &[u8]) -> Vec<u8> {
+    Sha256::digest(&Sha256::digest(data)).to_vec()
+}
+
+fn base58check_encode(version: u8, hash: &[u8]) -> String {
+    let mut data = vec![version];
+    data.extend_from_slice(hash);
+    let checksum = double_sha256(&data);
+    data.extend_from_slice(&checksum[..4]);
+
+    // each leading zero byte is written as a '1'
+    let zeros = data.iter().take_while(|&&byte| byte == 0).count();
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in &data[zeros..] {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let mut text = "1".repeat(zeros);
+    text.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&digit| BASE58_ALPHABET[digit as usize] as char),
+    );
+    text
+}
+
+// Returns the version byte and the hash, without the checksum.
+fn base58check_decode(text: &str) -> Result<Vec<u8>, &'static str> {
+    let zeros = text.bytes().take_while(|&c| c == b'1').count();
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in text.bytes().skip(zeros) {
+        let mut carry = match BASE58_ALPHABET.iter().position(|&a| a == c) {
+            Some(value) => value as u32,
+            None => return Err("not a base58 character"),
+        };
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let mut data = vec![0; zeros];
+    data.extend(bytes.iter().rev());
+    if data.len() < 4 {
+        return Err("too short");
+    }
+    let (payload, checksum) = data.split_at(data.len() - 4);
+    if double_sha256(payload)[..4] != *checksum {
+        return Err("bad checksum");
+    }
+    Ok(payload.to_vec())
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut checksum: u32 = 1;
+    for &value in values {
+        let top = checksum >> 25;
+        checksum = (checksum & 0x01ff_ffff) << 5 ^ u32::from(value);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn bech32_hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut values: Vec<u8> = hrp.bytes().map(|c| c >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|c| c & 31));
+    values
+}
+
+// Regroups bits, e.g. bytes into the 5-bit values of bech32 and back.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, &'static str> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut out = Vec::new();
+    let max = (1 << to) - 1;
+    for &value in data {
+        acc = (acc << from) | u32::from(value);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || (acc << (to - bits)) & max != 0 {
+        return Err("bad padding");
+    }
+    Ok(out)
+}
+
+fn bech32_encode(hrp: &str, payload: &[u8]) -> String {
+    let mut data = convert_bits(payload, 8, 5, true).expect("padding never fails");
+    let mut values = bech32_hrp_expand(hrp);
+    values.extend_from_slice(&data);
+    values.extend_from_slice(&[0; 6]);
+    let checksum = bech32_polymod(&values) ^ 1;
+    data.extend((0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8));
+    let mut text = format!("{}1", hrp);
+    text.extend(
+        data.iter()
+            .map(|&value| BECH32_CHARSET[value as usize] as char),
+    );
+    text
+}
+
+// Returns the bytes of a bech32 string with the given human-readable part.
+fn bech32_decode(text: &str, hrp: &str) -> Result<Vec<u8>, &'static str> {
+    if text.to_lowercase() != text && text.to_uppercase() != text {
+        return Err("mixed case");
+    }
+    let text = text.to_lowercase();
+    let separator = text.rfind('1').ok_or("no separator")?;
+    if text[..separator] != *hrp {
+        return Err("wrong prefix");
+    }
+    let mut values = Vec::new();
+    for c in text[separator + 1..].bytes() {
+        match BECH32_CHARSET.iter().position(|&a| a == c) {
+            Some(value) => values.push(value as u8),
+            None => return Err("not a bech32 character"),
+        }
+    }
+    if values.len() < 6 {
+        return Err("too short");
+    }
+    let mut checked = bech32_hrp_expand(hrp);
+    checked.extend_from_slice(&values);
+    if bech32_polymod(&checked) != 1 {
+        return Err("bad checksum");
+    }
+    convert_bits(&values[..values.len() - 6], 5, 8, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAPLING: &str =
+        "zs1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7ruszzg3rysjjvfeg9y4zkvtfdeq";
+
+    #[test]
+    fn parses_transparent_and_sapling_addresses() {
+        let address: Address = "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse().unwrap();
+        let hash = match address.kind() {
+            AddressKind::PubKeyHash(hash) => *hash,
+            other => panic!("{:?}", other),
+        };
+        assert_eq!(
+            address.script_pub_key().unwrap()[3..23],
+            hash[..],
+            "the script pays the hash"
+        );
+        assert_eq!(
+            Address::from_kind(AddressKind::PubKeyHash(hash), &AddressParams::KOMODO),
+            address
+        );
+
+        let mut hash = [0; 20];
+        hash[19] = 1;
+        let script = Address::from_kind(AddressKind::ScriptHash(hash), &AddressParams::KOMODO);
+        assert_eq!(script.as_str(), "bCjGhELVMLPUWqrN5fK6Df8sVsuBYw24PK");
+        assert_eq!(script.to_string().parse::<Address>().unwrap(), script);
+
+        let sapling: Address = SAPLING.parse().unwrap();
+        assert!(sapling.is_shielded());
+        let upper: Address = SAPLING.to_uppercase().parse().unwrap();
+        assert_eq!(upper.as_str(), SAPLING);
+        assert_eq!(upper, sapling);
+        assert_eq!(sapling.script_pub_key(), None);
+        match sapling.kind() {
+            AddressKind::Sapling { diversifier, pk_d } => {
+                assert_eq!(diversifier[..], (1..12).collect::<Vec<u8>>()[..]);
+                assert_eq!(pk_d[31], 43);
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_bad_checksums_and_prefixes() {
+        let reason = |text: &str| match text.parse::<Address>() {
+            Err(KomodoError::InvalidAddress { reason, .. }) => reason,
+            other => panic!("{:?}", other),
+        };
+        // the last character changed
+        assert_eq!(reason("RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC5"), "bad checksum");
+        assert_eq!(
+            reason("RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC0"),
+            "not a base58 character"
+        );
+        // a bitcoin address
+        assert_eq!(
+            reason("1BoatSLRHtKNngkdXEeobR76b53LETtpyT"),
+            "unknown version byte"
+        );
+        let mut sapling = SAPLING.to_string();
+        sapling.pop();
+        sapling.push('p');
+        assert_eq!(reason(&sapling), "bad checksum");
+        let testnet = Address::parse(
+            SAPLING,
+            &AddressParams {
+                sapling_hrp: "ztestsapling",
+                ..AddressParams::KOMODO
+            },
+        );
+        assert!(testnet.is_err());
+    }
+
+    #[test]
+    fn parses_with_the_params_of_the_chain() {
+        // a chain launched with the version bytes of bitcoin
+        let params = AddressParams {
+            pubkey_prefix: 0,
+            script_prefix: 5,
+            ..AddressParams::KOMODO
+        };
+        let address = Address::parse("1BoatSLRHtKNngkdXEeobR76b53LETtpyT", &params).unwrap();
+        assert_eq!(address.script_pub_key().unwrap()[..3], [0x76, 0xa9, 0x14]);
+        assert!(Address::parse("RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4", &params).is_err());
+    }
+}
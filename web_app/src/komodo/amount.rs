@@ -0,0 +1,341 @@
+//!
+//! Coin amounts of Komodo, counted in satoshis.
+//!
+//! # Remarks
+//!
+//! * An `Amount` holds a whole number of satoshis (1 COIN = 100000000 satoshis) in an i64,
+//! so sums and differences of amounts are exact.
+//!
+//! * Amounts are parsed from and displayed as decimal strings with up to 8 places, e.g.
+//! "1.5" parses to 150000000 satoshis, which displays as "1.50000000". Parsing never rounds:
+//! a string with more than 8 significant decimal places is an error.
+//!
+//! * The daemon sends amounts as json numbers or as strings; both are read. Amounts are sent
+//! as json numbers written with all 8 decimals, e.g. 1.50000000, so no amount goes through a
+//! float on its way to the daemon.
+//!
+//! # Examples
+//! ```
+//! let fee: Amount = "0.0001".parse()?;
+//! let total = Amount::from_sat(150000000).checked_add(fee).unwrap();
+//! assert_eq!(total.to_string(), "1.50010000");
+//! ```
+//!
+
+use super::komodorpcutil::KomodoError;
+use serde::{de, ser};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Number, Value};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// The number of satoshis in one COIN.
+pub const COIN: i64 = 100_000_000;
+
+// The number of decimal places of an amount in COIN.
+const DECIMALS: usize = 8;
+
+/// A coin amount, in satoshis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(i64);
+
+impl Amount {
+    /// No coins.
+    pub const ZERO: Amount = Amount(0);
+
+    /// One COIN.
+    pub const ONE_COIN: Amount = Amount(COIN);
+
+    /**
+     *Function Name: from_sat
+     *@params: sats - the amount in satoshis
+     *Output: the amount
+     */
+    pub const fn from_sat(sats: i64) -> Amount {
+        Amount(sats)
+    }
+
+    /**
+     *Function Name: as_sat
+     *@params: the instance of the struct Amount
+     *Output: the amount in satoshis
+     */
+    pub fn as_sat(self) -> i64 {
+        self.0
+    }
+
+    /**
+     *Function Name: from_coins
+     *@params: coins - a whole number of coins
+     *Output: the amount, or None when it does not fit
+     */
+    pub fn from_coins(coins: i64) -> Option<Amount> {
+        coins.checked_mul(COIN).map(Amount)
+    }
+
+    /**
+     *Function Name: as_coins
+     *@params: the instance of the struct Amount
+     *Output: the amount in COIN as a float, for display and ratios only
+     */
+    pub fn as_coins(self) -> f64 {
+        self.0 as f64 / COIN as f64
+    }
+
+    /**
+     *Function Name: is_negative
+     *@params: the instance of the struct Amount
+     *Output: true when the amount is below zero
+     */
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /**
+     *Function Name: checked_add
+     *@params: other - the amount to add
+     *Output: the sum, or None on overflow
+     */
+    pub fn checked_add(self, other: Amount) -> Option<Amount> {
+        self.0.checked_add(other.0).map(Amount)
+    }
+
+    /**
+     *Function Name: checked_sub
+     *@params: other - the amount to subtract
+     *Output: the difference, or None on overflow
+     */
+    pub fn checked_sub(self, other: Amount) -> Option<Amount> {
+        self.0.checked_sub(other.0).map(Amount)
+    }
+
+    /**
+     *Function Name: checked_mul
+     *@params: factor - the number to multiply by
+     *Output: the product, or None on overflow
+     */
+    pub fn checked_mul(self, factor: i64) -> Option<Amount> {
+        self.0.checked_mul(factor).map(Amount)
+    }
+
+    /**
+     *Function Name: checked_div
+     *@params: divisor - the number to divide by
+     *Output: the quotient rounded toward zero, or None when divisor is 0
+     */
+    pub fn checked_div(self, divisor: i64) -> Option<Amount> {
+        self.0.checked_div(divisor).map(Amount)
+    }
+
+    // Reads a json number written with an exponent, which the exact parser does not take.
+    fn from_f64(coins: f64) -> Option<Amount> {
+        let sats = (coins * COIN as f64).round();
+        if sats.is_finite() && sats.abs() < i64::MAX as f64 {
+            Some(Amount(sats as i64))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // i128 so that the absolute value of i64::MIN fits
+        let sats = i128::from(self.0);
+        let sign = if sats < 0 { "-" } else { "" };
+        let coin = i128::from(COIN);
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            sats.abs() / coin,
+            sats.abs() % coin,
+            width = DECIMALS
+        )
+    }
+}
+
+impl FromStr for Amount {
+    type Err = KomodoError;
+
+    fn from_str(text: &str) -> Result<Amount, KomodoError> {
+        let invalid = || KomodoError::InvalidAmount(text.to_string());
+        let trimmed = text.trim();
+        let (negative, unsigned) = if let Some(unsigned) = trimmed.strip_prefix('-') {
+            (true, unsigned)
+        } else if let Some(unsigned) = trimmed.strip_prefix('+') {
+            (false, unsigned)
+        } else {
+            (false, trimmed)
+        };
+        let mut parts = unsigned.splitn(2, '.');
+        let whole = parts.next().unwrap_or("");
+        let fraction = parts.next().unwrap_or("");
+        let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            return Err(invalid());
+        }
+        // places past the 8th may only be zeros
+        if fraction.len() > DECIMALS && fraction[DECIMALS..].bytes().any(|byte| byte != b'0') {
+            return Err(invalid());
+        }
+
+        let mut sats: i64 = 0;
+        for digit in whole.bytes() {
+            sats = sats
+                .checked_mul(10)
+                .and_then(|sats| sats.checked_add(i64::from(digit - b'0')))
+                .ok_or_else(invalid)?;
+        }
+        sats = sats.checked_mul(COIN).ok_or_else(invalid)?;
+        let mut place = COIN;
+        for digit in fraction.bytes().take(DECIMALS) {
+            place /= 10;
+            sats = sats
+                .checked_add(i64::from(digit - b'0') * place)
+                .ok_or_else(invalid)?;
+        }
+        Ok(Amount(if negative { -sats } else { sats }))
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, other: Amount) -> Amount {
+        self.checked_add(other).expect("amount overflow")
+    }
+}
+
+impl AddAssign for Amount {
+    fn add_assign(&mut self, other: Amount) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, other: Amount) -> Amount {
+        self.checked_sub(other).expect("amount overflow")
+    }
+}
+
+impl SubAssign for Amount {
+    fn sub_assign(&mut self, other: Amount) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        Amount(self.0.checked_neg().expect("amount overflow"))
+    }
+}
+
+impl Sum for Amount {
+    fn sum<I: Iterator<Item = Amount>>(amounts: I) -> Amount {
+        amounts.fold(Amount::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Amount> for Amount {
+    fn sum<I: Iterator<Item = &'a Amount>>(amounts: I) -> Amount {
+        amounts.cloned().sum()
+    }
+}
+
+// The exact decimal text is written as a json number, e.g. 1.50000000; serde_json keeps
+// the digits as they are because of its arbitrary_precision feature.
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let number: Number = self.to_string().parse().map_err(ser::Error::custom)?;
+        number.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::String(text) => text.parse().map_err(de::Error::custom),
+            // the digits of the number as the daemon wrote them, unless they use an exponent
+            Value::Number(number) => number
+                .to_string()
+                .parse()
+                .ok()
+                .or_else(|| number.as_f64().and_then(Amount::from_f64))
+                .ok_or_else(|| de::Error::custom(format!("invalid amount {}", number))),
+            other => Err(de::Error::custom(format!(
+                "expected an amount in COIN, as a number or a string, got {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_and_formats_exactly() {
+        let amount: Amount = "0.1".parse().unwrap();
+        assert_eq!(amount, Amount::from_sat(10_000_000));
+        assert_eq!(amount.to_string(), "0.10000000");
+        assert_eq!(
+            "-12.00000001".parse::<Amount>().unwrap(),
+            Amount::from_sat(-1_200_000_001)
+        );
+        assert_eq!(
+            ".5".parse::<Amount>().unwrap(),
+            Amount::from_sat(50_000_000)
+        );
+        assert_eq!(Amount::from_sat(-1).to_string(), "-0.00000001");
+        // ten tenths add up to exactly one coin
+        let tenths: Amount = (0..10).map(|_| amount).sum();
+        assert_eq!(tenths, Amount::ONE_COIN);
+        assert!("0.000000001".parse::<Amount>().is_err());
+        assert!("1e-8".parse::<Amount>().is_err());
+        assert!("".parse::<Amount>().is_err());
+        assert!("92233720368.54775808".parse::<Amount>().is_err());
+        assert_eq!(
+            Amount::from_sat(i64::MAX).checked_add(Amount::from_sat(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn reads_numbers_and_strings() {
+        let amounts: Vec<Amount> =
+            serde_json::from_value(json!([1.1, "0.00000001", 3, "2.50000000"])).unwrap();
+        assert_eq!(
+            amounts,
+            vec![
+                Amount::from_sat(110_000_000),
+                Amount::from_sat(1),
+                Amount::from_sat(300_000_000),
+                Amount::from_sat(250_000_000)
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&Amount::from_sat(150_000_000)).unwrap(),
+            "1.50000000"
+        );
+        // above 2^53 satoshis, where a float would round to an even number of satoshis
+        let large = Amount::from_sat(9_007_199_254_740_993);
+        assert_eq!(
+            serde_json::to_string(&json!([large])).unwrap(),
+            "[90071992.54740993]"
+        );
+        assert_eq!(
+            serde_json::from_str::<Amount>("90071992.54740993").unwrap(),
+            large
+        );
+        assert!(serde_json::from_value::<Amount>(json!(true)).is_err());
+    }
+}
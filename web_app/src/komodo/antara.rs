@@ -0,0 +1,74 @@
+//!
+//! Types shared by the Antara (CryptoConditions) modules of Komodo.
+//!
+//! # Remarks
+//!
+//! * Most Antara methods answer with a json object whose "result" member is
+//! "success", or "error" together with an "error" message, instead of a JSON-RPC
+//! error. Their replies are read into a `CCReply`, which `into_result` turns into
+//! a `KomodoError::Antara` for the error case.
+//!
+//! * The methods that build a transaction do not send it. They return the signed
+//! transaction as hex in a `CCTransaction`, which is sent to the network with
+//! `broadcast` (sendrawtransaction).
+//!
+//! * The daemon reads the numeric arguments of these methods from strings, so
+//! the wrappers send numbers as strings.
+//!
+
+use super::komodorpcutil::{KomodoClient, KomodoError};
+use super::KomodoApi;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The reply of an Antara method: the typed object, or the error the module reported.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum CCReply<T> {
+    /// {"result": "error", "error": "..."}
+    Error {
+        /// the error message of the module
+        error: String,
+    },
+    /// {"result": "success", ...}
+    Success(T),
+}
+
+impl<T> CCReply<T> {
+    /**
+     *Function Name: into_result
+     *@params: the instance of the enum CCReply
+     *Output: the typed reply
+     *           KomodoError::Antara - when the module reported an error
+     */
+    pub fn into_result(self) -> Result<T, KomodoError> {
+        match self {
+            CCReply::Success(reply) => Ok(reply),
+            CCReply::Error { error } => Err(KomodoError::Antara(error)),
+        }
+    }
+}
+
+/// The reply of the <module>address methods, e.g. faucetaddress: the CC
+/// addresses and pubkeys of the module by name, such as "FaucetCCAddress" or "myaddress".
+pub type CCAddresses = BTreeMap<String, String>;
+
+/// A transaction built by an Antara method, ready to be broadcast.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CCTransaction {
+    /// the signed transaction in hex
+    pub hex: String,
+}
+
+impl CCTransaction {
+    /**
+     *Function Name: broadcast
+     *@params: client - the client of the chain the transaction was built on
+     *Output: the id of the transaction, once the daemon accepted it with sendrawtransaction
+     */
+    pub fn broadcast(&self, client: &KomodoClient) -> Result<String, KomodoError> {
+        client
+            .raw_transactions()
+            .send_raw_transaction(self.hex.clone(), None)
+    }
+}
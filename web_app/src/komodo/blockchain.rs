@@ -9,848 +9,1014 @@
 //!
 //! * ? Some documentation of methods may reference a different method.
 //!
-//! * A valid KomodoRPC object type must be passed in.
+//! * Methods are called on the handle returned by `KomodoClient::blockchain()`.
 //!
-//! * All examples for each method assumes a valid KomodoRPC object, named `some_user`, is used.
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
 //!
 //! * Use of any methods requires the following modules:
 //! ```
 //! use super::komodorpcutil;
-//! use komodorpcutil::KomodoRPC;
+//! use komodorpcutil::{KomodoClient, Response, Transport};
 //! use std::any::Any;
 //! ```
 //! [Komodo website]: https://docs.komodoplatform.com/basic-docs/smart-chains/smart-chain-api/address.html
 //!
 
 use super::komodorpcutil;
-use komodorpcutil::KomodoRPC;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+
+/// The object returned by getblock when verbose is true.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Block {
+    /// the block hash
+    pub hash: String,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: i64,
+    /// the raw confirmations; -1 if the block is not on the main chain
+    pub rawconfirmations: Option<i64>,
+    /// the block size
+    pub size: u64,
+    /// the block height or index
+    pub height: u64,
+    /// the block version
+    pub version: i64,
+    /// the merkle root
+    pub merkleroot: String,
+    /// the ids of the transactions in the block
+    pub tx: Vec<String>,
+    /// the block time in seconds since epoch (Jan 1 1970 GMT)
+    pub time: u64,
+    /// the nonce
+    pub nonce: String,
+    /// the bits
+    pub bits: String,
+    /// the difficulty
+    pub difficulty: f64,
+    /// the total amount of work in the chain up to this block, in hexadecimal
+    pub chainwork: Option<String>,
+    /// the hash of the previous block
+    pub previousblockhash: Option<String>,
+    /// the hash of the next block
+    pub nextblockhash: Option<String>,
+}
 
-/// The coinsupply method returns the coin supply information for the indicated block height.
-/// If no height is given, the method defaults to the blockchain's current height.
-/// # Arguments
-/// * `height`	(integer, optional)	the desired block height
-///# Response
-/// * `result`	(string)	whether the request was successful
-/// * `coin`	(string)	the ticker symbol of the coin for Smart Chains, otherwise KMD
-/// * `height`	(integer)	the height of this coin supply data
-/// * `supply`	(float)	the transparent coin supply
-/// * `zfunds`	(float)	the shielded coin supply (in zaddrs)
-/// * `sprout`	(float)	the sprout coin supply (in zcaddrs)
-/// * `total`	(float)	the total coin supply, i.e. sum of supply + zfunds
-/// %%%
-pub fn coin_supply(
-    some_user: komodorpcutil::KomodoRPC,
-    height_supplied: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("coinsupply");
-    let method_body: String;
-
-    let height = height_supplied.unwrap_or(0); //Default value is 0
-    if height > 0 {
-        method_body = String::from(format!("[\"{0}\"]", height));
-    } else {
-        method_body = String::from("[]");
-    }
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
+/// The object returned by getblockheader when verbose is true.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockHeader {
+    /// the block hash
+    pub hash: String,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: i64,
+    /// the raw confirmations; -1 if the block is not on the main chain
+    pub rawconfirmations: Option<i64>,
+    /// the block height or index
+    pub height: u64,
+    /// the block version
+    pub version: i64,
+    /// the merkle root
+    pub merkleroot: String,
+    /// the block time in seconds since epoch (Jan 1 1970 GMT)
+    pub time: u64,
+    /// the nonce
+    pub nonce: String,
+    /// the bits
+    pub bits: String,
+    /// the difficulty
+    pub difficulty: f64,
+    /// the hash of the previous block
+    pub previousblockhash: Option<String>,
+    /// the hash of the next block
+    pub nextblockhash: Option<String>,
+}
 
-    komodorpcutil::request(some_user.clone(), data)
+/// The progress toward enforcing or rejecting a softfork.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SoftForkProgress {
+    /// true if threshold reached
+    pub status: bool,
+    /// the number of blocks with the new version found
+    pub found: u64,
+    /// the number of blocks required to trigger
+    pub required: u64,
+    /// the maximum size of the examined window of recent blocks
+    pub window: u64,
 }
 
-/// The getbestblockhash method returns the hash of the best (tip) block in the longest block chain.
-/// # Arguments
-/// * `(none)`
-/// # Response
-/// * `hex`	(string)	the block hash, hex encoded
-pub fn get_best_block_hash(some_user: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getbestblockhash");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+/// One entry of the "softforks" array of getblockchaininfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SoftFork {
+    /// the name of the softfork
+    pub id: String,
+    /// the block version
+    pub version: i64,
+    /// the progress toward enforcing the softfork rules for blocks of the new version
+    pub enforce: SoftForkProgress,
+    /// the progress toward rejecting pre-softfork blocks
+    pub reject: SoftForkProgress,
 }
-/// The getblock method returns the block's relevant state information.
-/// The verbose input is optional. The default value is true, and it will return a json object
-/// with information about the indicated block. If verbose is false,
-/// the command returns a string that is serialized hex-encoded data
-/// for the indicated block.
-/// # Arguments
-/// * `hash OR height`	(string OR number), respectively	the block hash OR the block height
-/// * `verbose`	(boolean, optional, default=true)	true returns a json object, false returns hex-encoded data
-///
-/// # Response (verbose = true)
-/// * `hash`	(string)	the block hash (same as provided hash)
-/// * `confirmations`	(numeric)	a confirmation number that is aware of the dPoW security service
-/// * `rawconfirmations`	(numeric)	the raw confirmations (number of blocks on top of this block); the returned value is -1 if the block is not on the main chain
-/// * `size`	(numeric)	the block size
-/// * `height`	(numeric)	the block height or index (same as provided height)
-/// * `version`	(numeric)	the block version
-/// * `merkleroot`	(string)	the merkle root
-/// * `tx` : [ `transaction_id` ,...]	(array of strings)
-/// * `time`	(numeric)	the block time in seconds since epoch (Jan 1 1970 GMT)
-/// * `nonce`	(numeric)	the nonce
-/// * `bits`	(string)	the bits
-/// * `difficulty`	(numeric)	the difficulty
-/// * `previousblockhash`	(string)	the hash of the previous block
-/// * `nextblockhash`	(string)	the hash of the next block
-///
-/// # Response (Verbose = False)
-/// * `data` (string)	a string that is serialized, hex-encoded data for the indicated block
-/// %%%
-pub fn get_block(
-    SomeUser: komodorpcutil::KomodoRPC,
-    height_or_hash: String,
-    verbose: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let temp_verbose: bool = verbose.unwrap_or(false);
-    let method_name: String = String::from("getblock");
-    let method_body: String;
-    // if verbose is true
-
-    if temp_verbose {
-        method_body = String::from("[\"") + &height_or_hash + &String::from("\", true]");
-    } else {
-        method_body = String::from("[\"") + &height_or_hash + &String::from("\", false]");
-    }
 
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// One value of the "upgrades" object of getblockchaininfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NetworkUpgrade {
+    /// the name of upgrade
+    pub name: String,
+    /// the block height of activation
+    pub activationheight: u64,
+    /// the status of the upgrade
+    pub status: String,
+    /// additional information about the upgrade
+    pub info: String,
 }
-/// The getblockchaininfo method returns a json object containing state information about blockchain processing.
-///
-/// # Arguments
-///	* `(none)`
-///
-/// # Response
-/// * `chain`	(string)	the current network name, as defined in BIP70 (main, test, regtest)
-/// * `blocks`	(numeric)	the current number of blocks processed in the server
-/// * `headers`	(numeric)	the current number of headers we have validated
-/// * `bestblockhash`	(string)	the hash of the currently best block
-/// * `difficulty`	(numeric)	the current difficulty
-/// * `verificationprogress`	(numeric)	an estimate of verification progress [0..1]
-/// * `chainwork`	(string)	the total amount of work in the active chain, in hexadecimal
-/// * `pruned`	(bool)	whether the current state is in pruning mode; if true, the blockchain will not keep all transaction and block information, to preserve disk space
-/// * `size_on_disk`	(numeric)	the size of the blockchain on disk, measured in bytes
-/// * `commitments`	(numeric)	the current number of note commitments in the commitment tree
-/// * `softforks`: { ..... }	(array)	the status of softforks in progress
-/// * `id`	(string)	the name of the softfork
-/// * `version`	(numeric)	the block version
-/// * `enforce`: { ... }	(object)	the progress toward enforcing the softfork rules for blocks of the new version
-/// * `status`	(boolean)	true if threshold reached
-/// * `found`	(numeric)	the number of blocks with the new version found
-/// * `required`	(numeric)	the number of blocks required to trigger
-/// * `window`	(numeric)	the maximum size of the examined window of recent blocks
-/// * `reject`: { ... }	(object)	the progress toward rejecting pre-softfork blocks (same fields as `enforce`)
-/// * `upgrades`:	(object)	the status of network upgrades
-/// * `xxxxxxxxx_string`:	(string)	the branch ID of the upgrade
-/// * `name`	(string)	the name of upgrade
-/// * `activationheight`	(numeric)	the block height of activation
-/// * `status`	(string)	the status of the upgrade
-/// * `info`	(string)	additional information about the upgrade
-/// * `consensus`: { ..... }	(object)	branch IDs of the current and upcoming consensus rules
-/// * `chaintip`	(string)	branch ID used to validate the current chain tip
-/// * `nextblock`	(string)	branch ID under which the next block will be validated
-/// %%%
-pub fn get_blockchain_info(some_user: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getblockchaininfo");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+
+/// The "consensus" object of getblockchaininfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Consensus {
+    /// branch ID used to validate the current chain tip
+    pub chaintip: String,
+    /// branch ID under which the next block will be validated
+    pub nextblock: String,
 }
 
-///	The getblockcount method returns the number of blocks in the best valid block chain.
-///
-/// # Arguments
-///	* `(none)`
-///
-/// # Response
-/// data	(numeric)	the current block count
-/// %%%
-
-pub fn get_block_count(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getblockcount");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The object returned by getblockchaininfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BlockchainInfo {
+    /// the current network name, as defined in BIP70 (main, test, regtest)
+    pub chain: String,
+    /// the current number of blocks processed in the server
+    pub blocks: u64,
+    /// the current number of headers we have validated
+    pub headers: u64,
+    /// the hash of the currently best block
+    pub bestblockhash: String,
+    /// the current difficulty
+    pub difficulty: f64,
+    /// an estimate of verification progress [0..1]
+    pub verificationprogress: f64,
+    /// the total amount of work in the active chain, in hexadecimal
+    pub chainwork: String,
+    /// whether the current state is in pruning mode
+    pub pruned: bool,
+    /// the size of the blockchain on disk, measured in bytes
+    pub size_on_disk: Option<u64>,
+    /// the current number of note commitments in the commitment tree
+    pub commitments: u64,
+    /// the status of softforks in progress
+    #[serde(default)]
+    pub softforks: Vec<SoftFork>,
+    /// the status of network upgrades, keyed by branch ID
+    #[serde(default)]
+    pub upgrades: HashMap<String, NetworkUpgrade>,
+    /// branch IDs of the current and upcoming consensus rules
+    pub consensus: Option<Consensus>,
 }
 
-///The getblockhash method returns the hash of the indicated block index, according to the best blockchain at the time provided.
-/// # Arguments
-/// * `index`	(numeric, required)	the block index
-/// # Response
-/// * `hash`	(string)	the block hash
-pub fn get_block_hash(
-    SomeUser: komodorpcutil::KomodoRPC,
-    index: u32,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getblockckhash");
-    let method_body: String = String::from(format!("[{:?}]", index));
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// One entry of the array returned by getchaintips.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChainTip {
+    /// the height of the chain tip
+    pub height: u64,
+    /// the block hash of the tip
+    pub hash: String,
+    /// the length of the branch connecting the tip to the main chain; 0 for the main chain
+    pub branchlen: u64,
+    /// the status of the chain; "active" for the main chain
+    pub status: String,
 }
-///The getblockhashes method returns an array of hashes of blocks within the timestamp range provided.
-///The method requires timestampindex to be enabled.
-/// # Arguments
-/// * `high`	(numeric, required)	the newer block timestamp
-/// * `low`	(numeric, required)	the older block timestamp
-/// * `options`	(string, required)	a json object
-/// * `noOrphans`	(boolean)	a value of true implies that the method will only include blocks on the main chain
-/// * `logicalTimes`	(boolean)	a value of true implies that the method will only include logical timestamps with hashes
-/// # Response
-/// * `hash`	(string)	the block hash
-/// * `blockhash`	(string)	the block hash
-/// * `logicalts`	(numeric)	the logical timestamp
-/// %%%
-///
-/// SKIPPED DUE TO LACK OF PROPER DOCUMENTATION IN KOMODO PLATFORM
-pub fn get_block_hashes(
-    SomeUser: komodorpcutil::KomodoRPC,
-    high: u32,
-    low: u32,
-    no_orphans: bool,
-    logical_times: bool,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_no_orphans: String;
-    let temp_logical_times: String;
-    if no_orphans {
-        temp_no_orphans = String::from("true");
-    } else {
-        temp_no_orphans = String::from("false");
-    }
 
-    if logical_times {
-        temp_logical_times = String::from("true");
-    } else {
-        temp_logical_times = String::from("false");
-    }
+/// The object returned by getmempoolinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MempoolInfo {
+    /// the current transaction count
+    pub size: u64,
+    /// the sum of all transaction sizes
+    pub bytes: u64,
+    /// the total memory usage for the mempool
+    pub usage: u64,
+}
 
-    let json_data_for_request = String::from(format!(
-        "{{\"noOrphans\":{0}, \"logicalTimes\":{1}}}",
-        temp_no_orphans, temp_logical_times
-    ));
-    /*method_body = String::from("[")
-                    + &high.to_string()
-                    + &String::from(",")
-                    + &low.to_string()
-                    + &String::from(",\"")
-                    + &json_data_for_request
-                    + &String::from("\"]");
-                    print!("{}", method_body);
-    */
-    //alternative
-    method_body = String::from("[")
-        + &high.to_string()
-        + &String::from(",")
-        + &low.to_string()
-        + &String::from("]");
-
-    let method_name: String = String::from("getblockhashes");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(SomeUser.clone(), data);
-    return result;
+/// The script of a transaction output.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScriptPubKey {
+    /// scriptPubKey in assembly format
+    pub asm: String,
+    /// scriptPubKey in hex format
+    pub hex: String,
+    /// the number of required signatures
+    #[serde(rename = "reqSigs")]
+    pub req_sigs: Option<u32>,
+    /// the type, e.g. pubkeyhash
+    #[serde(rename = "type")]
+    pub script_type: String,
+    /// an array of Komodo addresses
+    #[serde(default)]
+    pub addresses: Vec<String>,
 }
 
-/// The getblockheader method returns information about the indicated block.
-/// The verbose input is optional. If verbose is false, the method returns a string that is serialized,
-/// hex-encoded data for the indicated blockheader. If verbose is true, the method returns a json object
-/// with information about the indicated blockheader.
-///
-/// # Arguments
-/// * `hash`	(string, required)	the block hash
-/// * `verbose`	(boolean, optional, default=true)	true returns a json object, false returns hex-encoded data
-/// # Response (verbose = `true`)
-/// * `hash`	(string)	the block hash (same as provided)
-/// * `confirmations`	(numeric)	a confirmation number that is aware of the dPoW security service
-/// * `rawconfirmations`	(numeric)	the raw confirmations (number of blocks on top of this block); if the block is not on the main chain, a value of -1 is returned
-/// * `height`	(numeric)	the block height or index
-/// * `version`	(numeric)	the block version
-/// * `merkleroot`	(string)	the merkle root
-/// * `time`	(numeric)	the block time in seconds since epoch (Jan 1 1970 GMT)
-/// * `nonce`	(numeric)	the nonce
-/// * `bits`	(string)	the bits
-/// * `difficulty`	(numeric)	the difficulty
-/// * `previousblockhash`	(string)	the hash of the previous block
-/// * `nextblockhash`	(string)	the hash of the next block
-/// # Response (verbose = `false`)
-/// * `data`	(string)	a string that is serialized hex-encoded data for the indicated block
-/// %%%
-///
-pub fn get_block_header(
-    SomeUser: komodorpcutil::KomodoRPC,
-    hash: String,
-    verbose: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    /*
-    Arguments
-    Name	Type	Description
-    "hash"	(string, required)	the block hash
-    verbose	(boolean, optional, default=true)	true returns a json object, false returns hex-encoded data
-    */
-    let temp_verbose: bool = verbose.unwrap_or(true);
-    let method_name: String = String::from("getblockheader");
-    let method_body: String;
-    // if verbose is true
-
-    if temp_verbose {
-        method_body = String::from("[\"") + &hash + &String::from("\", true]");
-    } else {
-        method_body = String::from("[\"") + &hash + &String::from("\", false]");
-    }
+/// The object returned by gettxout for an unspent output.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TxOut {
+    /// the block hash
+    pub bestblock: String,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: i64,
+    /// the raw confirmations (number of blocks on top of this block with this transaction)
+    pub rawconfirmations: Option<i64>,
+    /// the transaction value
+    pub value: Amount,
+    /// the script of the output
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: ScriptPubKey,
+    /// the version
+    pub version: i64,
+    /// whether this is a coinbase transaction
+    pub coinbase: bool,
+}
 
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(SomeUser.clone(), data);
-    return result;
+/// The object returned by gettxoutsetinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TxOutSetInfo {
+    /// the current block height (index)
+    pub height: u64,
+    /// the best block hash hex
+    pub bestblock: String,
+    /// the number of transactions
+    pub transactions: u64,
+    /// the number of output transactions
+    pub txouts: u64,
+    /// the serialized size
+    pub bytes_serialized: u64,
+    /// the serialized hash
+    pub hash_serialized: String,
+    /// the total amount
+    pub total_amount: Amount,
 }
 
-///The getchaintips method returns information about all known tips
-/// in the block tree, including the main chain and any orphaned branches.
-/// # Arguments
-/// * `(none)`
-/// # Response
-/// *`height`	(numeric)	the height of the chain tip
-/// *`hash`	(string)	the block hash of the tip
-/// *`branchlen`	(numeric)	0 for main chain
-/// *`status`	(string)	`active` for the main chain
-/// *`height`	(numeric)	the height of the branch tip
-/// *`hash`	(string)	the blockhash of the branch tip
-/// *`branchlen`	(numeric)	the length of the branch connecting the tip to the main chain
-/// *`status`	(string)	the status of the chain
-/// %%%
-pub fn get_chain_tips(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getchaintips");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The object returned by kvsearch.
+/// Only coin, currentheight, key and keylen are present when the key is not found.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KvEntry {
+    /// the chain on which the key is stored
+    pub coin: String,
+    /// the current height of the chain
+    pub currentheight: u64,
+    /// the key
+    pub key: String,
+    /// the length of the key
+    pub keylen: u64,
+    /// a hex string representing the owner of the key
+    pub owner: Option<String>,
+    /// the height at which the key was stored
+    pub height: Option<u64>,
+    /// the height at which the key will expire
+    pub expiration: Option<u64>,
+    /// 1 if the key was created with a password; 0 otherwise
+    pub flags: Option<u64>,
+    /// the stored value
+    pub value: Option<String>,
+    /// the amount of characters stored
+    pub valuesize: Option<u64>,
 }
 
-/// The method getchaintxstats returns statistics about
-/// the total number and rate of transactions in the chain.
-///
-/// # Arguments
-/// * `nblocks`	(numeric, optional)	the number of blocks in the averaging window.
-/// * `blockhash`	(string, optional)	the hash of the block which ends the window
-/// # Response
-/// * `time`	(numeric)	the timestamp for the final block in the window in UNIX format
-/// * `txcount`	(numeric)	the total number of transactions in the chain up to this point
-/// * `window_final_block_hash`	(string)	the hash of the final block in the window
-/// * `window_block_count`	(numeric)	the size of the window in the number of blocks
-/// * `window_tx_count`	(numeric)	the number of transactions in the window; this value is only returned if window_block_count is > 0.
-/// * `window_interval`	(numeric)	the elapsed time in the window in seconds; this value is only returned if window_block_count is > 0.
-/// * `txrate`	(numeric)	the average rate of transactions per second in the window; this value is only returned if window_interval is > 0.
-/// %%%
-pub fn get_chain_tx_stats(
-    SomeUser: komodorpcutil::KomodoRPC,
-    n_blocks: Option<u32>,
-    block_hash: Option<String>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_nblocks = n_blocks.unwrap_or(0); //Default value is 0
-    let temp_block_hash: String = block_hash.unwrap_or("".to_string());
-    if (temp_nblocks > 0) && !(temp_block_hash.is_empty()) {
-        method_body = String::from("[\"blockhash\": \"")
-            + &temp_block_hash.to_string()
-            + &String::from(" \", \"nblocks\" : ")
-            + &temp_nblocks.to_string()
-            + &String::from("]");
-    } else if (temp_nblocks <= 0) && !(temp_block_hash.is_empty()) {
-        method_body = String::from("[\"blockhash\": \"")
-            + &temp_block_hash.to_string()
-            + &String::from(" \"]");
-    } else if (temp_nblocks > 0) && (temp_block_hash.is_empty()) {
-        method_body =
-            String::from("[\"nblocks\": ") + &temp_nblocks.to_string() + &String::from("]");
-    } else {
-        method_body = String::from("[]");
-    }
-    let method_name: String = String::from("getchaintxstats");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// One entry of the "notaries" array of the notaries method.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Notary {
+    /// the public signing key of the notary node
+    pub pubkey: String,
+    /// the public BTC address the notary node uses to create notarizations
+    #[serde(rename = "BTCaddress")]
+    pub btc_address: String,
+    /// the public KMD address the notary node uses to create notarizations
+    #[serde(rename = "KMDaddress")]
+    pub kmd_address: String,
 }
 
-/// The getdifficulty method returns the proof-of-work difficulty as a multiple of the minimum difficulty.
-/// # Arguments
-/// * `(none)`
-/// # Response
-/// * `number`	(numeric)	the proof-of-work difficulty as a multiple of the minimum difficulty
-/// %%%
-pub fn get_difficulty(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getdifficulty");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The object returned by the notaries method.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NotaryList {
+    /// the notary nodes
+    pub notaries: Vec<Notary>,
+    /// the number of notary nodes
+    pub numnotaries: u32,
+    /// the block height number at which the notary-node information applies
+    pub height: u64,
+    /// the timestamp at which the notary-node information applies
+    pub timestamp: u64,
 }
-///The getlastsegidstakes method returns an object containing the number
-/// of blocks staked by each segid in the last X number of blocks,
-///  where the value of X is equal to the indicated depth.
-///
-/// # Arguments
-/// * `depth`	(numeric, required)	the number of blocks to scan, starting from the current height and working backwards
-/// # Response
-/// * `NotSet`	(numeric)	the number of blocks that have no SegId set
-///* `PoW`	(numeric)	the number of blocks created through PoW
-///* `PoSPerc`	(numeric)	the percentage of blocks created through PoS
-///* `SegIds`	(json object)	the json containing the data of number of blocks in each SegId
-///* `n`	(numeric)	the number of blocks staked from SegId n in the last X blocks, where X is equal to the indicated depth
-/// %%%
-pub fn get_last_segid_stakes(
-    SomeUser: komodorpcutil::KomodoRPC,
-    depth: u32,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getlastsegidstakes");
-    let method_body: String = String::from(format!("[{}]", depth));
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+
+/// The object returned by getlastsegidstakes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SegidStakes {
+    /// the number of blocks that have no SegId set
+    #[serde(rename = "NotSet")]
+    pub not_set: u64,
+    /// the number of blocks created through PoW
+    #[serde(rename = "PoW")]
+    pub pow: u64,
+    /// the percentage of blocks created through PoS
+    #[serde(rename = "PoSPerc")]
+    pub pos_perc: f64,
+    /// the number of blocks staked by each segid
+    #[serde(rename = "SegIds")]
+    pub seg_ids: BTreeMap<u8, u64>,
 }
 
-/// The getmempoolinfo method returns details on the active state of the transaction memory pool
-///
-/// # Arguments
-/// * `(none)`
-///
-/// # Resonse
-/// * `size`	(numeric)	the current transaction count
-/// * `bytes`	(numeric)	the sum of all transaction sizes
-/// * `usage`	(numeric)	the total memory usage for the mempool
-/// %%%
-pub fn get_mempool_info(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getmempoolinfo");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// Calls of the blockchain API, borrowed from a KomodoClient with `client.blockchain()`.
+pub struct Blockchain<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
 }
 
-///The getrawmempool method returns all transaction ids in the memory pool as a json array of transaction ids.
-/// The verbose input is optional and is false by default. When it is true, the method instead returns a json object with various related data.
-///
-/// # Arguments
-/// * `verbose`	(boolean, optional, default=false)	true for a json object, false for a json array of transaction ids
-///
-/// # Response (verbose = false)
-/// * `transaction_id`	(string)	the transaction id
-///
-/// # Response (verbose = true)
-/// * `transaction_id`: { .... }	(json object)
-/// * `size`	(numeric)	the transaction size in bytes
-/// * `fee`	(numeric)	the transaction fee
-/// * `time`	(numeric)	the local time transaction entered pool in seconds since 1 Jan 1970 GMT
-/// * `height`	(numeric)	the block height wherein the transaction entered the mempool
-/// * `startingpriority`	(numeric)	the priority when the transaction entered the mempool
-/// * `currentpriority`	(numeric)	the transaction priority at the current height
-/// * `depends`: { ... }	(array)	unconfirmed transactions used as inputs for this transaction
-/// * `transaction_id`	(string)	the parent transaction id
-/// %%%
-pub fn get_raw_mempool(
-    SomeUser: komodorpcutil::KomodoRPC,
-    verbose: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let temp_verbose: bool = verbose.unwrap_or(false);
-    let method_name: String = String::from("getrawmempool");
-
-    let method_body: String;
-    if temp_verbose {
-        method_body = String::from("[true]");
-    } else {
-        method_body = String::from("[false]");
+impl<'a, C> Blockchain<'a, C> {
+    /// The coinsupply method returns the coin supply information for the indicated block height.
+    /// If no height is given, the method defaults to the blockchain's current height.
+    /// # Arguments
+    /// * `height`	(integer, optional)	the desired block height
+    ///# Response
+    /// * `result`	(string)	whether the request was successful
+    /// * `coin`	(string)	the ticker symbol of the coin for Smart Chains, otherwise KMD
+    /// * `height`	(integer)	the height of this coin supply data
+    /// * `supply`	(float)	the transparent coin supply
+    /// * `zfunds`	(float)	the shielded coin supply (in zaddrs)
+    /// * `sprout`	(float)	the sprout coin supply (in zcaddrs)
+    /// * `total`	(float)	the total coin supply, i.e. sum of supply + zfunds
+    /// %%%
+    pub fn coin_supply(&self, height_supplied: Option<u32>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("coinsupply");
+        let mut method_body = Vec::new();
+        let height = height_supplied.unwrap_or(0); //Default value is 0
+        if height > 0 {
+            // the daemon reads the height as a string
+            method_body.push(json!(height.to_string()));
+        }
+        self.client.call(&method_name, method_body)
     }
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
 
-///The getspentinfo method returns the transaction id and index where the given output is spent.
-/// The method requires spentindex to be enabled.
-/// # Arguments
-/// * `txid`	(string)	the hex string of the transaction id
-/// * `index`	(number)	the output's index
-/// # Response
-/// * `txid`	(string)	the transaction id
-/// * `index`	(number)	the spending input index
-/// %%%
-///
-pub fn get_spent_info(
-    SomeUser: komodorpcutil::KomodoRPC,
-    tx_id: String,
-    index: u32,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    method_body = String::from("[\"txid\": \"")
-        + &tx_id.to_string()
-        + &String::from(" \", \"index\" : ")
-        + &index.to_string()
-        + &String::from("]");
-    let method_name: String = String::from("getspentinfo");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
-/// The gettxout method returns details about an unspent transaction output.
-/// # Arguments
-/// * `txid`	(string, required)	the transaction id
-/// * `vout`	(numeric, required)	the vout value
-/// * `includemempool`	(boolean, optional)	whether to include the mempool
-/// # Response
-/// * `bestblock`	(string)	the block hash
-/// * `confirmations`	(numeric)	a confirmation number that is aware of the dPoW security service aware
-/// * `rawconfirmations`	(numeric)	the raw confirmations (number of blocks on top of this block with this transaction)
-/// * `value`	(numeric)	the transaction value
-/// * `scriptPubKey`:	(json object)
-/// * `asm`	(string)	scriptPubKey in assembly format
-/// * `hex`	(string)	scriptPubKey in hex format
-/// * `reqSigs`	(numeric)	the number of required signatures
-/// * `type`	(string)	the type, e.g. pubkeyhash
-/// * `addresses`	(array of strings)	an array of Komodo addresses
-/// * `address`	(string)	the blockchain address
-/// * `version`	(numeric)	the version
-/// * `coinbase`	(boolean)	whether this is a coinbase transaction
-/// %%%
-pub fn get_tx_out(
-    SomeUser: komodorpcutil::KomodoRPC,
-    tx_id: String,
-    vout: u32,
-    include_mempool: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    if let Some(x) = include_mempool {
-        method_body = String::from("[\"")
-            + &tx_id.to_string()
-            + &String::from(" \", ")
-            + &vout.to_string()
-            + &String::from(format!(", {:?}", x))
-            + &String::from("]");
-    } else {
-        method_body = String::from("[\"")
-            + &tx_id.to_string()
-            + &String::from(",")
-            + &vout.to_string()
-            + &String::from(" ]");
+    /// The getbestblockhash method returns the hash of the best (tip) block in the longest block chain.
+    /// # Arguments
+    /// * `(none)`
+    /// # Response
+    /// * `hex`	(string)	the block hash, hex encoded
+    pub fn get_best_block_hash(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getbestblockhash");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
     }
-    let method_name: String = String::from("gettxout");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
-///The gettxoutproof method returns a hex-encoded proof showing that the indicated transaction was included in a block.
-///The gettxoutproof method relies on the txindex runtime parameter.
-/// This parameter is enabled by default on all KMD-based blockchains, and should never be disabled.
-/// # Arguments
-/// * `txid`	(string)	a transaction hash
-/// * `blockhash`	(string, optional)	if specified, the method looks for the relevant transaction id in this block hash
-/// # Response
-/// * `data`	(string)	a string that is a serialized, hex-encoded data for the proof
-/// %%%
-pub fn get_tx_out_proof(
-    SomeUser: komodorpcutil::KomodoRPC,
-    tx_id: String,
-    block_hash: Option<String>,
-) -> Result<String, reqwest::Error> {
-    let temp_block_hash: String = block_hash.unwrap_or("".to_string());
-    let method_body: String;
-    if (!temp_block_hash.is_empty()) {
-        method_body = String::from("[\"txid\": \"")
-            + &tx_id.to_string()
-            + &String::from(" \", \"blockhash\" : ")
-            + &temp_block_hash.to_string()
-            + &String::from(" ]");
-    } else {
-        method_body = String::from("[\"txid\": \"") + &tx_id.to_string() + &String::from(" \"]");
+    /// The getblock method returns the block's relevant state information.
+    /// get_block asks for the json object (verbose = true) with information about the indicated block;
+    /// get_block_hex asks for the serialized hex-encoded data (verbose = false).
+    /// # Arguments
+    /// * `hash OR height`	(string OR number), respectively	the block hash OR the block height
+    ///
+    /// # Response (verbose = true)
+    /// * `hash`	(string)	the block hash (same as provided hash)
+    /// * `confirmations`	(numeric)	a confirmation number that is aware of the dPoW security service
+    /// * `rawconfirmations`	(numeric)	the raw confirmations (number of blocks on top of this block); the returned value is -1 if the block is not on the main chain
+    /// * `size`	(numeric)	the block size
+    /// * `height`	(numeric)	the block height or index (same as provided height)
+    /// * `version`	(numeric)	the block version
+    /// * `merkleroot`	(string)	the merkle root
+    /// * `tx` : [ `transaction_id` ,...]	(array of strings)
+    /// * `time`	(numeric)	the block time in seconds since epoch (Jan 1 1970 GMT)
+    /// * `nonce`	(numeric)	the nonce
+    /// * `bits`	(string)	the bits
+    /// * `difficulty`	(numeric)	the difficulty
+    /// * `previousblockhash`	(string)	the hash of the previous block
+    /// * `nextblockhash`	(string)	the hash of the next block
+    ///
+    /// # Response (Verbose = False)
+    /// * `data` (string)	a string that is serialized, hex-encoded data for the indicated block
+    /// %%%
+    pub fn get_block(&self, height_or_hash: String) -> Response<C, Block>
+    where
+        C: Transport<Block>,
+    {
+        let method_name: String = String::from("getblock");
+        let method_body = vec![json!(height_or_hash), json!(true)];
+        self.client.call(&method_name, method_body)
     }
-    let method_name: String = String::from("gettxoutproof");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
 
-/// The gettxoutsetinfo method returns statistics about the unspent transaction output set.
-/// # Arguments
-/// * `(none)`
-///
-/// # Response
-/// * `height`	(numeric)	the current block height (index)
-/// * `bestblock`	(string)	the best block hash hex
-/// * `transactions`	(numeric)	the number of transactions
-/// * `txouts`	(numeric)	the number of output transactions
-/// * `bytes_serialized`	(numeric)	the serialized size
-/// * `hash_serialized`	(string)	the serialized hash
-/// * `total_amount`	(numeric)	the total amount
-pub fn tx_out_set_info(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("gettxoutsetinfo");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
+    /// getblock with verbose = false.
+    /// Returns the serialized, hex-encoded data for the indicated block.
+    /// See get_block for the arguments.
+    pub fn get_block_hex(&self, height_or_hash: String) -> Response<C, String>
+    where
+        C: Transport<String>,
+    {
+        let method_name: String = String::from("getblock");
+        let method_body = vec![json!(height_or_hash), json!(false)];
+        self.client.call(&method_name, method_body)
+    }
+    /// The getblockchaininfo method returns a json object containing state information about blockchain processing.
+    ///
+    /// # Arguments
+    ///	* `(none)`
+    ///
+    /// # Response
+    /// * `chain`	(string)	the current network name, as defined in BIP70 (main, test, regtest)
+    /// * `blocks`	(numeric)	the current number of blocks processed in the server
+    /// * `headers`	(numeric)	the current number of headers we have validated
+    /// * `bestblockhash`	(string)	the hash of the currently best block
+    /// * `difficulty`	(numeric)	the current difficulty
+    /// * `verificationprogress`	(numeric)	an estimate of verification progress [0..1]
+    /// * `chainwork`	(string)	the total amount of work in the active chain, in hexadecimal
+    /// * `pruned`	(bool)	whether the current state is in pruning mode; if true, the blockchain will not keep all transaction and block information, to preserve disk space
+    /// * `size_on_disk`	(numeric)	the size of the blockchain on disk, measured in bytes
+    /// * `commitments`	(numeric)	the current number of note commitments in the commitment tree
+    /// * `softforks`: { ..... }	(array)	the status of softforks in progress
+    /// * `id`	(string)	the name of the softfork
+    /// * `version`	(numeric)	the block version
+    /// * `enforce`: { ... }	(object)	the progress toward enforcing the softfork rules for blocks of the new version
+    /// * `status`	(boolean)	true if threshold reached
+    /// * `found`	(numeric)	the number of blocks with the new version found
+    /// * `required`	(numeric)	the number of blocks required to trigger
+    /// * `window`	(numeric)	the maximum size of the examined window of recent blocks
+    /// * `reject`: { ... }	(object)	the progress toward rejecting pre-softfork blocks (same fields as `enforce`)
+    /// * `upgrades`:	(object)	the status of network upgrades
+    /// * `xxxxxxxxx_string`:	(string)	the branch ID of the upgrade
+    /// * `name`	(string)	the name of upgrade
+    /// * `activationheight`	(numeric)	the block height of activation
+    /// * `status`	(string)	the status of the upgrade
+    /// * `info`	(string)	additional information about the upgrade
+    /// * `consensus`: { ..... }	(object)	branch IDs of the current and upcoming consensus rules
+    /// * `chaintip`	(string)	branch ID used to validate the current chain tip
+    /// * `nextblock`	(string)	branch ID under which the next block will be validated
+    /// %%%
+    pub fn get_blockchain_info(&self) -> Response<C, BlockchainInfo>
+    where
+        C: Transport<BlockchainInfo>,
+    {
+        let method_name: String = String::from("getblockchaininfo");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
 
-/// The kvsearch method searches for a key stored via the kvupdate command.
-/// This feature is only available for Smart Chains.
-/// # Arguments
-/// `key`	(string, required)	the key for which the user desires to search the chain
-///
-/// # Response
-/// * `coin`	(string)	the chain on which the key is stored
-/// * `currentheight`	(numeric)	the current height of the chain
-/// * `key`	(string)	the key
-/// * `keylen`	(string)	the length of the key
-/// * `owner`	(string)	a hex string representing the owner of the key
-/// * `height`	(numeric)	the height at which the key was stored
-/// * `expiration`	(numeric)	the height at which the key will expire
-/// * `flags`	(numeric)	1 if the key was created with a password; 0 otherwise
-/// * `value`	(string)	the stored value
-/// * `valuesize`	(string)	the amount of characters stored
-pub fn kv_search(
-    SomeUser: komodorpcutil::KomodoRPC,
-    key: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("kvsearch");
-    let method_body: String = String::from("[\"") + &key.to_string() + &String::from("\"]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
+    ///	The getblockcount method returns the number of blocks in the best valid block chain.
+    ///
+    /// # Arguments
+    ///	* `(none)`
+    ///
+    /// # Response
+    /// data	(numeric)	the current block count
+    /// %%%
+
+    pub fn get_block_count(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getblockcount");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
 
-///The kvupdate method stores a key/value pair via OP_RETURN.
-/// This feature is available only for Smart Chains. The maximum value memory size is 8kB.
-///
-/// # Arguments
-/// * `key`	(string, required)	key (should be unique)
-/// * `value`	(string, required)	value
-/// * `days`	(numeric, required)	amount of days before the key expires (1440 blocks/day); minimum 1 day
-/// * `passphrase`	(string, optional)	passphrase required to update this key
-///
-/// # Response
-/// * `coin`	(string)	the chain on which the key is stored
-/// * `height`	(numeric)	the height at which the key was stored
-/// * `expiration`	(numeric)	the height at which the key will expire
-/// * `flags`	(string)	the amount of days the key will be stored
-/// * `key`	(numeric)	the stored key
-/// * `keylen`	(numeric)	the length of the key
-/// * `value`	(numeric)	the stored value
-/// * `valuesize`	(string)	the length of the stored value
-/// * `fee`	(string)	the transaction fee paid to store the key
-/// * `txid`	(string)	the transaction id
-/// %%%
-pub fn kv_update(
-    SomeUser: komodorpcutil::KomodoRPC,
-    key: String,
-    value: String,
-    days: u32,
-    pass_phrase: Option<String>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("kvupdate");
-    let method_body: String;
-    let temp_pass_phrase: String = pass_phrase.unwrap_or("".to_string());
-    if (temp_pass_phrase.is_empty()) {
-        method_body = String::from("[\"")
-            + &key.to_string()
-            + &String::from("\", \"")
-            + &value.to_string()
-            + &String::from("\", \"")
-            + &days.to_string()
-            + &String::from("\"]");
-    } else {
-        method_body = String::from("[\"")
-            + &key.to_string()
-            + &String::from("\", \"")
-            + &value.to_string()
-            + &String::from("\", \"")
-            + &days.to_string()
-            + &String::from("\", \"")
-            + &temp_pass_phrase.to_string()
-            + &String::from("\"]");
+    ///The getblockhash method returns the hash of the indicated block index, according to the best blockchain at the time provided.
+    /// # Arguments
+    /// * `index`	(numeric, required)	the block index
+    /// # Response
+    /// * `hash`	(string)	the block hash
+    pub fn get_block_hash(&self, index: u32) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getblockhash");
+        let method_body = vec![json!(index)];
+        self.client.call(&method_name, method_body)
+    }
+    ///The getblockhashes method returns an array of hashes of blocks within the timestamp range provided.
+    ///The method requires timestampindex to be enabled.
+    /// # Arguments
+    /// * `high`	(numeric, required)	the newer block timestamp
+    /// * `low`	(numeric, required)	the older block timestamp
+    /// * `options`	(string, required)	a json object
+    /// * `noOrphans`	(boolean)	a value of true implies that the method will only include blocks on the main chain
+    /// * `logicalTimes`	(boolean)	a value of true implies that the method will only include logical timestamps with hashes
+    /// # Response
+    /// * `hash`	(string)	the block hash
+    /// * `blockhash`	(string)	the block hash
+    /// * `logicalts`	(numeric)	the logical timestamp
+    /// %%%
+    ///
+    /// SKIPPED DUE TO LACK OF PROPER DOCUMENTATION IN KOMODO PLATFORM
+    pub fn get_block_hashes(
+        &self,
+        high: u32,
+        low: u32,
+        no_orphans: bool,
+        logical_times: bool,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getblockhashes");
+        let method_body = vec![
+            json!(high),
+            json!(low),
+            json!({ "noOrphans": no_orphans, "logicalTimes": logical_times }),
+        ];
+        self.client.call(&method_name, method_body)
     }
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
 
-///The minerids method returns information about the notary nodes and external miners at a specific block height.
-/// The response will calculate results according to the 2000 blocks proceeding the indicated "height" block.
-/// # Arguments
-/// * `heights`	(number)	the block height for the query
-/// # Response
-/// * `mined`:
-/// * `notaryid`	(number)	the id of the specific notary node
-/// * `kmdaddress`	(string)	the KMD address of the notary node
-/// * `pubkey`	(string)	the public signing key of the notary node
-/// * `blocks`	(number)
-/// %%%
-pub fn miner_ids(
-    SomeUser: komodorpcutil::KomodoRPC,
-    height: u32,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("minerids");
-    let method_body: String = String::from(format!("[\"{:?}\"]", height));
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(SomeUser.clone(), data);
-    return result;
-}
+    /// The getblockheader method returns information about the indicated block.
+    /// get_block_header asks for the json object (verbose = true) with information about the indicated
+    /// blockheader; get_block_header_hex asks for the serialized, hex-encoded data (verbose = false).
+    ///
+    /// # Arguments
+    /// * `hash`	(string, required)	the block hash
+    /// # Response (verbose = `true`)
+    /// * `hash`	(string)	the block hash (same as provided)
+    /// * `confirmations`	(numeric)	a confirmation number that is aware of the dPoW security service
+    /// * `rawconfirmations`	(numeric)	the raw confirmations (number of blocks on top of this block); if the block is not on the main chain, a value of -1 is returned
+    /// * `height`	(numeric)	the block height or index
+    /// * `version`	(numeric)	the block version
+    /// * `merkleroot`	(string)	the merkle root
+    /// * `time`	(numeric)	the block time in seconds since epoch (Jan 1 1970 GMT)
+    /// * `nonce`	(numeric)	the nonce
+    /// * `bits`	(string)	the bits
+    /// * `difficulty`	(numeric)	the difficulty
+    /// * `previousblockhash`	(string)	the hash of the previous block
+    /// * `nextblockhash`	(string)	the hash of the next block
+    /// # Response (verbose = `false`)
+    /// * `data`	(string)	a string that is serialized hex-encoded data for the indicated block
+    /// %%%
+    ///
+    pub fn get_block_header(&self, hash: String) -> Response<C, BlockHeader>
+    where
+        C: Transport<BlockHeader>,
+    {
+        let method_name: String = String::from("getblockheader");
+        let method_body = vec![json!(hash), json!(true)];
+        self.client.call(&method_name, method_body)
+    }
 
-/// The notaries method returns the public key, BTC address, and KMD address for each Komodo notary node.
-/// Either or both of the height and timestamp parameters will suffice.
-/// # Arguments
-/// * `height`	(number)	the block height desired for the query
-/// * `timestamp`	(number)	the timestamp of the block desired for the query
-/// # Response
-/// * `notaries`: [ ... ]	(array)
-/// * `pubkey`	(string)	the public signing key of the indicated notary node, used on the KMD network to create notary-node authorized transactions
-/// * `BTCaddress`	(string)	the public BTC address the notary node uses on the BTC blockchain to create notarizations
-/// * `KMDaddress`	(string)	the public KMD address the notary node uses on the KMD blockchain to create notarizations
-/// * `numnotaries`	(number)	the number of notary nodes; typically this value is 64, but the value may vary on rare circumstances, such as during election seasons
-/// * `height`	(number)	the block height number at which the notary-node information applies
-/// * `timestamp`	(number)	the timestamp at which the notary-node information applies
-/// %%%
-pub fn notaries(
-    SomeUser: komodorpcutil::KomodoRPC,
-    height: u32,
-    timestamp: u32,
-) -> Result<String, reqwest::Error> {
-    //????? TODO Review this method again ??????
-    // what happens when two parameters are sent together
-    // Do we need to name the paramater too?
-    let method_name: String = String::from("notaries");
-    let method_body: String;
-
-    method_body = String::from("[\"")
-        + &height.to_string()
-        + &String::from("\",\"")
-        + &timestamp.to_string()
-        + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(SomeUser.clone(), data);
-    return result;
-}
+    /// getblockheader with verbose = false.
+    /// Returns the serialized, hex-encoded data for the indicated blockheader.
+    /// See get_block_header for the arguments.
+    pub fn get_block_header_hex(&self, hash: String) -> Response<C, String>
+    where
+        C: Transport<String>,
+    {
+        let method_name: String = String::from("getblockheader");
+        let method_body = vec![json!(hash), json!(false)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///The getchaintips method returns information about all known tips
+    /// in the block tree, including the main chain and any orphaned branches.
+    /// # Arguments
+    /// * `(none)`
+    /// # Response
+    /// *`height`	(numeric)	the height of the chain tip
+    /// *`hash`	(string)	the block hash of the tip
+    /// *`branchlen`	(numeric)	0 for main chain
+    /// *`status`	(string)	`active` for the main chain
+    /// *`height`	(numeric)	the height of the branch tip
+    /// *`hash`	(string)	the blockhash of the branch tip
+    /// *`branchlen`	(numeric)	the length of the branch connecting the tip to the main chain
+    /// *`status`	(string)	the status of the chain
+    /// %%%
+    pub fn get_chain_tips(&self) -> Response<C, Vec<ChainTip>>
+    where
+        C: Transport<Vec<ChainTip>>,
+    {
+        let method_name: String = String::from("getchaintips");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// The method getchaintxstats returns statistics about
+    /// the total number and rate of transactions in the chain.
+    ///
+    /// # Arguments
+    /// * `nblocks`	(numeric, optional)	the number of blocks in the averaging window.
+    /// * `blockhash`	(string, optional)	the hash of the block which ends the window
+    /// # Response
+    /// * `time`	(numeric)	the timestamp for the final block in the window in UNIX format
+    /// * `txcount`	(numeric)	the total number of transactions in the chain up to this point
+    /// * `window_final_block_hash`	(string)	the hash of the final block in the window
+    /// * `window_block_count`	(numeric)	the size of the window in the number of blocks
+    /// * `window_tx_count`	(numeric)	the number of transactions in the window; this value is only returned if window_block_count is > 0.
+    /// * `window_interval`	(numeric)	the elapsed time in the window in seconds; this value is only returned if window_block_count is > 0.
+    /// * `txrate`	(numeric)	the average rate of transactions per second in the window; this value is only returned if window_interval is > 0.
+    /// %%%
+    pub fn get_chain_tx_stats(
+        &self,
+        n_blocks: Option<u32>,
+        block_hash: Option<String>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getchaintxstats");
+        let mut method_body = Vec::new();
+        if n_blocks.is_some() || block_hash.is_some() {
+            // a missing nblocks is sent as null so that blockhash stays in second position
+            method_body.push(json!(n_blocks));
+        }
+        if let Some(temp_block_hash) = block_hash {
+            method_body.push(json!(temp_block_hash));
+        }
+        self.client.call(&method_name, method_body)
+    }
 
-///  The verifychain method verifies the coin daemon's blockchain database.
-///  Depending on the state of your blockchain database and daemon, this call can take a prolonged period of time to complete.
-/// # Arguments
-/// * `checklevel`	(numeric, optional, 0-4, default=3)	indicates the thoroughness of block verification
-/// * `numblocks`	(numeric, optional, default=288, 0=all)	indicates the number of blocks to verify
-/// # Response
-/// * `true/false`	(boolean)	whether the verification was successful
-/// %%%
-pub fn verify_chain(
-    SomeUser: komodorpcutil::KomodoRPC,
-    check_level: Option<u8>,
-    num_blocks: Option<u16>,
-) -> Result<String, reqwest::Error> {
-    let temp_check_level: u8 = check_level.unwrap_or(3);
-    let temp_num_blocks: u16 = num_blocks.unwrap_or(288);
-    let method_name: String = String::from("verifychain");
-    let method_body = String::from("[")
-        + &temp_check_level.to_string()
-        + &String::from(",")
-        + &temp_num_blocks.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(SomeUser.clone(), data);
-    return result;
+    /// The getdifficulty method returns the proof-of-work difficulty as a multiple of the minimum difficulty.
+    /// # Arguments
+    /// * `(none)`
+    /// # Response
+    /// * `number`	(numeric)	the proof-of-work difficulty as a multiple of the minimum difficulty
+    /// %%%
+    pub fn get_difficulty(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getdifficulty");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+    ///The getlastsegidstakes method returns an object containing the number
+    /// of blocks staked by each segid in the last X number of blocks,
+    ///  where the value of X is equal to the indicated depth.
+    ///
+    /// # Arguments
+    /// * `depth`	(numeric, required)	the number of blocks to scan, starting from the current height and working backwards
+    /// # Response
+    /// * `NotSet`	(numeric)	the number of blocks that have no SegId set
+    ///* `PoW`	(numeric)	the number of blocks created through PoW
+    ///* `PoSPerc`	(numeric)	the percentage of blocks created through PoS
+    ///* `SegIds`	(json object)	the json containing the data of number of blocks in each SegId
+    ///* `n`	(numeric)	the number of blocks staked from SegId n in the last X blocks, where X is equal to the indicated depth
+    /// %%%
+    pub fn get_last_segid_stakes(&self, depth: u32) -> Response<C, SegidStakes>
+    where
+        C: Transport<SegidStakes>,
+    {
+        let method_name: String = String::from("getlastsegidstakes");
+        let method_body = vec![json!(depth)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// The getmempoolinfo method returns details on the active state of the transaction memory pool
+    ///
+    /// # Arguments
+    /// * `(none)`
+    ///
+    /// # Resonse
+    /// * `size`	(numeric)	the current transaction count
+    /// * `bytes`	(numeric)	the sum of all transaction sizes
+    /// * `usage`	(numeric)	the total memory usage for the mempool
+    /// %%%
+    pub fn get_mempool_info(&self) -> Response<C, MempoolInfo>
+    where
+        C: Transport<MempoolInfo>,
+    {
+        let method_name: String = String::from("getmempoolinfo");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    ///The getrawmempool method returns all transaction ids in the memory pool as a json array of transaction ids.
+    /// The verbose input is optional and is false by default. When it is true, the method instead returns a json object with various related data.
+    ///
+    /// # Arguments
+    /// * `verbose`	(boolean, optional, default=false)	true for a json object, false for a json array of transaction ids
+    ///
+    /// # Response (verbose = false)
+    /// * `transaction_id`	(string)	the transaction id
+    ///
+    /// # Response (verbose = true)
+    /// * `transaction_id`: { .... }	(json object)
+    /// * `size`	(numeric)	the transaction size in bytes
+    /// * `fee`	(numeric)	the transaction fee
+    /// * `time`	(numeric)	the local time transaction entered pool in seconds since 1 Jan 1970 GMT
+    /// * `height`	(numeric)	the block height wherein the transaction entered the mempool
+    /// * `startingpriority`	(numeric)	the priority when the transaction entered the mempool
+    /// * `currentpriority`	(numeric)	the transaction priority at the current height
+    /// * `depends`: { ... }	(array)	unconfirmed transactions used as inputs for this transaction
+    /// * `transaction_id`	(string)	the parent transaction id
+    /// %%%
+    pub fn get_raw_mempool(&self, verbose: Option<bool>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getrawmempool");
+        let temp_verbose: bool = verbose.unwrap_or(false);
+        let method_body = vec![json!(temp_verbose)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///The getspentinfo method returns the transaction id and index where the given output is spent.
+    /// The method requires spentindex to be enabled.
+    /// # Arguments
+    /// * `txid`	(string)	the hex string of the transaction id
+    /// * `index`	(number)	the output's index
+    /// # Response
+    /// * `txid`	(string)	the transaction id
+    /// * `index`	(number)	the spending input index
+    /// %%%
+    ///
+    pub fn get_spent_info(&self, tx_id: String, index: u32) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getspentinfo");
+        let method_body = vec![json!({ "txid": tx_id, "index": index })];
+        self.client.call(&method_name, method_body)
+    }
+    /// The gettxout method returns details about an unspent transaction output.
+    /// # Arguments
+    /// * `txid`	(string, required)	the transaction id
+    /// * `vout`	(numeric, required)	the vout value
+    /// * `includemempool`	(boolean, optional)	whether to include the mempool
+    /// # Response
+    /// * `bestblock`	(string)	the block hash
+    /// * `confirmations`	(numeric)	a confirmation number that is aware of the dPoW security service aware
+    /// * `rawconfirmations`	(numeric)	the raw confirmations (number of blocks on top of this block with this transaction)
+    /// * `value`	(numeric)	the transaction value
+    /// * `scriptPubKey`:	(json object)
+    /// * `asm`	(string)	scriptPubKey in assembly format
+    /// * `hex`	(string)	scriptPubKey in hex format
+    /// * `reqSigs`	(numeric)	the number of required signatures
+    /// * `type`	(string)	the type, e.g. pubkeyhash
+    /// * `addresses`	(array of strings)	an array of Komodo addresses
+    /// * `address`	(string)	the blockchain address
+    /// * `version`	(numeric)	the version
+    /// * `coinbase`	(boolean)	whether this is a coinbase transaction
+    /// * `null` when the output is spent or does not exist, returned as None
+    /// %%%
+    pub fn get_tx_out(
+        &self,
+        tx_id: String,
+        vout: u32,
+        include_mempool: Option<bool>,
+    ) -> Response<C, Option<TxOut>>
+    where
+        C: Transport<Option<TxOut>>,
+    {
+        let method_name: String = String::from("gettxout");
+        let mut method_body = vec![json!(tx_id), json!(vout)];
+        if let Some(x) = include_mempool {
+            method_body.push(json!(x));
+        }
+        self.client.call(&method_name, method_body)
+    }
+    ///The gettxoutproof method returns a hex-encoded proof showing that the indicated transaction was included in a block.
+    ///The gettxoutproof method relies on the txindex runtime parameter.
+    /// This parameter is enabled by default on all KMD-based blockchains, and should never be disabled.
+    /// # Arguments
+    /// * `txid`	(string)	a transaction hash
+    /// * `blockhash`	(string, optional)	if specified, the method looks for the relevant transaction id in this block hash
+    /// # Response
+    /// * `data`	(string)	a string that is a serialized, hex-encoded data for the proof
+    /// %%%
+    pub fn get_tx_out_proof(&self, tx_id: String, block_hash: Option<String>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("gettxoutproof");
+        let mut method_body = vec![json!([tx_id])];
+        if let Some(temp_block_hash) = block_hash {
+            method_body.push(json!(temp_block_hash));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    /// The gettxoutsetinfo method returns statistics about the unspent transaction output set.
+    /// # Arguments
+    /// * `(none)`
+    ///
+    /// # Response
+    /// * `height`	(numeric)	the current block height (index)
+    /// * `bestblock`	(string)	the best block hash hex
+    /// * `transactions`	(numeric)	the number of transactions
+    /// * `txouts`	(numeric)	the number of output transactions
+    /// * `bytes_serialized`	(numeric)	the serialized size
+    /// * `hash_serialized`	(string)	the serialized hash
+    /// * `total_amount`	(numeric)	the total amount
+    pub fn tx_out_set_info(&self) -> Response<C, TxOutSetInfo>
+    where
+        C: Transport<TxOutSetInfo>,
+    {
+        let method_name: String = String::from("gettxoutsetinfo");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// The kvsearch method searches for a key stored via the kvupdate command.
+    /// This feature is only available for Smart Chains.
+    /// # Arguments
+    /// `key`	(string, required)	the key for which the user desires to search the chain
+    ///
+    /// # Response
+    /// * `coin`	(string)	the chain on which the key is stored
+    /// * `currentheight`	(numeric)	the current height of the chain
+    /// * `key`	(string)	the key
+    /// * `keylen`	(string)	the length of the key
+    /// * `owner`	(string)	a hex string representing the owner of the key
+    /// * `height`	(numeric)	the height at which the key was stored
+    /// * `expiration`	(numeric)	the height at which the key will expire
+    /// * `flags`	(numeric)	1 if the key was created with a password; 0 otherwise
+    /// * `value`	(string)	the stored value
+    /// * `valuesize`	(string)	the amount of characters stored
+    pub fn kv_search(&self, key: String) -> Response<C, KvEntry>
+    where
+        C: Transport<KvEntry>,
+    {
+        let method_name: String = String::from("kvsearch");
+        let method_body = vec![json!(key)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///The kvupdate method stores a key/value pair via OP_RETURN.
+    /// This feature is available only for Smart Chains. The maximum value memory size is 8kB.
+    ///
+    /// # Arguments
+    /// * `key`	(string, required)	key (should be unique)
+    /// * `value`	(string, required)	value
+    /// * `days`	(numeric, required)	amount of days before the key expires (1440 blocks/day); minimum 1 day
+    /// * `passphrase`	(string, optional)	passphrase required to update this key
+    ///
+    /// # Response
+    /// * `coin`	(string)	the chain on which the key is stored
+    /// * `height`	(numeric)	the height at which the key was stored
+    /// * `expiration`	(numeric)	the height at which the key will expire
+    /// * `flags`	(string)	the amount of days the key will be stored
+    /// * `key`	(numeric)	the stored key
+    /// * `keylen`	(numeric)	the length of the key
+    /// * `value`	(numeric)	the stored value
+    /// * `valuesize`	(string)	the length of the stored value
+    /// * `fee`	(string)	the transaction fee paid to store the key
+    /// * `txid`	(string)	the transaction id
+    /// %%%
+    pub fn kv_update(
+        &self,
+        key: String,
+        value: String,
+        days: u32,
+        pass_phrase: Option<String>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("kvupdate");
+        // the daemon reads days as a string
+        let mut method_body = vec![json!(key), json!(value), json!(days.to_string())];
+        if let Some(temp_pass_phrase) = pass_phrase {
+            method_body.push(json!(temp_pass_phrase));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///The minerids method returns information about the notary nodes and external miners at a specific block height.
+    /// The response will calculate results according to the 2000 blocks proceeding the indicated "height" block.
+    /// # Arguments
+    /// * `heights`	(number)	the block height for the query
+    /// # Response
+    /// * `mined`:
+    /// * `notaryid`	(number)	the id of the specific notary node
+    /// * `kmdaddress`	(string)	the KMD address of the notary node
+    /// * `pubkey`	(string)	the public signing key of the notary node
+    /// * `blocks`	(number)
+    /// %%%
+    pub fn miner_ids(&self, height: u32) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("minerids");
+        // the daemon reads the height as a string
+        let method_body = vec![json!(height.to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// The notaries method returns the public key, BTC address, and KMD address for each Komodo notary node.
+    /// Either or both of the height and timestamp parameters will suffice.
+    /// # Arguments
+    /// * `height`	(number)	the block height desired for the query
+    /// * `timestamp`	(number)	the timestamp of the block desired for the query
+    /// # Response
+    /// * `notaries`: [ ... ]	(array)
+    /// * `pubkey`	(string)	the public signing key of the indicated notary node, used on the KMD network to create notary-node authorized transactions
+    /// * `BTCaddress`	(string)	the public BTC address the notary node uses on the BTC blockchain to create notarizations
+    /// * `KMDaddress`	(string)	the public KMD address the notary node uses on the KMD blockchain to create notarizations
+    /// * `numnotaries`	(number)	the number of notary nodes; typically this value is 64, but the value may vary on rare circumstances, such as during election seasons
+    /// * `height`	(number)	the block height number at which the notary-node information applies
+    /// * `timestamp`	(number)	the timestamp at which the notary-node information applies
+    /// %%%
+    pub fn notaries(&self, height: u32, timestamp: u32) -> Response<C, NotaryList>
+    where
+        C: Transport<NotaryList>,
+    {
+        let method_name: String = String::from("notaries");
+        // the daemon reads height and timestamp as strings
+        let method_body = vec![json!(height.to_string()), json!(timestamp.to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///  The verifychain method verifies the coin daemon's blockchain database.
+    ///  Depending on the state of your blockchain database and daemon, this call can take a prolonged period of time to complete.
+    /// # Arguments
+    /// * `checklevel`	(numeric, optional, 0-4, default=3)	indicates the thoroughness of block verification
+    /// * `numblocks`	(numeric, optional, default=288, 0=all)	indicates the number of blocks to verify
+    /// # Response
+    /// * `true/false`	(boolean)	whether the verification was successful
+    /// %%%
+    pub fn verify_chain(
+        &self,
+        check_level: Option<u8>,
+        num_blocks: Option<u16>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("verifychain");
+        let temp_check_level: u8 = check_level.unwrap_or(3);
+        let temp_num_blocks: u16 = num_blocks.unwrap_or(288);
+        let method_body = vec![json!(temp_check_level), json!(temp_num_blocks)];
+        self.client.call(&method_name, method_body)
+    }
+    /// The verifytxoutproof method verifies that a proof points to a transaction in a block.
+    /// It returns the transaction to which the proof is committed,
+    /// or it will throw an RPC error if the block is not in the current best chain.
+    /// # Arguments
+    /// * `proof_string`	(string, required)	the hex-encoded proof generated by gettxoutproof
+    /// # Response
+    /// * `txid` (string)	the transaction ids to which the proof commits; the array is empty if the proof is invalid
+    /// %%%
+
+    pub fn verify_tx_out_proof(&self, proof_string: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("verifytxoutproof");
+        let method_body = vec![json!(proof_string)];
+        self.client.call(&method_name, method_body)
+    }
 }
-/// The verifytxoutproof method verifies that a proof points to a transaction in a block.
-/// It returns the transaction to which the proof is committed,
-/// or it will throw an RPC error if the block is not in the current best chain.
-/// # Arguments
-/// * `proof_string`	(string, required)	the hex-encoded proof generated by gettxoutproof
-/// # Response
-/// * `txid` (string)	the transaction ids to which the proof commits; the array is empty if the proof is invalid
-/// %%%
-
-pub fn verify_tx_out_proof(
-    SomeUser: komodorpcutil::KomodoRPC,
-    proof_string: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("verifytxoutproof");
-    let method_body: String =
-        String::from("[\"") + &proof_string.to_string() + &String::from("\"]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_BLOCK_HASH, MOCK_TXID};
+
+    #[test]
+    fn get_block_is_verbose() {
+        let daemon = MockKomodod::start();
+        let block = daemon
+            .client()
+            .blockchain()
+            .get_block(String::from("1200"))
+            .unwrap();
+        assert_eq!(daemon.last_params("getblock"), json!(["1200", true]));
+        assert_eq!(block.hash, MOCK_BLOCK_HASH);
+        assert_eq!(block.tx, vec![MOCK_TXID.to_string()]);
+        assert_eq!(block.nextblockhash, None);
+    }
+
+    #[test]
+    fn get_block_hex_is_not_verbose() {
+        let daemon = MockKomodod::start();
+        daemon.set_result("getblock", json!("0400000027"));
+        let hex = daemon
+            .client()
+            .blockchain()
+            .get_block_hex(String::from(MOCK_BLOCK_HASH))
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("getblock"),
+            json!([MOCK_BLOCK_HASH, false])
+        );
+        assert_eq!(hex, "0400000027");
+    }
+
+    #[test]
+    fn spent_tx_out_is_none() {
+        let daemon = MockKomodod::start();
+        let tx_out = daemon
+            .client()
+            .blockchain()
+            .get_tx_out(String::from(MOCK_TXID), 1, None)
+            .unwrap();
+        assert_eq!(daemon.last_params("gettxout"), json!([MOCK_TXID, 1]));
+        assert!(tx_out.is_none());
+    }
+
+    #[test]
+    fn kv_update_sends_days_as_string() {
+        let daemon = MockKomodod::start();
+        daemon.set_result("kvupdate", json!({ "coin": "KENNYCOIN" }));
+        daemon
+            .client()
+            .blockchain()
+            .kv_update(String::from("key"), String::from("value"), 3, None)
+            .unwrap();
+        assert_eq!(daemon.last_params("kvupdate"), json!(["key", "value", "3"]));
+    }
 }
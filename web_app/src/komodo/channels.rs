@@ -0,0 +1,219 @@
+//!
+//! This is the documentation for 'Channels' module of Komodo.
+//!
+//! The 'Channels' module of Komodo contains functionality of the Antara 'Channels' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::channels()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * A channel locks numpayments x paysize satoshis for one destination; each payment releases
+//! a multiple of paysize.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/channels.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// The object returned by channelsinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChannelsInfo {
+    /// the name of the module, when no channel was asked for
+    pub name: Option<String>,
+    /// the CC address of the channel
+    #[serde(rename = "Channel CC address")]
+    pub cc_address: Option<String>,
+    /// the address receiving the payments
+    #[serde(rename = "Destination address")]
+    pub destination_address: Option<String>,
+    /// the number of payments the channel was opened with
+    #[serde(rename = "Number of payments")]
+    pub number_of_payments: Option<u64>,
+    /// the size of one payment, in satoshis
+    #[serde(rename = "Denomination (satoshi)")]
+    pub denomination: Option<u64>,
+    /// the transactions of the channel, e.g. {"Open": txid} or {"Payment": txid, ...}
+    #[serde(rename = "Transactions", default)]
+    pub transactions: Vec<BTreeMap<String, Value>>,
+    /// the other fields of the reply, e.g. the open channels when no channel was asked for
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// Calls of the Antara channels API, borrowed from a KomodoClient with `client.channels()`.
+pub struct Channels<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Channels<'a, C> {
+    ///
+    /// The channelsopen method opens a payment channel to a destination pubkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `destpubkey` 	(string, required) 	the pubkey receiving the payments
+    /// * `numpayments` 	(numeric, required) 	the number of payments
+    /// * `paysize` 	(numeric, required) 	the size of one payment, in satoshis
+    /// * `tokenid` 	(string, optional) 	the token id, for a channel paying tokens
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the opentxid once broadcast
+    /// %%%
+    pub fn channels_open(
+        &self,
+        dest_pubkey: String,
+        num_payments: u32,
+        pay_size: u64,
+        token_id: Option<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("channelsopen");
+        let mut method_body = vec![
+            json!(dest_pubkey),
+            json!(num_payments.to_string()),
+            json!(pay_size.to_string()),
+        ];
+        if let Some(temp_token_id) = token_id {
+            method_body.push(json!(temp_token_id));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The channelspayment method releases payments of an open channel to its destination.
+    ///
+    /// # Arguments
+    ///
+    /// * `opentxid` 	(string, required) 	the txid of the channelsopen transaction
+    /// * `amount` 	(numeric, required) 	the amount to release, in satoshis; a multiple of paysize
+    /// * `secret` 	(string, optional) 	the secret of the payment, by default the next one of the channel
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn channels_payment(
+        &self,
+        open_tx_id: String,
+        amount: u64,
+        secret: Option<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("channelspayment");
+        let mut method_body = vec![json!(open_tx_id), json!(amount.to_string())];
+        if let Some(temp_secret) = secret {
+            method_body.push(json!(temp_secret));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The channelsclose method marks a channel as closed; its unpaid funds can then be refunded.
+    ///
+    /// # Arguments
+    ///
+    /// * `opentxid` 	(string, required) 	the txid of the channelsopen transaction
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn channels_close(&self, open_tx_id: String) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("channelsclose");
+        let method_body = vec![json!(open_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The channelsrefund method returns the unpaid funds of a closed channel to its owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `opentxid` 	(string, required) 	the txid of the channelsopen transaction
+    /// * `closetxid` 	(string, required) 	the txid of the channelsclose transaction
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn channels_refund(
+        &self,
+        open_tx_id: String,
+        close_tx_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("channelsrefund");
+        let method_body = vec![json!(open_tx_id), json!(close_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The channelsinfo method returns the details of a channel, or lists the channels of the node when no opentxid is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `opentxid` 	(string, optional) 	the txid of the channelsopen transaction
+    ///
+    /// # Response
+    ///
+    /// * see ChannelsInfo
+    /// %%%
+    pub fn channels_info(&self, open_tx_id: Option<String>) -> Response<C, CCReply<ChannelsInfo>>
+    where
+        C: Transport<CCReply<ChannelsInfo>>,
+    {
+        let method_name: String = String::from("channelsinfo");
+        let mut method_body = Vec::new();
+        if let Some(temp_open_tx_id) = open_tx_id {
+            method_body.push(json!(temp_open_tx_id));
+        }
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::MockKomodod;
+
+    #[test]
+    fn channels_open_sends_numbers_as_strings() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "channelsopen",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        daemon
+            .client()
+            .channels()
+            .channels_open(String::from("02a1b2c3"), 12, 100000000, None)
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("channelsopen"),
+            json!(["02a1b2c3", "12", "100000000"])
+        );
+    }
+}
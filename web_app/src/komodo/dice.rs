@@ -0,0 +1,330 @@
+//!
+//! This is the documentation for 'Dice' module of Komodo.
+//!
+//! The 'Dice' module of Komodo contains functionality of the Antara 'Dice' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::dice()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * A dice table is funded by its house; players bet against it and a bet is settled with
+//! dicefinish once the house's entropy is revealed, or refunded after the table's timeout.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/dice.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+/// The limits of a dice table, as set by dicefund.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct DiceLimits {
+    /// the smallest bet
+    pub minbet: Amount,
+    /// the largest bet
+    pub maxbet: Amount,
+    /// the highest odds a player may ask for
+    pub maxodds: u32,
+    /// the number of blocks after which an unsettled bet is refunded
+    pub timeoutblocks: u32,
+}
+
+/// The object returned by diceinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiceInfo {
+    /// the txid of the table
+    pub fundingtxid: String,
+    /// the name of the table
+    pub name: String,
+    /// the table name packed into a number, as used in the table's transactions
+    pub sbits: i64,
+    /// the smallest bet
+    pub minbet: Amount,
+    /// the largest bet
+    pub maxbet: Amount,
+    /// the highest odds a player may ask for
+    pub maxodds: u32,
+    /// the number of blocks after which an unsettled bet is refunded
+    pub timeoutblocks: u32,
+    /// the funds of the house
+    pub funding: Amount,
+}
+
+/// The object returned by dicestatus.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiceStatus {
+    /// the state of the bet, e.g. "win", "loss" or "bet still pending"
+    pub status: String,
+    /// the number of bets settled, when no bettxid was given
+    pub n: Option<u64>,
+}
+
+/// Calls of the Antara dice API, borrowed from a KomodoClient with `client.dice()`.
+pub struct Dice<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Dice<'a, C> {
+    ///
+    /// The dicefund method creates a dice table and funds its house.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the table
+    /// * `funds` 	(numeric, required) 	the funds of the house
+    /// * `minbet`, `maxbet`, `maxodds`, `timeoutblocks` 	(numeric, required) 	the limits of the table
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the fundingtxid once broadcast
+    /// %%%
+    pub fn dice_fund(
+        &self,
+        name: String,
+        funds: Amount,
+        limits: DiceLimits,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("dicefund");
+        let method_body = vec![
+            json!(name),
+            json!(funds.to_string()),
+            json!(limits.minbet.to_string()),
+            json!(limits.maxbet.to_string()),
+            json!(limits.maxodds.to_string()),
+            json!(limits.timeoutblocks.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The diceaddfunds method adds funds to the house of a table; only its creator may add funds.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the table
+    /// * `fundingtxid` 	(string, required) 	the txid of the table
+    /// * `amount` 	(numeric, required) 	the amount to add
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn dice_add_funds(
+        &self,
+        name: String,
+        funding_tx_id: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("diceaddfunds");
+        let method_body = vec![json!(name), json!(funding_tx_id), json!(amount.to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The dicebet method places a bet on a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the table
+    /// * `fundingtxid` 	(string, required) 	the txid of the table
+    /// * `amount` 	(numeric, required) 	the amount to bet
+    /// * `odds` 	(numeric, required) 	the odds of the bet; a win pays amount x odds
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the bettxid once broadcast
+    /// %%%
+    pub fn dice_bet(
+        &self,
+        name: String,
+        funding_tx_id: String,
+        amount: Amount,
+        odds: u32,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("dicebet");
+        let method_body = vec![
+            json!(name),
+            json!(funding_tx_id),
+            json!(amount.to_string()),
+            json!(odds.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The dicefinish method settles a bet, or refunds it once the table's timeout has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the table
+    /// * `fundingtxid` 	(string, required) 	the txid of the table
+    /// * `bettxid` 	(string, required) 	the txid of the bet
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn dice_finish(
+        &self,
+        name: String,
+        funding_tx_id: String,
+        bet_tx_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("dicefinish");
+        let method_body = vec![json!(name), json!(funding_tx_id), json!(bet_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The dicestatus method returns the state of a bet, or settles the pending bets of the table when no bettxid is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the table
+    /// * `fundingtxid` 	(string, required) 	the txid of the table
+    /// * `bettxid` 	(string, optional) 	the txid of the bet
+    ///
+    /// # Response
+    ///
+    /// * see DiceStatus
+    /// %%%
+    pub fn dice_status(
+        &self,
+        name: String,
+        funding_tx_id: String,
+        bet_tx_id: Option<String>,
+    ) -> Response<C, CCReply<DiceStatus>>
+    where
+        C: Transport<CCReply<DiceStatus>>,
+    {
+        let method_name: String = String::from("dicestatus");
+        let mut method_body = vec![json!(name), json!(funding_tx_id)];
+        if let Some(temp_bet_tx_id) = bet_tx_id {
+            method_body.push(json!(temp_bet_tx_id));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The dicelist method lists the txids of all dice tables on the chain.
+    ///
+    /// # Response
+    ///
+    /// * `fundingtxid` 	(string) 	the txid of a table
+    /// %%%
+    pub fn dice_list(&self) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("dicelist");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The diceinfo method returns the details of a dice table.
+    ///
+    /// # Arguments
+    ///
+    /// * `fundingtxid` 	(string, required) 	the txid of the table
+    ///
+    /// # Response
+    ///
+    /// * see DiceInfo
+    /// %%%
+    pub fn dice_info(&self, funding_tx_id: String) -> Response<C, CCReply<DiceInfo>>
+    where
+        C: Transport<CCReply<DiceInfo>>,
+    {
+        let method_name: String = String::from("diceinfo");
+        let method_body = vec![json!(funding_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn dice_fund_sends_the_limits_as_strings() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "dicefund",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        let limits = DiceLimits {
+            minbet: Amount::from_sat(10_000_000),
+            maxbet: Amount::from_sat(1_000_000_000),
+            maxodds: 100,
+            timeoutblocks: 5,
+        };
+        daemon
+            .client()
+            .dice()
+            .dice_fund(
+                String::from("LUCKY"),
+                Amount::from_sat(100_000_000_000),
+                limits,
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("dicefund"),
+            json!([
+                "LUCKY",
+                "1000.00000000",
+                "0.10000000",
+                "10.00000000",
+                "100",
+                "5"
+            ])
+        );
+    }
+
+    #[test]
+    fn dice_status_reads_a_pending_bet() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "dicestatus",
+            json!({ "result": "success", "status": "bet still pending" }),
+        );
+        let status = daemon
+            .client()
+            .dice()
+            .dice_status(
+                String::from("LUCKY"),
+                MOCK_TXID.to_string(),
+                Some(MOCK_TXID.to_string()),
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(status.status, "bet still pending");
+        assert_eq!(status.n, None);
+    }
+}
@@ -4,91 +4,70 @@
 The following RPC calls interact with the komodod software, and are made available through the komodo-cli software
  */
 use super::komodorpcutil;
-use komodorpcutil::KomodoRPC;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_json::{json, Value};
 
-/**
- * z_getpaymentdisclosure
-EXPERIMENTAL FEATURE: Payment disclosure is currently DISABLED. This call always fails.
-
-z_getpaymentdisclosure transaction js_index output_index ("message")
+/// Calls of the payment disclosure API, borrowed from a KomodoClient with `client.disclosure()`.
+pub struct Disclosure<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
 
-The z_getpaymentdisclosure method generates a payment disclosure for a given joinsplit output.
+impl<'a, C> Disclosure<'a, C> {
+    /**
+     * z_getpaymentdisclosure
+    EXPERIMENTAL FEATURE: Payment disclosure is currently DISABLED. This call always fails.
 
-# Arguments
-Name	Type	Description
-"txid"	(string, required)	(in development)
-"js_index"	(string, required)
-"output_index"	(string, required)
-"message"	(string, optional)
- */
+    z_getpaymentdisclosure transaction js_index output_index ("message")
 
-pub fn z_get_payment_disclosure(
-    someUser: komodorpcutil::KomodoRPC,
-    txid: String,
-    js_index: String,
-    output_index: String,
-    message: Option<String>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_message = message.unwrap_or("".to_string()); //Default value is 0
-    if (!temp_message.is_empty()) {
-        method_body = String::from("[\"")
-            + &txid
-            + &"\",".to_string()
-            + &js_index
-            + &",".to_string()
-            + &output_index
-            + &",\"".to_string()
-            + &temp_message
-            + "\"]"; // String::from(format!("[/"{0}/"]",temp_top));
-    } else {
-        method_body = String::from("[\"")
-            + &txid
-            + &"\",".to_string()
-            + &js_index
-            + &",".to_string()
-            + &output_index
-            + "]";
-    }
+    The z_getpaymentdisclosure method generates a payment disclosure for a given joinsplit output.
 
-    let method_name: String = String::from("z_getpaymentdisclosure");
+    # Arguments
+    Name	Type	Description
+    "txid"	(string, required)	(in development)
+    "js_index"	(numeric, required)
+    "output_index"	(numeric, required)
+    "message"	(string, optional)
+     */
 
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
-}
-
-/**
-  *
-  z_validatepaymentdisclosure
-z_validatepaymentdisclosure "paymentdisclosure"
-
-The z_validatepaymentdisclosure method validates a payment disclosure.
+    pub fn z_get_payment_disclosure(
+        &self,
+        txid: String,
+        js_index: u32,
+        output_index: u32,
+        message: Option<String>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_getpaymentdisclosure");
+        let mut method_body = vec![json!(txid), json!(js_index), json!(output_index)];
+        if let Some(temp_message) = message {
+            method_body.push(json!(temp_message));
+        }
+        self.client.call(&method_name, method_body)
+    }
 
-# Arguments
-Name	Type	Description
-"paymentdisclosure"	(string, required)	hex data string, with "zpd:" prefix
-# Response
-Name	Type	Description
-(currently disabled)
-  */
+    /**
+      *
+      z_validatepaymentdisclosure
+    z_validatepaymentdisclosure "paymentdisclosure"
 
-pub fn z_validate_payment_disclosure(
-    someUser: komodorpcutil::KomodoRPC,
-    paymentdisclosure: String,
-) -> Result<String, reqwest::Error> {
-    let params = String::from("[\"") + &paymentdisclosure + "\"]"; // String::from(format!("[/"{0}/"]",temp_top));
+    The z_validatepaymentdisclosure method validates a payment disclosure.
 
-    let method_name: String = String::from("z_validatepaymentdisclosure");
-    let method_body: String = String::from(params);
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
+    # Arguments
+    Name	Type	Description
+    "paymentdisclosure"	(string, required)	hex data string, with "zpd:" prefix
+    # Response
+    Name	Type	Description
+    (currently disabled)
+      */
 
-    komodorpcutil::request(someUser.clone(), data)
+    pub fn z_validate_payment_disclosure(&self, paymentdisclosure: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_validatepaymentdisclosure");
+        let method_body = vec![json!(paymentdisclosure)];
+        self.client.call(&method_name, method_body)
+    }
 }
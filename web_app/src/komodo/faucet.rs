@@ -0,0 +1,144 @@
+//!
+//! This is the documentation for 'Faucet' module of Komodo.
+//!
+//! The 'Faucet' module of Komodo contains functionality of the Antara 'Faucet' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::faucet()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/faucet.html
+//!
+
+use super::antara::{CCAddresses, CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+/// The object returned by faucetinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FaucetInfo {
+    /// the name of the module, "Faucet"
+    pub name: String,
+    /// the amount of coins left in the faucet
+    pub funding: Amount,
+}
+
+/// Calls of the Antara faucet API, borrowed from a KomodoClient with `client.faucet()`.
+pub struct Faucet<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Faucet<'a, C> {
+    ///
+    /// The faucetfund method funds the on-chain faucet.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` 	(numeric, required) 	the amount of coins to add to the faucet
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    ///
+    /// # Examples
+    /// ```
+    /// let txid = client.faucet().faucet_fund("10".parse::<Amount>()?)?.into_result()?.broadcast(&client)?;
+    /// ```
+    /// %%%
+    pub fn faucet_fund(&self, amount: Amount) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("faucetfund");
+        let method_body = vec![json!(amount.to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The faucetget method requests coins from the faucet. The daemon mines a small proof of work for the request, so the call can take a while.
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn faucet_get(&self) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("faucetget");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The faucetinfo method displays the balance of funds in the faucet.
+    ///
+    /// # Response
+    ///
+    /// * `name` 	(string) 	the name of the module
+    /// * `funding` 	(string) 	the amount of coins left in the faucet
+    /// %%%
+    pub fn faucet_info(&self) -> Response<C, CCReply<FaucetInfo>>
+    where
+        C: Transport<CCReply<FaucetInfo>>,
+    {
+        let method_name: String = String::from("faucetinfo");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The faucetaddress method returns the Antara addresses of the faucet module, for the given pubkey or the node's own.
+    ///
+    /// # Arguments
+    ///
+    /// * `pubkey` 	(string, optional) 	the pubkey to list the addresses of
+    ///
+    /// # Response
+    ///
+    /// * `FaucetCCAddress`, `Faucetmarker`, `myaddress`, ... 	(string) 	the addresses by name
+    /// %%%
+    pub fn faucet_address(&self, pubkey: Option<String>) -> Response<C, CCAddresses>
+    where
+        C: Transport<CCAddresses>,
+    {
+        let method_name: String = String::from("faucetaddress");
+        let mut method_body = Vec::new();
+        if let Some(temp_pubkey) = pubkey {
+            method_body.push(json!(temp_pubkey));
+        }
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::MockKomodod;
+
+    #[test]
+    fn faucet_info_reads_the_funding_string() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "faucetinfo",
+            json!({ "result": "success", "name": "Faucet", "funding": "1000.50000000" }),
+        );
+        let info = daemon
+            .client()
+            .faucet()
+            .faucet_info()
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(info.funding, Amount::from_sat(100_050_000_000));
+    }
+}
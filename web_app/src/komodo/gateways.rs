@@ -0,0 +1,422 @@
+//!
+//! This is the documentation for 'Gateways' module of Komodo.
+//!
+//! The 'Gateways' module of Komodo contains functionality of the Antara 'Gateways' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::gateways()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * A gateway binds a token of the smart chain to a coin of an external chain. Deposits of the
+//! coin to the gateway's multisig address are proven through an oracle and claimed as tokens;
+//! withdrawals burn tokens and are paid out by the multisig signers.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/gateways.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// The address types of the external coin, as used by gatewaysbind.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct AddressPrefixes {
+    /// the prefix of the coin's p2pkh addresses, e.g. 0 for BTC or 60 for KMD
+    pub pubtype: u8,
+    /// the prefix of the coin's p2sh addresses, e.g. 5 for BTC or 85 for KMD
+    pub p2shtype: u8,
+    /// the prefix of the coin's private keys, e.g. 128 for BTC or 188 for KMD
+    pub wiftype: u8,
+    /// the extra prefix byte of coins with two-byte addresses, if any
+    pub taddr: Option<u8>,
+}
+
+/// The object returned by gatewaysinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GatewaysInfo {
+    /// the name of the module, "Gateways"
+    pub name: Option<String>,
+    /// the pubkey of the node that bound the gateway
+    pub pubkey: Option<String>,
+    /// the ticker of the external coin
+    pub coin: String,
+    /// the txid of the oracle publishing the coin's block headers
+    pub oracletxid: Option<String>,
+    /// the number of signatures a withdrawal needs
+    #[serde(rename = "M")]
+    pub m: Option<u8>,
+    /// the number of signers of the gateway
+    #[serde(rename = "N")]
+    pub n: Option<u8>,
+    /// the pubkeys of the signers
+    #[serde(default)]
+    pub pubkeys: Vec<String>,
+    /// the multisig address of the external coin receiving deposits
+    pub deposit: Option<String>,
+    /// the token representing the coin
+    pub tokenid: Option<String>,
+    /// the supply of tokens bound to the gateway, in tokens
+    #[serde(default)]
+    pub totalsupply: Option<u64>,
+    /// the tokens not claimed yet
+    #[serde(default)]
+    pub remaining: Option<u64>,
+    /// the tokens claimed from deposits
+    #[serde(default)]
+    pub issued: Option<u64>,
+    /// the other fields of the reply, e.g. the address prefixes
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// A withdrawal waiting to be paid out by the signers, as listed by gatewayspending.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GatewaysWithdrawal {
+    /// the txid of the gatewayswithdraw transaction
+    pub withdrawtxid: Option<String>,
+    /// the address of the external coin to pay
+    pub withdrawaddr: Option<String>,
+    /// the amount of coins to pay
+    pub amount: Amount,
+    /// the other fields of the withdrawal, e.g. its confirmations
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// The object returned by gatewayspending.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GatewaysPending {
+    /// the ticker of the external coin
+    pub coin: String,
+    /// the withdrawals waiting to be paid out
+    #[serde(default)]
+    pub pending: Vec<GatewaysWithdrawal>,
+}
+
+/// Calls of the Antara gateways API, borrowed from a KomodoClient with `client.gateways()`.
+pub struct Gateways<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Gateways<'a, C> {
+    ///
+    /// The gatewaysbind method binds a token to a coin of an external chain, signed by M of the N given pubkeys.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenid` 	(string, required) 	the token representing the coin; all of its supply must be held by the node
+    /// * `oracletxid` 	(string, required) 	the oracle publishing the block headers of the coin
+    /// * `coin` 	(string, required) 	the ticker of the coin, which must match the name of the oracle
+    /// * `tokensupply` 	(numeric, required) 	the supply of the token, in tokens (see `token_info`)
+    /// * `M` 	(numeric, required) 	the number of signatures a withdrawal needs
+    /// * `N` 	(numeric, required) 	the number of signers
+    /// * `pubkey` 	(string, required) 	the pubkeys of the N signers
+    /// * `pubtype`, `p2shtype`, `wiftype`, `taddr` 	(numeric) 	the address prefixes of the coin
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the bindtxid once broadcast
+    /// %%%
+    #[allow(clippy::too_many_arguments)]
+    pub fn gateways_bind(
+        &self,
+        token_id: String,
+        oracle_tx_id: String,
+        coin: String,
+        token_supply: u64,
+        m: u8,
+        pubkeys: Vec<String>,
+        prefixes: AddressPrefixes,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("gatewaysbind");
+        let mut method_body = vec![
+            json!(token_id),
+            json!(oracle_tx_id),
+            json!(coin),
+            json!(token_supply.to_string()),
+            json!(m.to_string()),
+            json!(pubkeys.len().to_string()),
+        ];
+        method_body.extend(pubkeys.into_iter().map(|pubkey| json!(pubkey)));
+        method_body.push(json!(prefixes.pubtype.to_string()));
+        method_body.push(json!(prefixes.p2shtype.to_string()));
+        method_body.push(json!(prefixes.wiftype.to_string()));
+        if let Some(temp_taddr) = prefixes.taddr {
+            method_body.push(json!(temp_taddr.to_string()));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The gatewaysdeposit method registers a deposit of the external coin to the gateway, with its proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindtxid` 	(string, required) 	the txid of the gateway
+    /// * `height` 	(numeric, required) 	the height of the block of the deposit on the external chain
+    /// * `coin` 	(string, required) 	the ticker of the coin
+    /// * `cointxid` 	(string, required) 	the txid of the deposit on the external chain
+    /// * `claimvout` 	(numeric, required) 	the output of the deposit paying the gateway
+    /// * `deposithex` 	(string, required) 	the deposit transaction in hex
+    /// * `proof` 	(string, required) 	the merkle proof of the deposit (gettxoutproof on the external chain)
+    /// * `destpub` 	(string, required) 	the pubkey receiving the tokens
+    /// * `amount` 	(numeric, required) 	the amount of the deposit
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the deposittxid once broadcast
+    /// %%%
+    #[allow(clippy::too_many_arguments)]
+    pub fn gateways_deposit(
+        &self,
+        bind_tx_id: String,
+        height: u64,
+        coin: String,
+        coin_tx_id: String,
+        claim_vout: u32,
+        deposit_hex: String,
+        proof: String,
+        dest_pub: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("gatewaysdeposit");
+        let method_body = vec![
+            json!(bind_tx_id),
+            json!(height.to_string()),
+            json!(coin),
+            json!(coin_tx_id),
+            json!(claim_vout.to_string()),
+            json!(deposit_hex),
+            json!(proof),
+            json!(dest_pub),
+            json!(amount.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The gatewaysclaim method claims the tokens of a registered deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindtxid` 	(string, required) 	the txid of the gateway
+    /// * `coin` 	(string, required) 	the ticker of the coin
+    /// * `deposittxid` 	(string, required) 	the txid of the gatewaysdeposit transaction
+    /// * `destpub` 	(string, required) 	the pubkey receiving the tokens
+    /// * `amount` 	(numeric, required) 	the amount of the deposit
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn gateways_claim(
+        &self,
+        bind_tx_id: String,
+        coin: String,
+        deposit_tx_id: String,
+        dest_pub: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("gatewaysclaim");
+        let method_body = vec![
+            json!(bind_tx_id),
+            json!(coin),
+            json!(deposit_tx_id),
+            json!(dest_pub),
+            json!(amount.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The gatewayswithdraw method returns tokens to the gateway, to be paid out as coins of the external chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindtxid` 	(string, required) 	the txid of the gateway
+    /// * `coin` 	(string, required) 	the ticker of the coin
+    /// * `withdrawpub` 	(string, required) 	the pubkey receiving the coins on the external chain
+    /// * `amount` 	(numeric, required) 	the amount to withdraw
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn gateways_withdraw(
+        &self,
+        bind_tx_id: String,
+        coin: String,
+        withdraw_pub: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("gatewayswithdraw");
+        let method_body = vec![
+            json!(bind_tx_id),
+            json!(coin),
+            json!(withdraw_pub),
+            json!(amount.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The gatewayspending method lists the withdrawals of a gateway waiting to be paid out.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindtxid` 	(string, required) 	the txid of the gateway
+    /// * `coin` 	(string, required) 	the ticker of the coin
+    ///
+    /// # Response
+    ///
+    /// * see GatewaysPending
+    /// %%%
+    pub fn gateways_pending(
+        &self,
+        bind_tx_id: String,
+        coin: String,
+    ) -> Response<C, CCReply<GatewaysPending>>
+    where
+        C: Transport<CCReply<GatewaysPending>>,
+    {
+        let method_name: String = String::from("gatewayspending");
+        let method_body = vec![json!(bind_tx_id), json!(coin)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The gatewaysinfo method returns the details of a gateway.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindtxid` 	(string, required) 	the txid of the gateway
+    ///
+    /// # Response
+    ///
+    /// * see GatewaysInfo
+    /// %%%
+    pub fn gateways_info(&self, bind_tx_id: String) -> Response<C, CCReply<GatewaysInfo>>
+    where
+        C: Transport<CCReply<GatewaysInfo>>,
+    {
+        let method_name: String = String::from("gatewaysinfo");
+        let method_body = vec![json!(bind_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The gatewayslist method lists the txids of all gateways on the chain.
+    ///
+    /// # Response
+    ///
+    /// * `bindtxid` 	(string) 	the txid of a gateway
+    /// %%%
+    pub fn gateways_list(&self) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("gatewayslist");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn gateways_bind_counts_the_signers() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "gatewaysbind",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        let prefixes = AddressPrefixes {
+            pubtype: 0,
+            p2shtype: 5,
+            wiftype: 128,
+            taddr: None,
+        };
+        daemon
+            .client()
+            .gateways()
+            .gateways_bind(
+                MOCK_TXID.to_string(),
+                MOCK_TXID.to_string(),
+                String::from("BTC"),
+                10_000_000_000,
+                1,
+                vec![String::from("02a1b2c3"), String::from("03d4e5f6")],
+                prefixes,
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("gatewaysbind"),
+            json!([
+                MOCK_TXID,
+                MOCK_TXID,
+                "BTC",
+                "10000000000",
+                "1",
+                "2",
+                "02a1b2c3",
+                "03d4e5f6",
+                "0",
+                "5",
+                "128"
+            ])
+        );
+    }
+
+    #[test]
+    fn gateways_pending_reads_the_withdrawals() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "gatewayspending",
+            json!({
+                "result": "success",
+                "coin": "BTC",
+                "pending": [{
+                    "withdrawtxid": MOCK_TXID,
+                    "withdrawaddr": "1BoatSLRHtKNngkdXEeobR76b53LETtpyT",
+                    "amount": "0.25000000",
+                    "confirmed_or_notarized": true
+                }]
+            }),
+        );
+        let pending = daemon
+            .client()
+            .gateways()
+            .gateways_pending(MOCK_TXID.to_string(), String::from("BTC"))
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(pending.pending[0].amount, Amount::from_sat(25_000_000));
+    }
+}
@@ -0,0 +1,336 @@
+//!
+//! This is the documentation for 'Heir' module of Komodo.
+//!
+//! The 'Heir' module of Komodo contains functionality of the Antara 'Heir' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::heir()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * A heir plan holds coins, or tokens when it is created with a tokenid. The daemon reads amounts of
+//! tokens as whole numbers, so plans of tokens have their own methods taking a count of tokens:
+//! `heir_fund_tokens`, `heir_add_tokens` and `heir_claim_tokens`.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/heir.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// The object returned by heirinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HeirInfo {
+    /// the txid of the plan
+    pub fundingtxid: String,
+    /// the name of the plan
+    pub name: String,
+    /// what the plan holds, "coins" or "tokens"
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// the token id, for a plan holding tokens
+    pub tokenid: Option<String>,
+    /// the pubkey of the owner
+    pub owner: String,
+    /// the pubkey of the heir
+    pub heir: String,
+    /// the total amount ever added to the plan
+    #[serde(default)]
+    pub lifetime: Option<Amount>,
+    /// the amount left in the plan
+    #[serde(default)]
+    pub available: Option<Amount>,
+    /// the memo of the plan
+    pub memo: Option<String>,
+    /// the other fields of the reply, e.g. the inactivity time and whether the heir may spend
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// Calls of the Antara heir API, borrowed from a KomodoClient with `client.heir()`.
+pub struct Heir<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Heir<'a, C> {
+    ///
+    /// The heirfund method creates a heir plan: the heir may spend the funds once the owner has been inactive for the given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `funds` 	(numeric, required) 	the amount of coins
+    /// * `heirname` 	(string, required) 	the name of the plan
+    /// * `heirpubkey` 	(string, required) 	the pubkey of the heir
+    /// * `inactivitytime` 	(numeric, required) 	the time in seconds after which the heir may spend the funds
+    /// * `memo` 	(string, required) 	a memo stored with the plan
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the fundingtxid once broadcast
+    /// %%%
+    pub fn heir_fund(
+        &self,
+        funds: Amount,
+        heir_name: String,
+        heir_pubkey: String,
+        inactivity_time: u64,
+        memo: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("heirfund");
+        let method_body = vec![
+            json!(funds.to_string()),
+            json!(heir_name),
+            json!(heir_pubkey),
+            json!(inactivity_time.to_string()),
+            json!(memo),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The heirfund method with a tokenid creates a heir plan holding tokens instead of coins.
+    ///
+    /// # Arguments
+    ///
+    /// * `funds` 	(numeric, required) 	the number of tokens
+    /// * `heirname` 	(string, required) 	the name of the plan
+    /// * `heirpubkey` 	(string, required) 	the pubkey of the heir
+    /// * `inactivitytime` 	(numeric, required) 	the time in seconds after which the heir may spend the funds
+    /// * `memo` 	(string, required) 	a memo stored with the plan
+    /// * `tokenid` 	(string, required) 	the token id
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the fundingtxid once broadcast
+    /// %%%
+    pub fn heir_fund_tokens(
+        &self,
+        tokens: u64,
+        heir_name: String,
+        heir_pubkey: String,
+        inactivity_time: u64,
+        memo: String,
+        token_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("heirfund");
+        let method_body = vec![
+            json!(tokens.to_string()),
+            json!(heir_name),
+            json!(heir_pubkey),
+            json!(inactivity_time.to_string()),
+            json!(memo),
+            json!(token_id),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The heiradd method adds funds to a heir plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `funds` 	(numeric, required) 	the amount of coins to add
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn heir_add(
+        &self,
+        funds: Amount,
+        funding_tx_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("heiradd");
+        let method_body = vec![json!(funds.to_string()), json!(funding_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The heiradd method adds tokens to a heir plan holding tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `funds` 	(numeric, required) 	the number of tokens to add
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn heir_add_tokens(
+        &self,
+        tokens: u64,
+        funding_tx_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("heiradd");
+        let method_body = vec![json!(tokens.to_string()), json!(funding_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The heirclaim method spends funds of a heir plan, by the owner at any time or by the heir after the inactivity time.
+    ///
+    /// # Arguments
+    ///
+    /// * `funds` 	(numeric, required) 	the amount of coins to claim
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn heir_claim(
+        &self,
+        funds: Amount,
+        funding_tx_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("heirclaim");
+        let method_body = vec![json!(funds.to_string()), json!(funding_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The heirclaim method spends tokens of a heir plan holding tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `funds` 	(numeric, required) 	the number of tokens to claim
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn heir_claim_tokens(
+        &self,
+        tokens: u64,
+        funding_tx_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("heirclaim");
+        let method_body = vec![json!(tokens.to_string()), json!(funding_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The heirinfo method returns the details of a heir plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    ///
+    /// # Response
+    ///
+    /// * see HeirInfo
+    /// %%%
+    pub fn heir_info(&self, funding_tx_id: String) -> Response<C, CCReply<HeirInfo>>
+    where
+        C: Transport<CCReply<HeirInfo>>,
+    {
+        let method_name: String = String::from("heirinfo");
+        let method_body = vec![json!(funding_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The heirlist method lists the txids of all heir plans on the chain.
+    ///
+    /// # Response
+    ///
+    /// * `fundingtxid` 	(string) 	the txid of a plan
+    /// %%%
+    pub fn heir_list(&self) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("heirlist");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn heir_fund_sends_coins_and_tokens() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "heirfund",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        daemon
+            .client()
+            .heir()
+            .heir_fund(
+                Amount::from_sat(250_000_000),
+                String::from("savings"),
+                String::from("02a1b2c3"),
+                86400,
+                String::from("for the kids"),
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("heirfund"),
+            json!(["2.50000000", "savings", "02a1b2c3", "86400", "for the kids"])
+        );
+
+        daemon
+            .client()
+            .heir()
+            .heir_fund_tokens(
+                2_500,
+                String::from("savings"),
+                String::from("02a1b2c3"),
+                86400,
+                String::from("for the kids"),
+                MOCK_TXID.to_string(),
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("heirfund"),
+            json!([
+                "2500",
+                "savings",
+                "02a1b2c3",
+                "86400",
+                "for the kids",
+                MOCK_TXID
+            ])
+        );
+    }
+}
@@ -1,15 +1,173 @@
 #![allow(warnings)]
 pub mod address;
+pub mod address_codec;
+pub mod amount;
+pub mod antara;
 pub mod blockchain;
+pub mod channels;
 pub mod control;
 pub mod cross_chain;
+pub mod dice;
 pub mod disclosure;
+pub mod faucet;
+pub mod gateways;
 pub mod generate;
+pub mod heir;
 pub mod mining;
 pub mod network;
+pub mod oracles;
+pub mod payments;
+pub mod pegs;
+pub mod prices;
 pub mod rawtransactions;
+pub mod rewards;
+pub mod rogue;
+pub mod staking;
+pub mod tokens;
+pub mod transaction;
+pub mod transaction_builder;
 pub mod util;
 pub mod wallet;
 
 use super::komodorpcutil;
-use super::komodorpcutil::KomodoRPC;
+use super::komodorpcutil::KomodoClient;
+use serde::de::{Deserialize as _, Error as _};
+use serde::Deserializer;
+use serde_json::Value;
+
+pub use self::address_codec::{Address, AddressParams};
+pub use self::amount::Amount;
+
+// Reads a number the daemon sends as a string, e.g. "5.00000000", or as a number.
+// Coin amounts are read by Amount itself; this is for rates and prices.
+fn number_from_str<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(number) => number.trim().parse().map_err(D::Error::custom),
+        Value::Number(number) => number
+            .as_f64()
+            .ok_or_else(|| D::Error::custom("number out of range")),
+        other => Err(D::Error::custom(format!(
+            "expected a number, got {}",
+            other
+        ))),
+    }
+}
+
+fn option_number_from_str<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    number_from_str(deserializer).map(Some)
+}
+
+/**
+ * Accessors for the API modules of the Komodo Daemon.
+ * Each one borrows the client, so calls share its configuration and connections:
+ * client.wallet().get_balance(None, None)
+ * The methods of a module return whatever the client's Transport gives back,
+ * e.g. a Result for KomodoClient, a future for AsyncKomodoClient or a
+ * BatchCall handle for a Batch.
+ */
+pub trait KomodoApi: Sized {
+    fn address_index(&self) -> address::AddressIndex<'_, Self> {
+        address::AddressIndex { client: self }
+    }
+
+    fn blockchain(&self) -> blockchain::Blockchain<'_, Self> {
+        blockchain::Blockchain { client: self }
+    }
+
+    fn channels(&self) -> channels::Channels<'_, Self> {
+        channels::Channels { client: self }
+    }
+
+    fn control(&self) -> control::Control<'_, Self> {
+        control::Control { client: self }
+    }
+
+    fn cross_chain(&self) -> cross_chain::CrossChain<'_, Self> {
+        cross_chain::CrossChain { client: self }
+    }
+
+    fn dice(&self) -> dice::Dice<'_, Self> {
+        dice::Dice { client: self }
+    }
+
+    fn disclosure(&self) -> disclosure::Disclosure<'_, Self> {
+        disclosure::Disclosure { client: self }
+    }
+
+    fn faucet(&self) -> faucet::Faucet<'_, Self> {
+        faucet::Faucet { client: self }
+    }
+
+    fn gateways(&self) -> gateways::Gateways<'_, Self> {
+        gateways::Gateways { client: self }
+    }
+
+    fn generate(&self) -> generate::Generate<'_, Self> {
+        generate::Generate { client: self }
+    }
+
+    fn heir(&self) -> heir::Heir<'_, Self> {
+        heir::Heir { client: self }
+    }
+
+    fn mining(&self) -> mining::Mining<'_, Self> {
+        mining::Mining { client: self }
+    }
+
+    fn network(&self) -> network::Network<'_, Self> {
+        network::Network { client: self }
+    }
+
+    fn oracles(&self) -> oracles::Oracles<'_, Self> {
+        oracles::Oracles { client: self }
+    }
+
+    fn payments(&self) -> payments::Payments<'_, Self> {
+        payments::Payments { client: self }
+    }
+
+    fn pegs(&self) -> pegs::Pegs<'_, Self> {
+        pegs::Pegs { client: self }
+    }
+
+    fn prices(&self) -> prices::Prices<'_, Self> {
+        prices::Prices { client: self }
+    }
+
+    fn raw_transactions(&self) -> rawtransactions::RawTransactions<'_, Self> {
+        rawtransactions::RawTransactions { client: self }
+    }
+
+    fn rewards(&self) -> rewards::Rewards<'_, Self> {
+        rewards::Rewards { client: self }
+    }
+
+    fn rogue(&self) -> rogue::Rogue<'_, Self> {
+        rogue::Rogue { client: self }
+    }
+
+    fn tokens(&self) -> tokens::Tokens<'_, Self> {
+        tokens::Tokens { client: self }
+    }
+
+    fn util(&self) -> util::Util<'_, Self> {
+        util::Util { client: self }
+    }
+
+    fn wallet(&self) -> wallet::Wallet<'_, Self> {
+        wallet::Wallet { client: self }
+    }
+}
+
+impl KomodoApi for KomodoClient {}
+
+impl<'c> KomodoApi for komodorpcutil::Batch<'c> {}
+
+#[cfg(feature = "async")]
+impl KomodoApi for komodorpcutil::AsyncKomodoClient {}
@@ -0,0 +1,613 @@
+//!
+//! This is the documentation for 'Oracles' module of Komodo.
+//!
+//! The 'Oracles' module of Komodo contains functionality of the Antara 'Oracles' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::oracles()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! * `encode_oracle_data` builds the hex payload of oraclesdata from the oracle's format;
+//! `OraclePublisher` and `OracleSubscriber` cover the usual publish and read loops.
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/oracles.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::{Amount, KomodoApi};
+use komodorpcutil::{KomodoClient, KomodoError, Response, Transport};
+use rustc_serialize::hex::{FromHex, ToHex};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// The object returned by oraclesinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OracleInfo {
+    /// the id of the oracle
+    pub txid: String,
+    /// the name of the oracle
+    pub name: String,
+    /// the description of the oracle
+    pub description: String,
+    /// the format of the oracle's data, e.g. "L" or "sL"
+    pub format: String,
+    /// the marker address of the oracle
+    pub marker: String,
+    /// the publishers registered with the oracle
+    pub registered: Vec<OraclePublisherInfo>,
+}
+
+/// A publisher registered with an oracle, as listed by oraclesinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OraclePublisherInfo {
+    /// the pubkey of the publisher
+    pub publisher: String,
+    /// the baton address of the publisher, used by oraclessamples
+    pub baton: String,
+    /// the txid of the publisher's latest baton
+    pub batontxid: String,
+    /// the lifetime of the publisher
+    pub lifetime: Amount,
+    /// the funds subscribers have paid to the publisher
+    pub funds: Amount,
+    /// the fee for each data point
+    pub datafee: Amount,
+}
+
+/// One data point of an oracle, decoded by the daemon into one value per format character.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OracleSample {
+    /// the txid of the data transaction
+    pub txid: Option<String>,
+    /// the decoded values
+    pub data: Vec<Value>,
+}
+
+/// The object returned by oraclessamples.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OracleSamples {
+    /// the latest data points, newest first
+    pub samples: Vec<OracleSample>,
+}
+
+/// A value of oracle data, written by encode_oracle_data according to one format character.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OracleValue {
+    /// a string, for the s and S formats
+    Text(String),
+    /// binary data, for the d and D formats
+    Bytes(Vec<u8>),
+    /// a signed integer, for the c, t, i and l formats
+    Int(i64),
+    /// an unsigned integer, for the C, T, I and L formats
+    UInt(u64),
+    /// a hash in the hex form the daemon prints, for the h format
+    Hash(String),
+}
+
+/**
+ *Function Name: encode_oracle_data
+ *@params: format - the format of the oracle, one character per value:
+ *            s/S - a string of less than 256/65536 bytes, d/D - binary data of less
+ *            than 256/65536 bytes, c/C, t/T, i/I, l/L - signed/unsigned integers of
+ *            1, 2, 4 and 8 bytes, h - a 32 byte hash
+ *            values - one value per format character
+ *Output: the hex payload of oraclesdata; lengths and integers are little-endian
+ *           KomodoError::OracleData - when a value does not fit its format character
+ */
+pub fn encode_oracle_data(format: &str, values: &[OracleValue]) -> Result<String, KomodoError> {
+    if format.chars().count() != values.len() {
+        return Err(KomodoError::OracleData(format!(
+            "format {:?} needs {} values, got {}",
+            format,
+            format.chars().count(),
+            values.len()
+        )));
+    }
+    let mut data = Vec::new();
+    for (kind, value) in format.chars().zip(values) {
+        match (kind, value) {
+            ('s', OracleValue::Text(text)) => push_prefixed(&mut data, text.as_bytes(), 1)?,
+            ('S', OracleValue::Text(text)) => push_prefixed(&mut data, text.as_bytes(), 2)?,
+            ('d', OracleValue::Bytes(bytes)) => push_prefixed(&mut data, bytes, 1)?,
+            ('D', OracleValue::Bytes(bytes)) => push_prefixed(&mut data, bytes, 2)?,
+            ('c', OracleValue::Int(n)) => push_int(&mut data, *n as i128, 1, true)?,
+            ('t', OracleValue::Int(n)) => push_int(&mut data, *n as i128, 2, true)?,
+            ('i', OracleValue::Int(n)) => push_int(&mut data, *n as i128, 4, true)?,
+            ('l', OracleValue::Int(n)) => push_int(&mut data, *n as i128, 8, true)?,
+            ('C', OracleValue::UInt(n)) => push_int(&mut data, *n as i128, 1, false)?,
+            ('T', OracleValue::UInt(n)) => push_int(&mut data, *n as i128, 2, false)?,
+            ('I', OracleValue::UInt(n)) => push_int(&mut data, *n as i128, 4, false)?,
+            ('L', OracleValue::UInt(n)) => push_int(&mut data, *n as i128, 8, false)?,
+            ('h', OracleValue::Hash(hash)) => {
+                let mut bytes = match hash.from_hex() {
+                    Ok(ref bytes) if bytes.len() == 32 => bytes.clone(),
+                    _ => {
+                        return Err(KomodoError::OracleData(format!(
+                            "{:?} is not a 32 byte hash",
+                            hash
+                        )))
+                    }
+                };
+                // the daemon prints hashes with the byte order reversed
+                bytes.reverse();
+                data.extend(bytes);
+            }
+            (kind, value) => {
+                return Err(KomodoError::OracleData(format!(
+                    "{:?} does not match format character {:?}",
+                    value, kind
+                )))
+            }
+        }
+    }
+    Ok(data.to_hex())
+}
+
+fn push_prefixed(data: &mut Vec<u8>, bytes: &[u8], prefix: usize) -> Result<(), KomodoError> {
+    push_int(data, bytes.len() as i128, prefix, false)
+        .map_err(|_| KomodoError::OracleData(format!("{} bytes are too long", bytes.len())))?;
+    data.extend_from_slice(bytes);
+    Ok(())
+}
+
+fn push_int(data: &mut Vec<u8>, n: i128, size: usize, signed: bool) -> Result<(), KomodoError> {
+    let bits = 8 * size as u32;
+    let (min, max) = if signed {
+        (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+    } else {
+        (0, (1i128 << bits) - 1)
+    };
+    if n < min || n > max {
+        return Err(KomodoError::OracleData(format!(
+            "{} does not fit in {} bytes",
+            n, size
+        )));
+    }
+    data.extend_from_slice(&n.to_le_bytes()[..size]);
+    Ok(())
+}
+
+/// Calls of the Antara oracles API, borrowed from a KomodoClient with `client.oracles()`.
+pub struct Oracles<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Oracles<'a, C> {
+    ///
+    /// The oraclescreate method creates a new oracle.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the oracle
+    /// * `description` 	(string, required) 	the description of the oracle
+    /// * `format` 	(string, required) 	the format of the data, one character per value (see encode_oracle_data)
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the oracletxid once broadcast
+    /// %%%
+    pub fn oracles_create(
+        &self,
+        name: String,
+        description: String,
+        format: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("oraclescreate");
+        let method_body = vec![json!(name), json!(description), json!(format)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oraclesfund method funds the publisher's baton with the fee of oraclesregister.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracletxid` 	(string, required) 	the id of the oracle
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn oracles_fund(&self, oracle_tx_id: String) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("oraclesfund");
+        let method_body = vec![json!(oracle_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oraclesregister method registers the node as a publisher of the oracle.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracletxid` 	(string, required) 	the id of the oracle
+    /// * `datafee` 	(numeric, required) 	the fee subscribers pay for each data point, in satoshis
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn oracles_register(
+        &self,
+        oracle_tx_id: String,
+        data_fee: u64,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("oraclesregister");
+        let method_body = vec![json!(oracle_tx_id), json!(data_fee.to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oraclessubscribe method pays a publisher of the oracle for its data.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracletxid` 	(string, required) 	the id of the oracle
+    /// * `publisher` 	(string, required) 	the pubkey of the publisher
+    /// * `amount` 	(numeric, required) 	the amount of coins to pay
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn oracles_subscribe(
+        &self,
+        oracle_tx_id: String,
+        publisher: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("oraclessubscribe");
+        let method_body = vec![
+            json!(oracle_tx_id),
+            json!(publisher),
+            json!(amount.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oraclesdata method publishes data to the oracle.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracletxid` 	(string, required) 	the id of the oracle
+    /// * `hexstr` 	(string, required) 	the data in hex, as built by encode_oracle_data
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn oracles_data(
+        &self,
+        oracle_tx_id: String,
+        hex_str: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("oraclesdata");
+        let method_body = vec![json!(oracle_tx_id), json!(hex_str)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oraclessample method fetches one data point of the oracle.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracletxid` 	(string, required) 	the id of the oracle
+    /// * `txid` 	(string, required) 	the txid of the data transaction
+    ///
+    /// # Response
+    ///
+    /// * `data` 	(array) 	the decoded values
+    /// %%%
+    pub fn oracles_sample(
+        &self,
+        oracle_tx_id: String,
+        tx_id: String,
+    ) -> Response<C, CCReply<OracleSample>>
+    where
+        C: Transport<CCReply<OracleSample>>,
+    {
+        let method_name: String = String::from("oraclessample");
+        let method_body = vec![json!(oracle_tx_id), json!(tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oraclessamples method fetches the latest data points of a publisher.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracletxid` 	(string, required) 	the id of the oracle
+    /// * `batonaddress` 	(string, required) 	the baton address of the publisher (see oraclesinfo)
+    /// * `num` 	(numeric, required) 	the number of data points
+    ///
+    /// # Response
+    ///
+    /// * `samples` 	(array) 	the data points, newest first
+    /// %%%
+    pub fn oracles_samples(
+        &self,
+        oracle_tx_id: String,
+        baton_address: String,
+        num: u32,
+    ) -> Response<C, CCReply<OracleSamples>>
+    where
+        C: Transport<CCReply<OracleSamples>>,
+    {
+        let method_name: String = String::from("oraclessamples");
+        let method_body = vec![
+            json!(oracle_tx_id),
+            json!(baton_address),
+            json!(num.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oraclesinfo method returns the details of an oracle and its publishers.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracletxid` 	(string, required) 	the id of the oracle
+    ///
+    /// # Response
+    ///
+    /// * see OracleInfo
+    /// %%%
+    pub fn oracles_info(&self, oracle_tx_id: String) -> Response<C, CCReply<OracleInfo>>
+    where
+        C: Transport<CCReply<OracleInfo>>,
+    {
+        let method_name: String = String::from("oraclesinfo");
+        let method_body = vec![json!(oracle_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The oracleslist method lists the ids of all oracles on the chain.
+    ///
+    /// # Response
+    ///
+    /// * `oracletxid` 	(string) 	the id of an oracle
+    /// %%%
+    pub fn oracles_list(&self) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("oracleslist");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+}
+
+/**
+ * OraclePublisher publishes data to an oracle the node is registered with.
+ * Each publish encodes the values with the oracle's format, builds the
+ * oraclesdata transaction and broadcasts it.
+ *
+ * let publisher = OraclePublisher::new(&client, oracle_tx_id)?;
+ * let txid = publisher.publish(&[OracleValue::UInt(1_234_500)])?;
+ */
+pub struct OraclePublisher<'a> {
+    client: &'a KomodoClient,
+    oracle_tx_id: String,
+    format: String,
+}
+
+impl<'a> OraclePublisher<'a> {
+    /**
+     *Function Name: new
+     *@params: client - the client of the node registered as publisher
+     *            oracle_tx_id - the id of the oracle
+     *Output: a publisher using the format read with oraclesinfo
+     */
+    pub fn new(
+        client: &'a KomodoClient,
+        oracle_tx_id: String,
+    ) -> Result<OraclePublisher<'a>, KomodoError> {
+        let info = client
+            .oracles()
+            .oracles_info(oracle_tx_id.clone())?
+            .into_result()?;
+        Ok(OraclePublisher {
+            client,
+            oracle_tx_id,
+            format: info.format,
+        })
+    }
+
+    /// The format method returns the format of the oracle.
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    /**
+     *Function Name: publish
+     *@params: values - one value per character of the oracle's format
+     *Output: the txid of the broadcast oraclesdata transaction
+     */
+    pub fn publish(&self, values: &[OracleValue]) -> Result<String, KomodoError> {
+        let hex_str = encode_oracle_data(&self.format, values)?;
+        self.client
+            .oracles()
+            .oracles_data(self.oracle_tx_id.clone(), hex_str)?
+            .into_result()?
+            .broadcast(self.client)
+    }
+}
+
+/**
+ * OracleSubscriber reads the data of one publisher of an oracle and pays
+ * for the subscription.
+ *
+ * let subscriber = OracleSubscriber::new(&client, oracle_tx_id, publisher_pubkey);
+ * subscriber.subscribe("1".parse::<Amount>()?)?;
+ * let latest = subscriber.latest(10)?;
+ */
+pub struct OracleSubscriber<'a> {
+    client: &'a KomodoClient,
+    oracle_tx_id: String,
+    publisher: String,
+}
+
+impl<'a> OracleSubscriber<'a> {
+    /// The new method of OracleSubscriber follows the publisher with this pubkey.
+    pub fn new(
+        client: &'a KomodoClient,
+        oracle_tx_id: String,
+        publisher: String,
+    ) -> OracleSubscriber<'a> {
+        OracleSubscriber {
+            client,
+            oracle_tx_id,
+            publisher,
+        }
+    }
+
+    /**
+     *Function Name: subscribe
+     *@params: amount - the amount of coins to pay the publisher
+     *Output: the txid of the broadcast oraclessubscribe transaction
+     */
+    pub fn subscribe(&self, amount: Amount) -> Result<String, KomodoError> {
+        self.client
+            .oracles()
+            .oracles_subscribe(self.oracle_tx_id.clone(), self.publisher.clone(), amount)?
+            .into_result()?
+            .broadcast(self.client)
+    }
+
+    /**
+     *Function Name: latest
+     *@params: num - the number of data points
+     *Output: the latest data points of the publisher, newest first
+     *           KomodoError::Antara - when the publisher is not registered with the oracle
+     */
+    pub fn latest(&self, num: u32) -> Result<Vec<OracleSample>, KomodoError> {
+        let oracles = self.client.oracles();
+        let info = oracles
+            .oracles_info(self.oracle_tx_id.clone())?
+            .into_result()?;
+        let baton = match info
+            .registered
+            .into_iter()
+            .find(|registered| registered.publisher == self.publisher)
+        {
+            Some(registered) => registered.baton,
+            None => {
+                return Err(KomodoError::Antara(format!(
+                    "{} is not a publisher of oracle {}",
+                    self.publisher, self.oracle_tx_id
+                )))
+            }
+        };
+        Ok(oracles
+            .oracles_samples(self.oracle_tx_id.clone(), baton, num)?
+            .into_result()?
+            .samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn encode_oracle_data_writes_little_endian_with_length_prefixes() {
+        assert_eq!(
+            encode_oracle_data("s", &[OracleValue::Text(String::from("Hello, world!"))]).unwrap(),
+            "0d48656c6c6f2c20776f726c6421"
+        );
+        assert_eq!(
+            encode_oracle_data(
+                "SLc",
+                &[
+                    OracleValue::Text(String::from("hi")),
+                    OracleValue::UInt(10000),
+                    OracleValue::Int(-1),
+                ]
+            )
+            .unwrap(),
+            "020068691027000000000000ff"
+        );
+        assert_eq!(
+            encode_oracle_data("D", &[OracleValue::Bytes(vec![0xde, 0xad])]).unwrap(),
+            "0200dead"
+        );
+        let hash = format!("{}01", "00".repeat(31));
+        assert_eq!(
+            encode_oracle_data("h", &[OracleValue::Hash(hash)]).unwrap(),
+            format!("01{}", "00".repeat(31))
+        );
+    }
+
+    #[test]
+    fn encode_oracle_data_rejects_bad_values() {
+        let too_long = OracleValue::Text("x".repeat(256));
+        for (format, values) in vec![
+            ("L", vec![]),
+            ("s", vec![too_long]),
+            ("C", vec![OracleValue::UInt(256)]),
+            ("c", vec![OracleValue::Int(128)]),
+            ("L", vec![OracleValue::Int(5)]),
+            ("h", vec![OracleValue::Hash(String::from("abcd"))]),
+        ] {
+            match encode_oracle_data(format, &values) {
+                Err(KomodoError::OracleData(_)) => {}
+                other => panic!("{:?} should not encode, got {:?}", format, other),
+            }
+        }
+    }
+
+    #[test]
+    fn publisher_encodes_and_broadcasts() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "oraclesinfo",
+            json!({
+                "result": "success",
+                "txid": MOCK_TXID,
+                "name": "KPAYUSD",
+                "description": "KPay price in US cents",
+                "format": "L",
+                "marker": "RFHz6uVgUrgBaL3pSS3x2Jk4y7sPqMmwqK",
+                "registered": []
+            }),
+        );
+        daemon.set_result(
+            "oraclesdata",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        daemon.set_result("sendrawtransaction", json!(MOCK_TXID));
+        let client = daemon.client();
+        let publisher = OraclePublisher::new(&client, MOCK_TXID.to_string()).unwrap();
+        let txid = publisher.publish(&[OracleValue::UInt(125)]).unwrap();
+        assert_eq!(txid, MOCK_TXID);
+        assert_eq!(
+            daemon.last_params("oraclesdata"),
+            json!([MOCK_TXID, "7d00000000000000"])
+        );
+    }
+}
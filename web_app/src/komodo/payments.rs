@@ -0,0 +1,337 @@
+//!
+//! This is the documentation for 'Payments' module of Komodo.
+//!
+//! The 'Payments' module of Komodo contains functionality of the Antara 'Payments' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::payments()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * A payments plan pays its funds out to a fixed set of recipients. Each recipient is
+//! a transaction made with paymentstxidopret, holding its allocation and scriptPubKey; a release
+//! splits the released amount between the recipients by allocation.
+//!
+//! * The daemon reads the arguments of these methods from a single json array, sent as a string.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/payments.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::{Amount, KomodoApi};
+use komodorpcutil::{KomodoClient, KomodoError, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// A recipient of a payments plan, as listed by paymentsinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PaymentsRecipient {
+    /// the txid of the paymentstxidopret transaction
+    pub txid: Option<String>,
+    /// the share of the recipient in each release
+    pub allocation: u64,
+    /// the script the recipient is paid to
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: String,
+    /// the op_return data added to the payouts of the recipient
+    pub destopret: Option<String>,
+}
+
+/// The object returned by paymentsinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PaymentsInfo {
+    /// the txid of the plan
+    pub createtxid: Option<String>,
+    /// the number of blocks funds stay locked before they can be released
+    pub lockedblocks: u32,
+    /// the minimum amount of a release
+    #[serde(default)]
+    pub minrelease: Option<Amount>,
+    /// the sum of the allocations of the recipients
+    pub totalallocations: u64,
+    /// the recipients of the plan
+    #[serde(default)]
+    pub txidoprets: Vec<PaymentsRecipient>,
+    /// the funds of the plan
+    #[serde(default)]
+    pub totalfunds: Option<Amount>,
+    /// the funds that can be released now
+    #[serde(default)]
+    pub elegiblefunds: Option<Amount>,
+    /// the other fields of the reply
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// The object returned by paymentslist.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PaymentsList {
+    /// the txids of the plans on the chain
+    #[serde(default)]
+    pub createtxids: Vec<String>,
+}
+
+/// Calls of the Antara payments API, borrowed from a KomodoClient with `client.payments()`.
+pub struct Payments<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Payments<'a, C> {
+    ///
+    /// The paymentstxidopret method creates the transaction describing one recipient of a plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `allocation` 	(numeric, required) 	the share of the recipient in each release
+    /// * `scriptPubKey` 	(string, required) 	the script the recipient is paid to
+    /// * `destopret` 	(string, optional) 	op_return data to add to the payouts of the recipient
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is passed to paymentscreate once broadcast
+    /// %%%
+    pub fn payments_txid_opret(
+        &self,
+        allocation: u64,
+        script_pub_key: String,
+        dest_opret: Option<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("paymentstxidopret");
+        let mut args = vec![json!(allocation), json!(script_pub_key)];
+        if let Some(temp_dest_opret) = dest_opret {
+            args.push(json!(temp_dest_opret));
+        }
+        let method_body = vec![json!(Value::Array(args).to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The paymentscreate method creates a payments plan for the given recipients.
+    ///
+    /// # Arguments
+    ///
+    /// * `lockedblocks` 	(numeric, required) 	the number of blocks funds stay locked before they can be released
+    /// * `minamount` 	(numeric, required) 	the minimum amount of a release, in coins
+    /// * `paytxid` 	(string, required) 	the txids of the paymentstxidopret transactions of the recipients
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the createtxid once broadcast
+    /// %%%
+    pub fn payments_create(
+        &self,
+        locked_blocks: u32,
+        min_release: Amount,
+        pay_tx_ids: Vec<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("paymentscreate");
+        let mut args = vec![json!(locked_blocks), json!(min_release)];
+        args.extend(pay_tx_ids.into_iter().map(|txid| json!(txid)));
+        let method_body = vec![json!(Value::Array(args).to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The paymentsfund method adds funds to a payments plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `createtxid` 	(string, required) 	the txid of the plan
+    /// * `amount` 	(numeric, required) 	the amount of coins to add
+    /// * `useopret` 	(boolean, optional, default=false) 	mark the funds with an op_return instead of a CC output
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn payments_fund(
+        &self,
+        create_tx_id: String,
+        amount: Amount,
+        use_opret: Option<bool>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("paymentsfund");
+        let mut args = vec![json!(create_tx_id), json!(amount)];
+        if let Some(temp_use_opret) = use_opret {
+            args.push(json!(temp_use_opret as u8));
+        }
+        let method_body = vec![json!(Value::Array(args).to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The paymentsrelease method pays out funds of a plan to its recipients, split by allocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `createtxid` 	(string, required) 	the txid of the plan
+    /// * `amount` 	(numeric, required) 	the amount of coins to release
+    /// * `skipminimum` 	(boolean, optional, default=false) 	leave out the recipients whose share is below the dust limit
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    ///
+    /// # Examples
+    /// ```
+    /// let txid = client.payments().payments_release(createtxid, "10".parse::<Amount>()?, None)?.into_result()?.broadcast(&client)?;
+    /// ```
+    /// %%%
+    pub fn payments_release(
+        &self,
+        create_tx_id: String,
+        amount: Amount,
+        skip_minimum: Option<bool>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("paymentsrelease");
+        let mut args = vec![json!(create_tx_id), json!(amount)];
+        if let Some(temp_skip_minimum) = skip_minimum {
+            args.push(json!(temp_skip_minimum as u8));
+        }
+        let method_body = vec![json!(Value::Array(args).to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The paymentsinfo method returns the details of a payments plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `createtxid` 	(string, required) 	the txid of the plan
+    ///
+    /// # Response
+    ///
+    /// * see PaymentsInfo
+    /// %%%
+    pub fn payments_info(&self, create_tx_id: String) -> Response<C, CCReply<PaymentsInfo>>
+    where
+        C: Transport<CCReply<PaymentsInfo>>,
+    {
+        let method_name: String = String::from("paymentsinfo");
+        let method_body = vec![json!(json!([create_tx_id]).to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The paymentslist method lists the txids of all payments plans on the chain.
+    ///
+    /// # Response
+    ///
+    /// * `createtxids` 	(array of strings) 	the txids of the plans
+    /// %%%
+    pub fn payments_list(&self) -> Response<C, CCReply<PaymentsList>>
+    where
+        C: Transport<CCReply<PaymentsList>>,
+    {
+        let method_name: String = String::from("paymentslist");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+}
+
+/**
+ *Function Name: schedule_payouts
+ *@params: client - the client of the chain
+ *         locked_blocks - the number of blocks funds stay locked before they can be released
+ *         min_release - the minimum amount of a release
+ *         recipients - the scriptPubKey and allocation of each recipient
+ *Output: the createtxid of the plan, once every transaction was broadcast
+ */
+pub fn schedule_payouts(
+    client: &KomodoClient,
+    locked_blocks: u32,
+    min_release: Amount,
+    recipients: &[(String, u64)],
+) -> Result<String, KomodoError> {
+    let payments = client.payments();
+    let mut pay_tx_ids = Vec::new();
+    for (script_pub_key, allocation) in recipients {
+        let transaction = payments
+            .payments_txid_opret(*allocation, script_pub_key.clone(), None)?
+            .into_result()?;
+        pay_tx_ids.push(transaction.broadcast(client)?);
+    }
+    payments
+        .payments_create(locked_blocks, min_release, pay_tx_ids)?
+        .into_result()?
+        .broadcast(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn payments_create_sends_one_json_array() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "paymentscreate",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        daemon
+            .client()
+            .payments()
+            .payments_create(
+                10,
+                Amount::from_sat(50_000_000),
+                vec![MOCK_TXID.to_string()],
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("paymentscreate"),
+            json!([format!("[10,0.50000000,\"{}\"]", MOCK_TXID)])
+        );
+    }
+
+    #[test]
+    fn payments_info_reads_the_recipients() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "paymentsinfo",
+            json!({
+                "result": "success",
+                "createtxid": MOCK_TXID,
+                "lockedblocks": 10,
+                "totalallocations": 3,
+                "minrelease": "0.50000000",
+                "txidoprets": [
+                    { "txid": MOCK_TXID, "allocation": 1, "scriptPubKey": "76a914" },
+                    { "txid": MOCK_TXID, "allocation": 2, "scriptPubKey": "76a915" }
+                ],
+                "totalfunds": 12.5,
+                "utxos": 2
+            }),
+        );
+        let info = daemon
+            .client()
+            .payments()
+            .payments_info(MOCK_TXID.to_string())
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(info.txidoprets[1].allocation, 2);
+        assert_eq!(info.minrelease, Some(Amount::from_sat(50_000_000)));
+        assert_eq!(info.totalfunds, Some(Amount::from_sat(1_250_000_000)));
+    }
+}
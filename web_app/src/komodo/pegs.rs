@@ -0,0 +1,401 @@
+//!
+//! This is the documentation for 'Pegs' module of Komodo.
+//!
+//! The 'Pegs' module of Komodo contains functionality of the Antara 'Pegs' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::pegs()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * Pegs lets users deposit gateway tokens in an account and borrow the chain's coin against them,
+//! at the price reported by the chain's price feeds. Accounts whose debt grows too large compared
+//! to their deposit can be liquidated.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/pegs.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// The deposit and debt of one token, as listed by pegsinfo and pegsaccountinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PegsTokenBalance {
+    /// the name of the token
+    pub token: String,
+    /// the tokens deposited
+    #[serde(alias = "total deposit")]
+    pub deposit: Amount,
+    /// the coins borrowed against the deposit
+    #[serde(alias = "total debt")]
+    pub debt: Amount,
+    /// the debt to deposit ratio, e.g. "62.50%"
+    #[serde(alias = "total ratio")]
+    pub ratio: Option<String>,
+}
+
+/// The object returned by pegsinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PegsInfo {
+    /// the name of the module
+    pub name: Option<String>,
+    /// the totals of each token of the pegs
+    #[serde(default)]
+    pub info: Vec<PegsTokenBalance>,
+    /// the debt to deposit ratio of all accounts
+    #[serde(rename = "global ratio")]
+    pub global_ratio: Option<String>,
+}
+
+/// The object returned by pegsaccountinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PegsAccountInfo {
+    /// the name of the module
+    pub name: Option<String>,
+    /// the balances of the account, by token
+    #[serde(rename = "account info", default)]
+    pub account_info: Vec<PegsTokenBalance>,
+}
+
+/// One action on an account, as listed by pegsaccounthistory.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PegsAction {
+    /// the action, e.g. "fund", "get", "redeem" or "exchange"
+    pub action: String,
+    /// the amount of the action
+    pub amount: Amount,
+    /// the txid of the account
+    pub accounttxid: String,
+    /// the name of the token
+    pub token: String,
+    /// the deposit after the action
+    pub deposit: Amount,
+    /// the debt after the action
+    pub debt: Amount,
+}
+
+/// The object returned by pegsaccounthistory.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PegsAccountHistory {
+    /// the name of the module
+    pub name: Option<String>,
+    /// the actions on the accounts of the node
+    #[serde(rename = "account history", default)]
+    pub account_history: Vec<PegsAction>,
+}
+
+/// The object returned by pegsworstaccounts: the name of the module, and the
+/// accounts closest to liquidation listed under the id of their token.
+pub type PegsWorstAccounts = BTreeMap<String, Value>;
+
+/// Calls of the Antara pegs API, borrowed from a KomodoClient with `client.pegs()`.
+pub struct Pegs<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Pegs<'a, C> {
+    ///
+    /// The pegscreate method creates the pegs of the chain, backed by the given gateways.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` 	(numeric, required) 	the amount of coins locked in the pegs to pay the fees of its transactions
+    /// * `N` 	(numeric, required) 	the number of gateways
+    /// * `bindtxid` 	(string, required) 	the txids of the N gateways
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the pegstxid once broadcast
+    /// %%%
+    pub fn pegs_create(
+        &self,
+        amount: Amount,
+        bind_tx_ids: Vec<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("pegscreate");
+        let mut method_body = vec![
+            json!(amount.to_string()),
+            json!(bind_tx_ids.len().to_string()),
+        ];
+        method_body.extend(bind_tx_ids.into_iter().map(|txid| json!(txid)));
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pegsfund method deposits gateway tokens in the account of the node, creating it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    /// * `tokenid` 	(string, required) 	the token to deposit
+    /// * `amount` 	(numeric, required) 	the amount of tokens to deposit
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn pegs_fund(
+        &self,
+        pegs_tx_id: String,
+        token_id: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        self.pegs_amount_call("pegsfund", pegs_tx_id, token_id, amount)
+    }
+
+    ///
+    /// The pegsget method borrows coins against the deposit of the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    /// * `tokenid` 	(string, required) 	the token of the account
+    /// * `amount` 	(numeric, required) 	the amount of coins to borrow
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn pegs_get(
+        &self,
+        pegs_tx_id: String,
+        token_id: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        self.pegs_amount_call("pegsget", pegs_tx_id, token_id, amount)
+    }
+
+    ///
+    /// The pegsredeem method repays the debt of the account and returns its deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    /// * `tokenid` 	(string, required) 	the token of the account
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn pegs_redeem(
+        &self,
+        pegs_tx_id: String,
+        token_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("pegsredeem");
+        let method_body = vec![json!(pegs_tx_id), json!(token_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pegsexchange method exchanges coins for gateway tokens taken from the accounts with the worst ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    /// * `tokenid` 	(string, required) 	the token to receive
+    /// * `amount` 	(numeric, required) 	the amount of coins to exchange
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn pegs_exchange(
+        &self,
+        pegs_tx_id: String,
+        token_id: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        self.pegs_amount_call("pegsexchange", pegs_tx_id, token_id, amount)
+    }
+
+    ///
+    /// The pegsliquidate method liquidates an account whose ratio went above the limit, repaying its debt for its deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    /// * `tokenid` 	(string, required) 	the token of the account
+    /// * `accounttxid` 	(string, required) 	the txid of the account, as listed by pegsworstaccounts
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn pegs_liquidate(
+        &self,
+        pegs_tx_id: String,
+        token_id: String,
+        account_tx_id: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("pegsliquidate");
+        let method_body = vec![json!(pegs_tx_id), json!(token_id), json!(account_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pegsaccounthistory method lists the actions on the accounts of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    ///
+    /// # Response
+    ///
+    /// * see PegsAccountHistory
+    /// %%%
+    pub fn pegs_account_history(
+        &self,
+        pegs_tx_id: String,
+    ) -> Response<C, CCReply<PegsAccountHistory>>
+    where
+        C: Transport<CCReply<PegsAccountHistory>>,
+    {
+        let method_name: String = String::from("pegsaccounthistory");
+        let method_body = vec![json!(pegs_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pegsaccountinfo method returns the balances of the accounts of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    ///
+    /// # Response
+    ///
+    /// * see PegsAccountInfo
+    /// %%%
+    pub fn pegs_account_info(&self, pegs_tx_id: String) -> Response<C, CCReply<PegsAccountInfo>>
+    where
+        C: Transport<CCReply<PegsAccountInfo>>,
+    {
+        let method_name: String = String::from("pegsaccountinfo");
+        let method_body = vec![json!(pegs_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pegsworstaccounts method lists the accounts closest to liquidation, by token.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    ///
+    /// # Response
+    ///
+    /// * `tokenid` 	(array of json objects) 	the accounttxid, deposit, debt and ratio of each account
+    /// %%%
+    pub fn pegs_worst_accounts(&self, pegs_tx_id: String) -> Response<C, CCReply<PegsWorstAccounts>>
+    where
+        C: Transport<CCReply<PegsWorstAccounts>>,
+    {
+        let method_name: String = String::from("pegsworstaccounts");
+        let method_body = vec![json!(pegs_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pegsinfo method returns the totals of the pegs.
+    ///
+    /// # Arguments
+    ///
+    /// * `pegstxid` 	(string, required) 	the txid of the pegs
+    ///
+    /// # Response
+    ///
+    /// * see PegsInfo
+    /// %%%
+    pub fn pegs_info(&self, pegs_tx_id: String) -> Response<C, CCReply<PegsInfo>>
+    where
+        C: Transport<CCReply<PegsInfo>>,
+    {
+        let method_name: String = String::from("pegsinfo");
+        let method_body = vec![json!(pegs_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    // pegsfund, pegsget and pegsexchange take the same (pegstxid, tokenid, amount) arguments
+    fn pegs_amount_call(
+        &self,
+        method: &str,
+        pegs_tx_id: String,
+        token_id: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from(method);
+        let method_body = vec![
+            json!(pegs_tx_id),
+            json!(token_id),
+            json!(amount.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn pegs_info_reads_the_token_totals() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "pegsinfo",
+            json!({
+                "result": "success",
+                "name": "pegsinfo",
+                "info": [{
+                    "token": "KMD",
+                    "total deposit": 100.0,
+                    "total debt": 50.0,
+                    "total ratio": "50.00%"
+                }],
+                "global ratio": "50.00%"
+            }),
+        );
+        let info = daemon
+            .client()
+            .pegs()
+            .pegs_info(MOCK_TXID.to_string())
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(info.info[0].deposit, Amount::from_sat(10_000_000_000));
+        assert_eq!(info.info[0].ratio.as_ref().unwrap(), "50.00%");
+        assert_eq!(info.global_ratio.unwrap(), "50.00%");
+    }
+}
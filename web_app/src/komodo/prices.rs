@@ -0,0 +1,254 @@
+//!
+//! This is the documentation for 'Prices' module of Komodo.
+//!
+//! The 'Prices' module of Komodo contains functionality of the Antara 'Prices' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::prices()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * A bet takes a leveraged position on a synthetic price, an expression over the price feeds
+//! of the chain in reverse polish notation, e.g. "BTC_USD, 1" or "KMD_BTC, BTC_USD, *, 1".
+//! A negative leverage bets on the price going down.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/prices.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::{option_number_from_str, Amount};
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// The bets listed by priceslist.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum PricesFilter {
+    /// every bet
+    All,
+    /// the bets that can still be cashed out
+    Open,
+    /// the bets that were cashed out or liquidated
+    Closed,
+}
+
+impl PricesFilter {
+    fn as_str(self) -> &'static str {
+        match self {
+            PricesFilter::All => "all",
+            PricesFilter::Open => "open",
+            PricesFilter::Closed => "closed",
+        }
+    }
+}
+
+/// The object returned by pricesinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PricesInfo {
+    /// 1 when the bet was liquidated
+    #[serde(default)]
+    pub rekt: u8,
+    /// 1 while the bet can still be cashed out
+    pub open: Option<u8>,
+    /// the synthetic price of the bet
+    pub expression: Option<String>,
+    /// the txid of the latest transaction of the bet
+    pub batontxid: Option<String>,
+    /// the price the bet was opened at
+    #[serde(default, deserialize_with = "option_number_from_str")]
+    pub costbasis: Option<f64>,
+    /// the amount of the bet with its added funding
+    #[serde(default)]
+    pub positionsize: Option<Amount>,
+    /// the profit of the bet at the current price
+    #[serde(default)]
+    pub profit: Option<Amount>,
+    /// the current value of the position
+    #[serde(default)]
+    pub equity: Option<Amount>,
+    /// the price at which the bet is liquidated
+    #[serde(default, deserialize_with = "option_number_from_str")]
+    pub liquidationprice: Option<f64>,
+    /// the other fields of the reply, e.g. the last price and the bets added to the position
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+/// Calls of the Antara prices API, borrowed from a KomodoClient with `client.prices()`.
+pub struct Prices<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Prices<'a, C> {
+    ///
+    /// The pricesbet method opens a bet on a synthetic price.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` 	(numeric, required) 	the amount to bet
+    /// * `leverage` 	(numeric, required) 	the leverage of the bet; negative to bet on the price going down
+    /// * `synthetic` 	(string, required) 	the expression of the synthetic price
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the bettxid once broadcast
+    /// %%%
+    pub fn prices_bet(
+        &self,
+        amount: Amount,
+        leverage: i32,
+        synthetic: String,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("pricesbet");
+        let method_body = vec![
+            json!(amount.to_string()),
+            json!(leverage.to_string()),
+            json!(synthetic),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pricesaddfunding method adds funds to a bet, moving its liquidation price further away.
+    ///
+    /// # Arguments
+    ///
+    /// * `bettxid` 	(string, required) 	the txid of the bet
+    /// * `amount` 	(numeric, required) 	the amount to add
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn prices_add_funding(
+        &self,
+        bet_tx_id: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("pricesaddfunding");
+        let method_body = vec![json!(bet_tx_id), json!(amount.to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The pricesinfo method returns the details of a bet, at the current height or the given one.
+    ///
+    /// # Arguments
+    ///
+    /// * `bettxid` 	(string, required) 	the txid of the bet
+    /// * `height` 	(numeric, optional) 	the height to value the bet at
+    ///
+    /// # Response
+    ///
+    /// * see PricesInfo
+    /// %%%
+    pub fn prices_info(
+        &self,
+        bet_tx_id: String,
+        height: Option<u64>,
+    ) -> Response<C, CCReply<PricesInfo>>
+    where
+        C: Transport<CCReply<PricesInfo>>,
+    {
+        let method_name: String = String::from("pricesinfo");
+        let mut method_body = vec![json!(bet_tx_id)];
+        if let Some(temp_height) = height {
+            method_body.push(json!(temp_height.to_string()));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The priceslist method lists the txids of the bets on the chain, or of the given pubkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` 	(string, optional, default="all") 	"all", "open" or "closed"
+    /// * `pubkey` 	(string, optional) 	the pubkey whose bets to list
+    ///
+    /// # Response
+    ///
+    /// * `bettxid` 	(string) 	the txid of a bet
+    /// %%%
+    pub fn prices_list(
+        &self,
+        filter: Option<PricesFilter>,
+        pubkey: Option<String>,
+    ) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("priceslist");
+        let mut method_body = Vec::new();
+        // the pubkey is the second argument, so it needs the filter before it
+        if filter.is_some() || pubkey.is_some() {
+            method_body.push(json!(filter.unwrap_or(PricesFilter::All).as_str()));
+        }
+        if let Some(temp_pubkey) = pubkey {
+            method_body.push(json!(temp_pubkey));
+        }
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn prices_list_fills_in_the_filter_before_a_pubkey() {
+        let daemon = MockKomodod::start();
+        daemon.set_result("priceslist", json!([MOCK_TXID]));
+        let bets = daemon
+            .client()
+            .prices()
+            .prices_list(None, Some(String::from("02a1b2c3")))
+            .unwrap();
+        assert_eq!(bets, vec![MOCK_TXID.to_string()]);
+        assert_eq!(daemon.last_params("priceslist"), json!(["all", "02a1b2c3"]));
+    }
+
+    #[test]
+    fn prices_info_reads_the_position() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "pricesinfo",
+            json!({
+                "result": "success",
+                "rekt": 0,
+                "open": 1,
+                "expression": "BTC_USD, 1",
+                "batontxid": MOCK_TXID,
+                "costbasis": 9876.54,
+                "positionsize": "10.00000000",
+                "profit": -0.5,
+                "LastPrice": 9851.2
+            }),
+        );
+        let info = daemon
+            .client()
+            .prices()
+            .prices_info(MOCK_TXID.to_string(), None)
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(info.rekt, 0);
+        assert_eq!(info.positionsize, Some(Amount::from_sat(1_000_000_000)));
+        assert_eq!(info.profit, Some(Amount::from_sat(-50_000_000)));
+        assert_eq!(info.other["LastPrice"], json!(9851.2));
+    }
+}
@@ -0,0 +1,251 @@
+//!
+//! This is the documentation for 'Rewards' module of Komodo.
+//!
+//! The 'Rewards' module of Komodo contains functionality of the Antara 'Rewards' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::rewards()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/rewards.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::{number_from_str, Amount};
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+/// The object returned by rewardsinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RewardsInfo {
+    /// the txid of the rewards plan
+    pub fundingtxid: String,
+    /// the name of the rewards plan
+    pub name: String,
+    /// the plan name packed into a number, as used in the plan's transactions
+    pub sbits: i64,
+    /// the annual percentage rate
+    #[serde(rename = "APR", deserialize_with = "number_from_str")]
+    pub apr: f64,
+    /// the minimum time in seconds funds must be locked
+    pub minseconds: i64,
+    /// the maximum time in seconds funds earn rewards
+    pub maxseconds: i64,
+    /// the minimum deposit, in coins
+    pub mindeposit: Amount,
+    /// the funds left to pay rewards
+    pub funding: Amount,
+    /// the funds locked in the plan
+    pub locked: Amount,
+}
+
+/// Calls of the Antara rewards API, borrowed from a KomodoClient with `client.rewards()`.
+pub struct Rewards<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Rewards<'a, C> {
+    ///
+    /// The rewardscreatefunding method creates a new rewards plan and funds it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the plan, at most 8 characters
+    /// * `amount` 	(numeric, required) 	the amount of coins to fund the plan with
+    /// * `APR` 	(numeric, required) 	the annual percentage rate, at most 25
+    /// * `mindays` 	(numeric, required) 	the minimum number of days funds must be locked
+    /// * `maxdays` 	(numeric, required) 	the maximum number of days funds earn rewards
+    /// * `mindeposit` 	(numeric, required) 	the minimum deposit, in coins
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the fundingtxid once broadcast
+    /// %%%
+    pub fn rewards_create_funding(
+        &self,
+        name: String,
+        amount: Amount,
+        apr: f64,
+        min_days: u32,
+        max_days: u32,
+        min_deposit: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("rewardscreatefunding");
+        let method_body = vec![
+            json!(name),
+            json!(amount.to_string()),
+            json!(apr.to_string()),
+            json!(min_days.to_string()),
+            json!(max_days.to_string()),
+            json!(min_deposit.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The rewardslock method locks funds in a rewards plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the plan
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    /// * `amount` 	(numeric, required) 	the amount of coins to lock
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is needed to unlock the funds
+    /// %%%
+    pub fn rewards_lock(
+        &self,
+        name: String,
+        funding_tx_id: String,
+        amount: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("rewardslock");
+        let method_body = vec![json!(name), json!(funding_tx_id), json!(amount.to_string())];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The rewardsunlock method unlocks funds with their rewards, or all funds of the node in the plan when no txid is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the plan
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    /// * `txid` 	(string, optional) 	the txid of the rewardslock transaction
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn rewards_unlock(
+        &self,
+        name: String,
+        funding_tx_id: String,
+        tx_id: Option<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("rewardsunlock");
+        let mut method_body = vec![json!(name), json!(funding_tx_id)];
+        if let Some(temp_tx_id) = tx_id {
+            method_body.push(json!(temp_tx_id));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The rewardsinfo method returns the details of a rewards plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `fundingtxid` 	(string, required) 	the txid of the plan
+    ///
+    /// # Response
+    ///
+    /// * see RewardsInfo
+    /// %%%
+    pub fn rewards_info(&self, funding_tx_id: String) -> Response<C, CCReply<RewardsInfo>>
+    where
+        C: Transport<CCReply<RewardsInfo>>,
+    {
+        let method_name: String = String::from("rewardsinfo");
+        let method_body = vec![json!(funding_tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The rewardslist method lists the txids of all rewards plans on the chain.
+    ///
+    /// # Response
+    ///
+    /// * `fundingtxid` 	(string) 	the txid of a plan
+    /// %%%
+    pub fn rewards_list(&self) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("rewardslist");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    #[test]
+    fn rewards_create_funding_sends_numbers_as_strings() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "rewardscreatefunding",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        let transaction = daemon
+            .client()
+            .rewards()
+            .rewards_create_funding(
+                String::from("SAVE"),
+                Amount::from_sat(100_000_000_000),
+                5.0,
+                1,
+                10,
+                Amount::from_sat(1_000_000_000),
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(transaction.hex, "0400008085202f89");
+        assert_eq!(
+            daemon.last_params("rewardscreatefunding"),
+            json!(["SAVE", "1000.00000000", "5", "1", "10", "10.00000000"])
+        );
+    }
+
+    #[test]
+    fn rewards_info_reads_string_amounts() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "rewardsinfo",
+            json!({
+                "result": "success",
+                "fundingtxid": MOCK_TXID,
+                "name": "SAVE",
+                "sbits": 1163280723,
+                "APR": "5.00000000",
+                "minseconds": 86400,
+                "maxseconds": 864000,
+                "mindeposit": "10.00000000",
+                "funding": "990.00000000",
+                "locked": "25.00000000"
+            }),
+        );
+        let info = daemon
+            .client()
+            .rewards()
+            .rewards_info(MOCK_TXID.to_string())
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(info.apr, 5.0);
+        assert_eq!(info.locked, Amount::from_sat(2_500_000_000));
+    }
+}
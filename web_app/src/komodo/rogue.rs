@@ -0,0 +1,283 @@
+//!
+//! This is the documentation for 'Rogue' module of Komodo.
+//!
+//! The 'Rogue' module of Komodo contains functionality of the Antara 'Rogue' game noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::rogue()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * Rogue is a cclib module: every method is the cclib RPC with the rogue method name, the
+//! evalcode 17 and the arguments as a json array sent as a string. The chain must run with
+//! `-ac_cclib=rogue`.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/rogue.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// The evalcode of the rogue module, passed to cclib.
+pub const ROGUE_EVALCODE: u8 = 17;
+
+/// The object returned by gameinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RogueGameInfo {
+    /// the txid of the game
+    pub gametxid: String,
+    /// the height of the game's transaction
+    pub height: Option<u64>,
+    /// the height at which the game starts
+    pub start: Option<u64>,
+    /// the number of players still playing
+    pub alive: Option<u32>,
+    /// the number of registered players
+    pub numplayers: Option<u32>,
+    /// the maximum number of players
+    pub maxplayers: Option<u32>,
+    /// the buy-in of the game; 0 for a practice game
+    #[serde(default)]
+    pub buyin: Option<Amount>,
+    /// the seed of the game's dungeon
+    pub seed: Option<Value>,
+    /// the players of the game and their state
+    #[serde(default)]
+    pub players: Vec<BTreeMap<String, Value>>,
+}
+
+/// The object returned by pending: the games still open for registration.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoguePending {
+    /// the txids of the games
+    #[serde(default)]
+    pub pending: Vec<String>,
+}
+
+/// The object returned by games: the games of the node.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RogueGames {
+    /// the games in progress
+    #[serde(default)]
+    pub games: Vec<String>,
+    /// the games that ended
+    #[serde(default)]
+    pub pastgames: Vec<String>,
+}
+
+/// The object returned by players: the characters of the node.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoguePlayers {
+    /// the txids holding the data of the characters
+    #[serde(default)]
+    pub playerdata: Vec<String>,
+}
+
+/// Calls of the Antara rogue game, borrowed from a KomodoClient with `client.rogue()`.
+pub struct Rogue<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Rogue<'a, C> {
+    ///
+    /// The newgame method creates a game.
+    ///
+    /// # Arguments
+    ///
+    /// * `maxplayers` 	(numeric, required) 	the maximum number of players
+    /// * `buyin` 	(numeric, required) 	the buy-in of each player, which the winner takes; 0 for a practice game
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; its txid is the gametxid once broadcast
+    /// %%%
+    pub fn rogue_new_game(
+        &self,
+        max_players: u32,
+        buyin: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        self.cclib("newgame", vec![json!(max_players), json!(buyin)])
+    }
+
+    ///
+    /// The register method registers the node in a game, optionally with a character of an earlier game.
+    ///
+    /// # Arguments
+    ///
+    /// * `gametxid` 	(string, required) 	the txid of the game
+    /// * `playertxid` 	(string, optional) 	the txid holding the data of the character
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn rogue_register(
+        &self,
+        game_tx_id: String,
+        player_tx_id: Option<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let mut args = vec![json!(game_tx_id)];
+        if let Some(temp_player_tx_id) = player_tx_id {
+            args.push(json!(temp_player_tx_id));
+        }
+        self.cclib("register", args)
+    }
+
+    ///
+    /// The bailout method leaves a game, converting the gold of the character into coins.
+    ///
+    /// # Arguments
+    ///
+    /// * `gametxid` 	(string, required) 	the txid of the game
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn rogue_bailout(&self, game_tx_id: String) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        self.cclib("bailout", vec![json!(game_tx_id)])
+    }
+
+    ///
+    /// The highlander method claims the buy-ins of a game, by the last player alive or one who found the amulet.
+    ///
+    /// # Arguments
+    ///
+    /// * `gametxid` 	(string, required) 	the txid of the game
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn rogue_highlander(&self, game_tx_id: String) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        self.cclib("highlander", vec![json!(game_tx_id)])
+    }
+
+    ///
+    /// The gameinfo method returns the details of a game.
+    ///
+    /// # Arguments
+    ///
+    /// * `gametxid` 	(string, required) 	the txid of the game
+    ///
+    /// # Response
+    ///
+    /// * see RogueGameInfo
+    /// %%%
+    pub fn rogue_game_info(&self, game_tx_id: String) -> Response<C, CCReply<RogueGameInfo>>
+    where
+        C: Transport<CCReply<RogueGameInfo>>,
+    {
+        self.cclib("gameinfo", vec![json!(game_tx_id)])
+    }
+
+    ///
+    /// The pending method lists the games still open for registration.
+    ///
+    /// # Response
+    ///
+    /// * `pending` 	(array of strings) 	the txids of the games
+    /// %%%
+    pub fn rogue_pending(&self) -> Response<C, CCReply<RoguePending>>
+    where
+        C: Transport<CCReply<RoguePending>>,
+    {
+        self.cclib("pending", Vec::new())
+    }
+
+    ///
+    /// The games method lists the games the node played in.
+    ///
+    /// # Response
+    ///
+    /// * `games`, `pastgames` 	(array of strings) 	the txids of the games in progress and of those that ended
+    /// %%%
+    pub fn rogue_games(&self) -> Response<C, CCReply<RogueGames>>
+    where
+        C: Transport<CCReply<RogueGames>>,
+    {
+        self.cclib("games", Vec::new())
+    }
+
+    ///
+    /// The players method lists the characters of the node, which can be registered in later games.
+    ///
+    /// # Response
+    ///
+    /// * `playerdata` 	(array of strings) 	the txids holding the data of the characters
+    /// %%%
+    pub fn rogue_players(&self) -> Response<C, CCReply<RoguePlayers>>
+    where
+        C: Transport<CCReply<RoguePlayers>>,
+    {
+        self.cclib("players", Vec::new())
+    }
+
+    // cclib <method> 17 "[args]"; the daemon reads no third argument as no arguments
+    fn cclib<T>(&self, method: &str, args: Vec<Value>) -> Response<C, T>
+    where
+        C: Transport<T>,
+    {
+        let method_name: String = String::from("cclib");
+        let mut method_body = vec![json!(method), json!(ROGUE_EVALCODE.to_string())];
+        if !args.is_empty() {
+            method_body.push(json!(Value::Array(args).to_string()));
+        }
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::MockKomodod;
+
+    #[test]
+    fn rogue_new_game_goes_through_cclib() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "cclib",
+            json!({
+                "name": "rogue",
+                "method": "newgame",
+                "maxplayers": 3,
+                "buyin": 10.0,
+                "hex": "0400008085202f89",
+                "result": "success"
+            }),
+        );
+        daemon
+            .client()
+            .rogue()
+            .rogue_new_game(3, Amount::from_sat(1_000_000_000))
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("cclib"),
+            json!(["newgame", "17", "[3,10.00000000]"])
+        );
+    }
+}
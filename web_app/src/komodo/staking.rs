@@ -0,0 +1,178 @@
+//!
+//! Staking overview of Komodo Smart Chains that use ac_staked.
+//!
+//! On these chains every staked coin sits in one of 64 segments (segids). The
+//! overview lines up the wallet's balance in each segid, from getbalance64,
+//! with the blocks each segid staked recently, from getlastsegidstakes.
+//!
+//! # Examples
+//! ```
+//! let overview = StakingOverview::fetch(&client, 1440)?;
+//! for segid in overview.segids.iter().filter(|segid| segid.staking > Amount::ZERO) {
+//!     println!("segid {}: {} staking, {} blocks", segid.segid, segid.staking, segid.blocks_staked);
+//! }
+//! ```
+//!
+
+use super::blockchain::SegidStakes;
+use super::komodorpcutil::{KomodoClient, KomodoError};
+use super::wallet::Balance64;
+use super::{Amount, KomodoApi};
+use serde_derive::{Deserialize, Serialize};
+
+/// The number of segments coins are staked in.
+pub const SEGID_COUNT: u8 = 64;
+
+/// The wallet's balance and recent stakes of one segid.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SegidOverview {
+    /// the segid, 0 to 63
+    pub segid: u8,
+    /// the mature balance of the wallet in this segid
+    pub staking: Amount,
+    /// the immature balance of the wallet in this segid
+    pub not_staking: Amount,
+    /// the number of blocks this segid staked within the depth of the overview
+    pub blocks_staked: u64,
+}
+
+/// The staking state of the wallet on an ac_staked chain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StakingOverview {
+    /// the number of blocks the stakes were counted over
+    pub depth: u32,
+    /// the balance of mature coins, which can stake
+    pub mature: Amount,
+    /// the balance of immature coins
+    pub immature: Amount,
+    /// the percentage of blocks created through PoS within the depth
+    pub pos_percent: f64,
+    /// the number of blocks created through PoW within the depth
+    pub pow_blocks: u64,
+    /// one entry per segid, ordered by segid
+    pub segids: Vec<SegidOverview>,
+}
+
+impl StakingOverview {
+    /**
+     *Function Name: new
+     *@params: depth - the depth stakes was requested with
+     *            balance - the reply of getbalance64
+     *            stakes - the reply of getlastsegidstakes
+     *Output: the overview of all 64 segids; a segid missing from either reply counts as 0
+     */
+    pub fn new(depth: u32, balance: &Balance64, stakes: &SegidStakes) -> StakingOverview {
+        let segids = (0..SEGID_COUNT)
+            .map(|segid| SegidOverview {
+                segid,
+                staking: balance
+                    .staking
+                    .get(segid as usize)
+                    .cloned()
+                    .unwrap_or(Amount::ZERO),
+                not_staking: balance
+                    .notstaking
+                    .get(segid as usize)
+                    .cloned()
+                    .unwrap_or(Amount::ZERO),
+                blocks_staked: stakes.seg_ids.get(&segid).cloned().unwrap_or(0),
+            })
+            .collect();
+        StakingOverview {
+            depth,
+            mature: balance.mature,
+            immature: balance.immature,
+            pos_percent: stakes.pos_perc,
+            pow_blocks: stakes.pow,
+            segids,
+        }
+    }
+
+    /**
+     *Function Name: fetch
+     *@params: client - the client of an ac_staked chain's daemon
+     *            depth - the number of blocks to count stakes over
+     *Output: the overview, from one batch request with getbalance64 and getlastsegidstakes
+     */
+    pub fn fetch(client: &KomodoClient, depth: u32) -> Result<StakingOverview, KomodoError> {
+        let batch = client.batch();
+        let balance = batch.wallet().get_balance64();
+        let stakes = batch.blockchain().get_last_segid_stakes(depth);
+        let reply = batch.send()?;
+        Ok(StakingOverview::new(
+            depth,
+            &reply.get(balance)?,
+            &reply.get(stakes)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mockkomodod::MockKomodod;
+    use serde_json::json;
+
+    fn segid_balances(segid: usize, amount: Amount) -> Vec<Amount> {
+        let mut balances = vec![Amount::ZERO; SEGID_COUNT as usize];
+        balances[segid] = amount;
+        balances
+    }
+
+    #[test]
+    fn new_lines_up_balances_and_stakes() {
+        let balance = Balance64 {
+            mature: Amount::from_sat(15_000_000_000),
+            immature: Amount::from_sat(200_000_000),
+            staking: segid_balances(5, Amount::from_sat(15_000_000_000)),
+            notstaking: segid_balances(63, Amount::from_sat(200_000_000)),
+        };
+        let stakes: SegidStakes = serde_json::from_value(json!({
+            "NotSet": 0,
+            "PoW": 4,
+            "PoSPerc": 60,
+            "SegIds": { "5": 3, "17": 3 }
+        }))
+        .unwrap();
+        let overview = StakingOverview::new(10, &balance, &stakes);
+        assert_eq!(overview.segids.len(), 64);
+        assert_eq!(overview.pos_percent, 60.0);
+        assert_eq!(
+            overview.segids[5],
+            SegidOverview {
+                segid: 5,
+                staking: Amount::from_sat(15_000_000_000),
+                not_staking: Amount::ZERO,
+                blocks_staked: 3,
+            }
+        );
+        assert_eq!(overview.segids[17].blocks_staked, 3);
+        assert_eq!(
+            overview.segids[63].not_staking,
+            Amount::from_sat(200_000_000)
+        );
+    }
+
+    #[test]
+    fn fetch_reads_both_replies() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "getbalance64",
+            json!({
+                "mature": 10.5,
+                "immature": 0.0,
+                "staking": segid_balances(2, Amount::from_sat(1_050_000_000)),
+                "notstaking": segid_balances(0, Amount::ZERO)
+            }),
+        );
+        daemon.set_result(
+            "getlastsegidstakes",
+            json!({ "NotSet": 1, "PoW": 0, "PoSPerc": 90, "SegIds": { "2": 9 } }),
+        );
+        let overview = StakingOverview::fetch(&daemon.client(), 10).unwrap();
+        assert_eq!(overview.mature, Amount::from_sat(1_050_000_000));
+        assert_eq!(overview.segids[2].blocks_staked, 9);
+        assert_eq!(daemon.requests().len(), 2);
+        assert_eq!(daemon.last_params("getlastsegidstakes"), json!([10]));
+    }
+}
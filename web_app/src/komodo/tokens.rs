@@ -0,0 +1,431 @@
+//!
+//! This is the documentation for 'Tokens' module of Komodo.
+//!
+//! The 'Tokens' module of Komodo contains functionality of the Antara 'Tokens' module noted on the
+//! [Komodo website].
+//!
+//! # Remarks
+//!
+//! * Methods are called on the handle returned by `KomodoClient::tokens()`.
+//!
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
+//!
+//! * The chain must be started with the -ac_cc parameter to use the Tokens module.
+//!
+//! * Replies are read into a `CCReply` and transactions are returned unsent (see the antara module).
+//!
+//! [Komodo website]: https://docs.komodoplatform.com/basic-docs/antara/antara-api/tokens.html
+//!
+
+use super::antara::{CCReply, CCTransaction};
+use super::komodorpcutil;
+use super::Amount;
+use komodorpcutil::{KomodoClient, Response, Transport};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+
+/// The object returned by tokeninfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TokenInfo {
+    /// the token id
+    pub tokenid: String,
+    /// the pubkey of the owner
+    pub owner: String,
+    /// the name of the token
+    pub name: String,
+    /// the total supply, in tokens
+    pub supply: u64,
+    /// the description of the token
+    pub description: String,
+    /// the hex data attached to the token, if any
+    pub data: Option<String>,
+}
+
+/// The object returned by tokenbalance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TokenBalance {
+    /// the CC address the tokens are held in
+    #[serde(rename = "CCaddress")]
+    pub cc_address: String,
+    /// the token id
+    pub tokenid: String,
+    /// the balance, in tokens
+    pub balance: u64,
+}
+
+/// An open bid or ask, as returned by tokenorders.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TokenOrder {
+    /// the function id of the order: b or B for a bid, s, S, e or E for an ask
+    pub funcid: String,
+    /// the id of the order transaction
+    pub txid: String,
+    /// the output of the order
+    pub vout: u32,
+    /// the amount locked in the order
+    pub amount: Amount,
+    /// the coins offered, for a bid
+    pub bidamount: Option<Amount>,
+    /// the tokens offered, for an ask
+    pub askamount: Option<Amount>,
+    /// the address of the creator of the order
+    pub origaddress: String,
+    /// the token address of the creator of the order
+    pub origtokenaddress: Option<String>,
+    /// the token id
+    pub tokenid: String,
+    /// the number of tokens (bid) or coins (ask) required to fill the order
+    pub totalrequired: Option<Amount>,
+    /// the price of one token, in coins
+    pub price: Option<Amount>,
+}
+
+/// Calls of the Antara tokens API, borrowed from a KomodoClient with `client.tokens()`.
+pub struct Tokens<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
+}
+
+impl<'a, C> Tokens<'a, C> {
+    ///
+    /// The tokencreate method creates a transaction which makes new tokens. One token is worth one satoshi of the coin, so a supply of 1 creates 100000000 tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` 	(string, required) 	the name of the token
+    /// * `supply` 	(numeric, required) 	the amount of coins converted into tokens
+    /// * `description` 	(string, optional) 	the description of the token
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex; the token id is the txid of this transaction once broadcast
+    ///
+    /// # Examples
+    /// ```
+    /// let txid = client.tokens().token_create("MYTOKEN".to_string(), Amount::from_sat(100_000_000), None)?
+    ///     .into_result()?
+    ///     .broadcast(&client)?;
+    /// ```
+    /// %%%
+    pub fn token_create(
+        &self,
+        name: String,
+        supply: Amount,
+        description: Option<String>,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("tokencreate");
+        let mut method_body = vec![json!(name), json!(supply.to_string())];
+        if let Some(temp_description) = description {
+            method_body.push(json!(temp_description));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokeninfo method reveals information about any token.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenid` 	(string, required) 	the token id
+    ///
+    /// # Response
+    ///
+    /// * `tokenid` 	(string) 	the token id
+    /// * `owner` 	(string) 	the pubkey of the owner
+    /// * `name` 	(string) 	the name of the token
+    /// * `supply` 	(numeric) 	the total supply, in tokens
+    /// * `description` 	(string) 	the description of the token
+    /// %%%
+    pub fn token_info(&self, token_id: String) -> Response<C, CCReply<TokenInfo>>
+    where
+        C: Transport<CCReply<TokenInfo>>,
+    {
+        let method_name: String = String::from("tokeninfo");
+        let method_body = vec![json!(token_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokenlist method lists all available tokens on the chain.
+    ///
+    /// # Response
+    ///
+    /// * `tokenid` 	(string) 	the id of a token
+    /// %%%
+    pub fn token_list(&self) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("tokenlist");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokenbalance method checks the token balance of a pubkey, by default the pubkey the daemon was started with.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenid` 	(string, required) 	the token id
+    /// * `pubkey` 	(string, optional) 	the pubkey to check
+    ///
+    /// # Response
+    ///
+    /// * `CCaddress` 	(string) 	the CC address the tokens are held in
+    /// * `tokenid` 	(string) 	the token id
+    /// * `balance` 	(numeric) 	the balance, in tokens
+    /// %%%
+    pub fn token_balance(
+        &self,
+        token_id: String,
+        pubkey: Option<String>,
+    ) -> Response<C, CCReply<TokenBalance>>
+    where
+        C: Transport<CCReply<TokenBalance>>,
+    {
+        let method_name: String = String::from("tokenbalance");
+        let mut method_body = vec![json!(token_id)];
+        if let Some(temp_pubkey) = pubkey {
+            method_body.push(json!(temp_pubkey));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokentransfer method transfers tokens to another pubkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenid` 	(string, required) 	the token id
+    /// * `destpubkey` 	(string, required) 	the pubkey receiving the tokens
+    /// * `amount` 	(numeric, required) 	the number of tokens to send
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn token_transfer(
+        &self,
+        token_id: String,
+        dest_pubkey: String,
+        amount: u64,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("tokentransfer");
+        let method_body = vec![
+            json!(token_id),
+            json!(dest_pubkey),
+            json!(amount.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokenbid method creates an order to buy tokens; the coins for the bid are locked until it is filled or cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `numtokens` 	(numeric, required) 	the number of tokens to buy
+    /// * `tokenid` 	(string, required) 	the token id
+    /// * `price` 	(numeric, required) 	the price of one token, in coins
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn token_bid(
+        &self,
+        num_tokens: u64,
+        token_id: String,
+        price: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("tokenbid");
+        let method_body = vec![
+            json!(num_tokens.to_string()),
+            json!(token_id),
+            json!(price.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokenask method creates an order to sell tokens; the tokens are locked until the ask is filled or cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `numtokens` 	(numeric, required) 	the number of tokens to sell
+    /// * `tokenid` 	(string, required) 	the token id
+    /// * `price` 	(numeric, required) 	the price of one token, in coins
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn token_ask(
+        &self,
+        num_tokens: u64,
+        token_id: String,
+        price: Amount,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("tokenask");
+        let method_body = vec![
+            json!(num_tokens.to_string()),
+            json!(token_id),
+            json!(price.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokenfillbid method sells tokens into an open bid.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenid` 	(string, required) 	the token id
+    /// * `bidtxid` 	(string, required) 	the txid of the bid
+    /// * `fillamount` 	(numeric, required) 	the number of tokens to sell
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn token_fill_bid(
+        &self,
+        token_id: String,
+        bid_tx_id: String,
+        fill_amount: u64,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("tokenfillbid");
+        let method_body = vec![
+            json!(token_id),
+            json!(bid_tx_id),
+            json!(fill_amount.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokenfillask method buys tokens from an open ask.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenid` 	(string, required) 	the token id
+    /// * `asktxid` 	(string, required) 	the txid of the ask
+    /// * `fillunits` 	(numeric, required) 	the number of tokens to buy
+    ///
+    /// # Response
+    ///
+    /// * `hex` 	(string) 	the transaction in hex
+    /// %%%
+    pub fn token_fill_ask(
+        &self,
+        token_id: String,
+        ask_tx_id: String,
+        fill_units: u64,
+    ) -> Response<C, CCReply<CCTransaction>>
+    where
+        C: Transport<CCReply<CCTransaction>>,
+    {
+        let method_name: String = String::from("tokenfillask");
+        let method_body = vec![
+            json!(token_id),
+            json!(ask_tx_id),
+            json!(fill_units.to_string()),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    ///
+    /// The tokenorders method lists the open bids and asks, for one token or for all tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenid` 	(string, optional) 	the token id; all tokens when it is left out
+    ///
+    /// # Response
+    ///
+    /// * an array of the open orders (see TokenOrder)
+    /// %%%
+    pub fn token_orders(&self, token_id: Option<String>) -> Response<C, Vec<TokenOrder>>
+    where
+        C: Transport<Vec<TokenOrder>>,
+    {
+        let method_name: String = String::from("tokenorders");
+        let mut method_body = Vec::new();
+        if let Some(temp_token_id) = token_id {
+            method_body.push(json!(temp_token_id));
+        }
+        self.client.call(&method_name, method_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::komodorpcutil::KomodoError;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    const TOKEN_ID: &str = "4a2e8bbe5a4e0b7c87ba9d2c2a3e61f55f2a1b7c9d53c0b8e7e2a4c4b27f5d31";
+
+    #[test]
+    fn token_create_sends_numbers_as_strings_and_broadcasts() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "tokencreate",
+            json!({ "result": "success", "hex": "0400008085202f89" }),
+        );
+        daemon.set_result("sendrawtransaction", json!(MOCK_TXID));
+        let client = daemon.client();
+        let transaction = client
+            .tokens()
+            .token_create(
+                String::from("KPAY"),
+                Amount::from_sat(50_000_000),
+                Some(String::from("points")),
+            )
+            .unwrap()
+            .into_result()
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("tokencreate"),
+            json!(["KPAY", "0.50000000", "points"])
+        );
+        assert_eq!(transaction.broadcast(&client).unwrap(), MOCK_TXID);
+        assert_eq!(
+            daemon.last_params("sendrawtransaction"),
+            json!(["0400008085202f89", false])
+        );
+    }
+
+    #[test]
+    fn module_errors_become_antara_errors() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "tokeninfo",
+            json!({ "result": "error", "error": "cant find tokenid" }),
+        );
+        let reply = daemon
+            .client()
+            .tokens()
+            .token_info(TOKEN_ID.to_string())
+            .unwrap();
+        match reply.into_result() {
+            Err(KomodoError::Antara(message)) => assert_eq!(message, "cant find tokenid"),
+            other => panic!("expected an Antara error, got {:?}", other),
+        }
+    }
+}
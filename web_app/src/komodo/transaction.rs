@@ -0,0 +1,721 @@
+//!
+//! Komodo transactions, decoded from and encoded to their raw bytes without the daemon.
+//!
+//! # Remarks
+//!
+//! * The layout is Zcash's, which Komodo follows: version 1 and 2 (Sprout) transactions, and the
+//! overwintered versions 3 (Overwinter) and 4 (Sapling), which add a version group id and an
+//! expiry height. Sapling transactions also carry shielded spends and outputs.
+//!
+//! * Decoding keeps every field, so encoding a decoded transaction gives back the same bytes.
+//! Byte strings that could not have come from a valid transaction, e.g. trailing bytes or a
+//! count written in more bytes than needed, are rejected.
+//!
+//! * Hashes are kept in the byte order of the transaction; `txid` and `TxIn::prev_tx_id` give
+//! them reversed, as the daemon prints them.
+//!
+//! # Examples
+//! ```
+//! let transaction = Transaction::from_hex(&hex)?;
+//! println!("{} pays {} outputs", transaction.txid(), transaction.vout.len());
+//! assert_eq!(transaction.to_hex(), hex);
+//! ```
+//!
+
+use super::komodorpcutil::KomodoError;
+use super::Amount;
+use rustc_serialize::hex::{FromHex, ToHex};
+use sha2::{Digest, Sha256};
+
+/// The version group id of Overwinter (version 3) transactions.
+pub const OVERWINTER_VERSION_GROUP_ID: u32 = 0x03C4_8270;
+
+/// The version group id of Sapling (version 4) transactions.
+pub const SAPLING_VERSION_GROUP_ID: u32 = 0x892F_2085;
+
+// The bit of the header that marks an overwintered transaction.
+const OVERWINTERED_FLAG: u32 = 1 << 31;
+
+// The sizes of the fixed-size parts of shielded data.
+const GROTH_PROOF_SIZE: usize = 192;
+const PHGR_PROOF_SIZE: usize = 296;
+const SAPLING_ENC_CIPHERTEXT_SIZE: usize = 580;
+const SAPLING_OUT_CIPHERTEXT_SIZE: usize = 80;
+const SPROUT_CIPHERTEXT_SIZE: usize = 601;
+const SIGNATURE_SIZE: usize = 64;
+
+/// A transparent input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxIn {
+    /// the txid of the transaction holding the spent output, in the byte order of the transaction
+    pub prev_tx_hash: [u8; 32],
+    /// the number of the spent output
+    pub prev_vout: u32,
+    /// the script unlocking the output; empty until the input is signed
+    pub script_sig: Vec<u8>,
+    /// the sequence number
+    pub sequence: u32,
+}
+
+impl TxIn {
+    /**
+     *Function Name: prev_tx_id
+     *@params: the instance of the struct TxIn
+     *Output: the txid of the spent output, as the daemon prints it
+     */
+    pub fn prev_tx_id(&self) -> String {
+        reversed_hex(&self.prev_tx_hash)
+    }
+}
+
+/// A transparent output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxOut {
+    /// the value of the output
+    pub value: Amount,
+    /// the script locking the output
+    pub script_pub_key: Vec<u8>,
+}
+
+/// A Sapling spend: a shielded note consumed by the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpendDescription {
+    /// the value commitment of the note
+    pub cv: [u8; 32],
+    /// the root of the note commitment tree the note is proven against
+    pub anchor: [u8; 32],
+    /// the nullifier of the note
+    pub nullifier: [u8; 32],
+    /// the randomized public key checking spend_auth_sig
+    pub rk: [u8; 32],
+    /// the zero-knowledge proof, 192 bytes
+    pub zkproof: Vec<u8>,
+    /// the spend authorization signature, 64 bytes
+    pub spend_auth_sig: Vec<u8>,
+}
+
+/// A Sapling output: a shielded note created by the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputDescription {
+    /// the value commitment of the note
+    pub cv: [u8; 32],
+    /// the note commitment
+    pub cmu: [u8; 32],
+    /// the ephemeral public key of the note encryption
+    pub ephemeral_key: [u8; 32],
+    /// the encrypted note for the recipient, 580 bytes
+    pub enc_ciphertext: Vec<u8>,
+    /// the encrypted note for the sender, 80 bytes
+    pub out_ciphertext: Vec<u8>,
+    /// the zero-knowledge proof, 192 bytes
+    pub zkproof: Vec<u8>,
+}
+
+/// A Sprout joinsplit: two shielded notes consumed and two created, as listed in vjoinsplit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinSplit {
+    /// the public value taken into the joinsplit
+    pub vpub_old: Amount,
+    /// the public value taken out of the joinsplit
+    pub vpub_new: Amount,
+    /// the root of the note commitment tree
+    pub anchor: [u8; 32],
+    /// the nullifiers of the input notes
+    pub nullifiers: [[u8; 32]; 2],
+    /// the commitments of the output notes
+    pub commitments: [[u8; 32]; 2],
+    /// the one-time public key used to encrypt the ciphertexts
+    pub onetime_pub_key: [u8; 32],
+    /// the random seed
+    pub random_seed: [u8; 32],
+    /// the MACs of the input notes
+    pub macs: [[u8; 32]; 2],
+    /// the zero-knowledge proof: 192 bytes in Sapling transactions, 296 bytes before
+    pub proof: Vec<u8>,
+    /// the encrypted output notes, 601 bytes each
+    pub ciphertexts: [Vec<u8>; 2],
+}
+
+/// A transaction of any version up to Sapling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    /// the overwintered flag; set for versions 3 and up
+    pub overwintered: bool,
+    /// the version
+    pub version: u32,
+    /// the version group id; 0 when the transaction is not overwintered
+    pub version_group_id: u32,
+    /// the transparent inputs
+    pub vin: Vec<TxIn>,
+    /// the transparent outputs
+    pub vout: Vec<TxOut>,
+    /// the lock time, a height or a time; on KMD it also dates the interest of the inputs
+    pub lock_time: u32,
+    /// the last height the transaction can be mined at; 0 when it is not overwintered
+    pub expiry_height: u32,
+    /// the value moved from the shielded pool to the transparent one (Sapling)
+    pub value_balance: Amount,
+    /// the Sapling spends
+    pub shielded_spends: Vec<SpendDescription>,
+    /// the Sapling outputs
+    pub shielded_outputs: Vec<OutputDescription>,
+    /// the Sprout joinsplits (version 2 and up)
+    pub join_splits: Vec<JoinSplit>,
+    /// the key checking join_split_sig; present when there are joinsplits
+    pub join_split_pub_key: Option<[u8; 32]>,
+    /// the signature over the joinsplits, 64 bytes; present when there are joinsplits
+    pub join_split_sig: Option<Vec<u8>>,
+    /// the Sapling binding signature, 64 bytes; present when there are spends or outputs
+    pub binding_sig: Option<Vec<u8>>,
+}
+
+impl Transaction {
+    /**
+     *Function Name: sapling
+     *@params: none
+     *Output: an empty version 4 transaction, to which inputs and outputs are added
+     */
+    pub fn sapling() -> Transaction {
+        Transaction {
+            overwintered: true,
+            version: 4,
+            version_group_id: SAPLING_VERSION_GROUP_ID,
+            vin: Vec::new(),
+            vout: Vec::new(),
+            lock_time: 0,
+            expiry_height: 0,
+            value_balance: Amount::ZERO,
+            shielded_spends: Vec::new(),
+            shielded_outputs: Vec::new(),
+            join_splits: Vec::new(),
+            join_split_pub_key: None,
+            join_split_sig: None,
+            binding_sig: None,
+        }
+    }
+
+    /**
+     *Function Name: from_hex
+     *@params: hex - the raw transaction in hex, as returned by getrawtransaction
+     *Output: the transaction, or KomodoError::InvalidTransaction
+     */
+    pub fn from_hex(hex: &str) -> Result<Transaction, KomodoError> {
+        let bytes = hex
+            .from_hex()
+            .map_err(|err| KomodoError::InvalidTransaction(err.to_string()))?;
+        Transaction::from_bytes(&bytes)
+    }
+
+    /**
+     *Function Name: from_bytes
+     *@params: bytes - the raw transaction
+     *Output: the transaction, or KomodoError::InvalidTransaction
+     */
+    pub fn from_bytes(bytes: &[u8]) -> Result<Transaction, KomodoError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let transaction = reader.transaction()?;
+        if reader.pos != bytes.len() {
+            return Err(KomodoError::InvalidTransaction(format!(
+                "{} bytes after the transaction",
+                bytes.len() - reader.pos
+            )));
+        }
+        Ok(transaction)
+    }
+
+    /**
+     *Function Name: to_bytes
+     *@params: the instance of the struct Transaction
+     *Output: the raw transaction
+     */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        let header = if self.overwintered {
+            self.version | OVERWINTERED_FLAG
+        } else {
+            self.version
+        };
+        out.extend_from_slice(&header.to_le_bytes());
+        if self.overwintered {
+            out.extend_from_slice(&self.version_group_id.to_le_bytes());
+        }
+
+        write_compact_size(&mut out, self.vin.len());
+        for input in &self.vin {
+            out.extend_from_slice(&input.prev_tx_hash);
+            out.extend_from_slice(&input.prev_vout.to_le_bytes());
+            write_var_bytes(&mut out, &input.script_sig);
+            out.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+        write_compact_size(&mut out, self.vout.len());
+        for output in &self.vout {
+            out.extend_from_slice(&output.value.as_sat().to_le_bytes());
+            write_var_bytes(&mut out, &output.script_pub_key);
+        }
+        out.extend_from_slice(&self.lock_time.to_le_bytes());
+        if self.overwintered {
+            out.extend_from_slice(&self.expiry_height.to_le_bytes());
+        }
+
+        if self.is_sapling() {
+            out.extend_from_slice(&self.value_balance.as_sat().to_le_bytes());
+            write_compact_size(&mut out, self.shielded_spends.len());
+            for spend in &self.shielded_spends {
+                out.extend_from_slice(&spend.cv);
+                out.extend_from_slice(&spend.anchor);
+                out.extend_from_slice(&spend.nullifier);
+                out.extend_from_slice(&spend.rk);
+                out.extend_from_slice(&spend.zkproof);
+                out.extend_from_slice(&spend.spend_auth_sig);
+            }
+            write_compact_size(&mut out, self.shielded_outputs.len());
+            for output in &self.shielded_outputs {
+                out.extend_from_slice(&output.cv);
+                out.extend_from_slice(&output.cmu);
+                out.extend_from_slice(&output.ephemeral_key);
+                out.extend_from_slice(&output.enc_ciphertext);
+                out.extend_from_slice(&output.out_ciphertext);
+                out.extend_from_slice(&output.zkproof);
+            }
+        }
+
+        if self.version >= 2 {
+            write_compact_size(&mut out, self.join_splits.len());
+            for join_split in &self.join_splits {
+                out.extend_from_slice(&(join_split.vpub_old.as_sat() as u64).to_le_bytes());
+                out.extend_from_slice(&(join_split.vpub_new.as_sat() as u64).to_le_bytes());
+                out.extend_from_slice(&join_split.anchor);
+                for hash in join_split.nullifiers.iter().chain(&join_split.commitments) {
+                    out.extend_from_slice(hash);
+                }
+                out.extend_from_slice(&join_split.onetime_pub_key);
+                out.extend_from_slice(&join_split.random_seed);
+                for mac in &join_split.macs {
+                    out.extend_from_slice(mac);
+                }
+                out.extend_from_slice(&join_split.proof);
+                for ciphertext in &join_split.ciphertexts {
+                    out.extend_from_slice(ciphertext);
+                }
+            }
+            if !self.join_splits.is_empty() {
+                out.extend_from_slice(&self.join_split_pub_key.unwrap_or([0; 32]));
+                write_signature(&mut out, &self.join_split_sig);
+            }
+        }
+
+        if self.is_sapling()
+            && !(self.shielded_spends.is_empty() && self.shielded_outputs.is_empty())
+        {
+            write_signature(&mut out, &self.binding_sig);
+        }
+        out
+    }
+
+    /**
+     *Function Name: to_hex
+     *@params: the instance of the struct Transaction
+     *Output: the raw transaction in hex, as sendrawtransaction and signrawtransaction take it
+     */
+    pub fn to_hex(&self) -> String {
+        self.to_bytes().to_hex()
+    }
+
+    /**
+     *Function Name: txid
+     *@params: the instance of the struct Transaction
+     *Output: the txid, the double sha256 of the raw transaction as the daemon prints it
+     */
+    pub fn txid(&self) -> String {
+        let hash = Sha256::digest(&Sha256::digest(&self.to_bytes()));
+        reversed_hex(&hash)
+    }
+
+    /**
+     *Function Name: is_sapling
+     *@params: the instance of the struct Transaction
+     *Output: true for an overwintered transaction of version 4 or up
+     */
+    pub fn is_sapling(&self) -> bool {
+        self.overwintered && self.version >= 4
+    }
+}
+
+/**
+ *Function Name: parse_tx_id
+ *@params: tx_id - a txid as the daemon prints it
+ *Output: the txid in the byte order of the transaction, e.g. for TxIn::prev_tx_hash
+ */
+pub fn parse_tx_id(tx_id: &str) -> Result<[u8; 32], KomodoError> {
+    let invalid = || KomodoError::InvalidTransaction(format!("invalid txid {:?}", tx_id));
+    let bytes = tx_id.from_hex().map_err(|_| invalid())?;
+    if bytes.len() != 32 {
+        return Err(invalid());
+    }
+    let mut hash = [0; 32];
+    for (byte, &reversed) in hash.iter_mut().zip(bytes.iter().rev()) {
+        *byte = reversed;
+    }
+    Ok(hash)
+}
+
+fn reversed_hex(hash: &[u8]) -> String {
+    hash.iter().rev().cloned().collect::<Vec<u8>>().to_hex()
+}
+
+fn write_compact_size(out: &mut Vec<u8>, size: usize) {
+    let size = size as u64;
+    if size < 0xfd {
+        out.push(size as u8);
+    } else if size <= 0xffff {
+        out.push(0xfd);
+        out.extend_from_slice(&(size as u16).to_le_bytes());
+    } else if size <= 0xffff_ffff {
+        out.push(0xfe);
+        out.extend_from_slice(&(size as u32).to_le_bytes());
+    } else {
+        out.push(0xff);
+        out.extend_from_slice(&size.to_le_bytes());
+    }
+}
+
+fn write_var_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_compact_size(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+// A missing signature is written as zeros, as it is before the transaction is signed.
+fn write_signature(out: &mut Vec<u8>, signature: &Option<Vec<u8>>) {
+    match signature {
+        Some(signature) => out.extend_from_slice(signature),
+        None => out.extend_from_slice(&[0; SIGNATURE_SIZE]),
+    }
+}
+
+// Reads the fields of a transaction in order, failing at the first one that runs out of bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], KomodoError> {
+        if self.bytes.len() - self.pos < len {
+            return Err(KomodoError::InvalidTransaction(format!(
+                "ends after {} bytes",
+                self.bytes.len()
+            )));
+        }
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, KomodoError> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Result<u64, KomodoError> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn amount(&mut self) -> Result<Amount, KomodoError> {
+        Ok(Amount::from_sat(self.u64()? as i64))
+    }
+
+    fn hash(&mut self) -> Result<[u8; 32], KomodoError> {
+        let mut hash = [0; 32];
+        hash.copy_from_slice(self.take(32)?);
+        Ok(hash)
+    }
+
+    fn compact_size(&mut self) -> Result<usize, KomodoError> {
+        let (size, min) = match self.take(1)?[0] {
+            0xfd => {
+                let mut bytes = [0; 2];
+                bytes.copy_from_slice(self.take(2)?);
+                (u64::from(u16::from_le_bytes(bytes)), 0xfd)
+            }
+            0xfe => (u64::from(self.u32()?), 0x1_0000),
+            0xff => (self.u64()?, 0x1_0000_0000),
+            size => (u64::from(size), 0),
+        };
+        // a size written in more bytes than needed would not be written back the same way
+        if size < min {
+            return Err(KomodoError::InvalidTransaction(String::from(
+                "non-canonical compact size",
+            )));
+        }
+        // no count or length can be larger than what is left
+        if size > (self.bytes.len() - self.pos) as u64 {
+            return Err(KomodoError::InvalidTransaction(format!(
+                "a size of {} past the end",
+                size
+            )));
+        }
+        Ok(size as usize)
+    }
+
+    fn var_bytes(&mut self) -> Result<Vec<u8>, KomodoError> {
+        let len = self.compact_size()?;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn transaction(&mut self) -> Result<Transaction, KomodoError> {
+        let header = self.u32()?;
+        let overwintered = header & OVERWINTERED_FLAG != 0;
+        let version = header & !OVERWINTERED_FLAG;
+        let version_group_id = if overwintered { self.u32()? } else { 0 };
+        if overwintered
+            && !(version == 3 && version_group_id == OVERWINTER_VERSION_GROUP_ID
+                || version == 4 && version_group_id == SAPLING_VERSION_GROUP_ID)
+        {
+            return Err(KomodoError::InvalidTransaction(format!(
+                "unknown version {} with group id {:08x}",
+                version, version_group_id
+            )));
+        }
+        let mut transaction = Transaction {
+            overwintered,
+            version,
+            version_group_id,
+            ..Transaction::sapling()
+        };
+
+        for _ in 0..self.compact_size()? {
+            transaction.vin.push(TxIn {
+                prev_tx_hash: self.hash()?,
+                prev_vout: self.u32()?,
+                script_sig: self.var_bytes()?,
+                sequence: self.u32()?,
+            });
+        }
+        for _ in 0..self.compact_size()? {
+            transaction.vout.push(TxOut {
+                value: self.amount()?,
+                script_pub_key: self.var_bytes()?,
+            });
+        }
+        transaction.lock_time = self.u32()?;
+        if overwintered {
+            transaction.expiry_height = self.u32()?;
+        }
+
+        if transaction.is_sapling() {
+            transaction.value_balance = self.amount()?;
+            for _ in 0..self.compact_size()? {
+                transaction.shielded_spends.push(SpendDescription {
+                    cv: self.hash()?,
+                    anchor: self.hash()?,
+                    nullifier: self.hash()?,
+                    rk: self.hash()?,
+                    zkproof: self.take(GROTH_PROOF_SIZE)?.to_vec(),
+                    spend_auth_sig: self.take(SIGNATURE_SIZE)?.to_vec(),
+                });
+            }
+            for _ in 0..self.compact_size()? {
+                transaction.shielded_outputs.push(OutputDescription {
+                    cv: self.hash()?,
+                    cmu: self.hash()?,
+                    ephemeral_key: self.hash()?,
+                    enc_ciphertext: self.take(SAPLING_ENC_CIPHERTEXT_SIZE)?.to_vec(),
+                    out_ciphertext: self.take(SAPLING_OUT_CIPHERTEXT_SIZE)?.to_vec(),
+                    zkproof: self.take(GROTH_PROOF_SIZE)?.to_vec(),
+                });
+            }
+        }
+
+        if version >= 2 {
+            let proof_size = if transaction.is_sapling() {
+                GROTH_PROOF_SIZE
+            } else {
+                PHGR_PROOF_SIZE
+            };
+            for _ in 0..self.compact_size()? {
+                transaction.join_splits.push(JoinSplit {
+                    vpub_old: self.amount()?,
+                    vpub_new: self.amount()?,
+                    anchor: self.hash()?,
+                    nullifiers: [self.hash()?, self.hash()?],
+                    commitments: [self.hash()?, self.hash()?],
+                    onetime_pub_key: self.hash()?,
+                    random_seed: self.hash()?,
+                    macs: [self.hash()?, self.hash()?],
+                    proof: self.take(proof_size)?.to_vec(),
+                    ciphertexts: [
+                        self.take(SPROUT_CIPHERTEXT_SIZE)?.to_vec(),
+                        self.take(SPROUT_CIPHERTEXT_SIZE)?.to_vec(),
+                    ],
+                });
+            }
+            if !transaction.join_splits.is_empty() {
+                transaction.join_split_pub_key = Some(self.hash()?);
+                transaction.join_split_sig = Some(self.take(SIGNATURE_SIZE)?.to_vec());
+            }
+        }
+
+        if transaction.is_sapling()
+            && !(transaction.shielded_spends.is_empty() && transaction.shielded_outputs.is_empty())
+        {
+            transaction.binding_sig = Some(self.take(SIGNATURE_SIZE)?.to_vec());
+        }
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the coinbase of the bitcoin genesis block, a version 1 transaction
+    const GENESIS_COINBASE: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+
+    // The vectors below were written field by field from the transaction layout of the Zcash
+    // protocol specification (section 7.1), not with this module; the filler bytes of each field
+    // are distinct, so a field read from the wrong offset is noticed. The txids are the double
+    // sha256 of the bytes, computed separately.
+
+    // version 4 (Sapling): one input, one output, one shielded spend and two shielded outputs
+    const SAPLING_TX: &str = "0400008085202f89013ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a020000006b6a34144f8a447c4c44e720589fb532735911566a36a4261d0230f8bdfe1e91eba5d8a519ebbf6bec629351e1b85e7ec4df2ae819c3101a2cfe82926a38847fe23d7899733cd112c97f5018e52e3759101d25a48d3d2279452c76f090493bf97b4d117fc70805edcfa9568afeffffff0170c9fa02000000001976a914173a5c80d8956eed4f1b2d46e3855fc97b3b64cf88ac00105e5fe0fd1c00800f05fdffffffff018d141318ecfac6c1fcb5ef19f6424c0f73c904094fcc1e46fa48f1e2fc320222922752503013894fc3b998c2fd887e78e70711e3c014bc5c6a4e98e285bfe3dfcd683cacc2e7fada9cb5914abdb076a56029cb292e71f7bb5159cf4b00ea2497fb38a4ac457d0d8e2ae1c5a8daa75aa3ad7c1a69d94b2eedb999d12a5d4c14e0fb515c5249d6328f4eefbf963ad11698dc3eace24bf300e2899bab67af54952042433509fee430bd24f0e9224a3f2ab83429a6be466edcaf20cfb8324f4414bab3e200f8f8113398907b4bc79f8661708ed89c9e81f16158148fb73a8ffd485176a77535b586131bd874e20960b729ce5123dd40cc2c88ede061f4ec1376f6719bb8dea1ef41f8d00f2b453b29202813b75ba87e7ba3c5d5ee3fe2bb0ced13fc0bae96215ae244e3301308145b08fddebd11fbf538082d7e440ed337333b2034fe5b87e7ab45fd35348c06f50899fd06d1c3894fb0a5a028eda16fe882384587a7db8035b56804f2f875422f3e9832ed42a99f8d1480a2d8a0ec80147e3c46b50279195bae466baf97f8d756e27ed4f97278ef1965507019c592ce498b955807adf998d72ebc282480a93b5587038200dae7add966233bdb9c1867e7f78845d915e87dcb5d7e347d7f50a1a01ed87fa7719aaea51727a34090a115defe0baaf6900011bcc13d4abd4824c031515d204b221d2c61020f8ec58d3bd7929bb45b9cd1e21b4796f38f1f22aae3458ae3305d64e8ec5a76b10f2a064370ccb7402488be1e8bb8ac123b148d50eb38f400ae4ea2b0f9c62a923e36d345f0bb9d36b5c36e7cc23ff5d351c40479e4a2ce6fc8c2919de609ca172b4707d444b9eb6fe7f4c8e19c97784efb52a728a724277a9f942ce37b78e0764cc2d4de6c22c0727e8ab40dda2462c1c0ae238071aebd6225513cefc15bb42965bedbf1eaa48413f2e903758728f5b783c01262ad86671967c45d676f14b1d60d10894003454e36073d4d379f0d112ac53fc369c245c40815d0e2767bada9c5fed24741632d7eecb80d436e774e081a6eb770e3712189e6ef3fb5c5664c099fb40f2afe4c8d1f9d0cdef7eb9790e2ccb9a501ca11816fb806d865fd059f32e2216ea0a85d4782535bfa9bb75837b049ba0b42d00a22cbb1742876b6103dbc640d63198059b7af2d78ff36af8a5fbcd2bddb3501b94f72172a571c239982cfeab8e26b074c25051e465e6dbe1e4a23b1a661281b7a656fa467001d248353de87454097c15b1c55da47b9311411102e4bf8d38e5f072006314abd7651f7c04a6179923f8a7ebe3bb0f6c55fe4e2db2674465bf6916e35435c8eceb6c3149e409d08749ec4354b21f96b13b989249cfe92a91b99c1fea2d48826727425e3e10b8cf8d6a984e3c7ec30cb3db8d1e542e6ebf07808c869610bccf26ef6c9f357e27ba2a1015ac10414d4bc4cc6027a9654faae36a46c7600cd132d6effa36d2c1fd474c0d3ffb381b98ab0d9afccc30537347add2f8bac1cba6b9b9567e62fc5668fd19483f046268c717e7f8403b9b8df729ce5fa4a637449715e126efc287987ee8541568633e385b2b56b9f862c30b957e7918b7599ebcaf0371ca6d5b4188fafec9dde8563582b96e1de204a26189b480ad15a96d772fed716d33477d3a92ea4ab761605259226795f78a0862a6c4ef332d900821971ad21996f94ef8993831a659d9d734247a9ece7fe9d2ad0ec92941b577b37eb784d1b2da4b23b9c413793edbfcc5e4c4b0a98c0a9bc87ac4a0fa05a242f8743826da17f2799c3a09203b9e690bde7ab967d3d49ae2c27fae67db6405b0730df2fd87a21a7cf5ff85dcbb23e5e9599bdae5f213fcdcca08e57c8e282c83343e7230edf799dd14cfbf3e0a2b154bb603d9a5a66b4a3be4a6b913678ccbae40511ec62f9504f654f7e25d5666858cfe5f7de18df6c099602bcf029c173fd19d9b93b3f50996af30d9edeb614e6105c744e993a66c7fa89abc10a895889ffe3dbdfb8cc4cf7f9f2fe65e0ca3d70dbc1f3896e76917775709c96f4aad0b3b4e4cdab0330cc670505e22d846fdbaa2ff9be1587992a17cbc0b865c6b1787affb49dc5dc30d9972967a11fac276879ca0c9ecef937e1fe309cd997a570e9cbf35de5f7fc357b6ddd2c76cdf5ebde5cdc195cdb9cee94fcaad6610e17a9c8999843633bd2c9ed04abde9d7fcf533cfa347befe0c557896532ef0f7400eadca8b50f2c601e7943ef6b82c945cbe89427d669f6e73b946c7d5573f96ae8bb62d93a32c4606d8b348b12bbb821d1688b6dda3eda74df91bdda151d76984a3f30e23ca2cb72b542c5e815a7bd65a5016822afa433001fd9264892b44cc60775e7d3e943f1864ebb09d0e4f3c11252d185dd74ca91009b11ef59d4a2df78e6e4e1a2b1b94998adceda8f8d620b983757e95e38621f48b192d2a7fad8e0b310754b4d8f9d58babdb356cdceacb819d33fbcf3cf2e85f34751e8bcbfdca32cd150fce2e8385be2d199bdcd6968b93cf9b620698478429602273749228b284e0a1d038eedc79709065d38cfad15ea6f8473acba34a324f3d3ccafae31f7d58d5ddb410a6f9fdd647fdd17e6e8cd7adf89a1f65ac08403d8260be402d2f3a14707bf7bddd7071c7f2d38a05aff074a0cbef48829b04f80d705b71df60e09ba365d55bba31309cd98ca785a50326c6dbd484ae980d5e8c29ab8949a430ccbeb981574d5ea15d2d0d515a194ea411dbb87a236f2282f958c7878d9846cc79e7d071865695bb88143cffce851a3d4f35c7e4e580b87e047ad90edd508155a865f119856f64bf8a361cea052c81d32131083ead5519b97d68ac4058277a6b78a6b11d9b85a1a75695b84389c5914068b975e33502cee1e4fff9fa4c28e7a8dccaac6b798fbb9e9c347a62c887c87c4ac3fc1f359654676af41b28cdc44d6f5cf43c194d9d0aa58901523f0d001a17a711d49a63ceac6116480858ea979360212ed9131383f5232abcb13fcf08fb317d09238abf209c9221074c1eb93e55cbcc67c7afd3245d44fa0d0b9b7446e9fe72ededaa021d224a01c6b1b771fbac79678dfac704f8d530dd4c569e255f52f4c714460e3c51777ee24f2b7d86c384a3a5dd0165333eaae83a90884a4f66995455a2219a05eea50c1f29f9c0af1b0ad16a04aa99fc004d422e2ac039804d8365d9e583746c7057bc6e8c599f37cd1264f1fb69879e403b2c81600945bd2ffece23996e73db66951e69425d477378151ebfa2b035a8eb522c4ccfb2557a7f63fd2aa33f7880e8f787f08959054a9fde0c5fd14341dedca6aff58d9";
+    const SAPLING_TXID: &str = "aab2148861f05843bd7d3f1e7efdedc66910ac0d98ed3dab735f4958fb2161e4";
+
+    // version 2 (Sprout): one output and one joinsplit with a 296-byte proof
+    const SPROUT_TX: &str = "020000000001f0053101000000001976a914173a5c80d8956eed4f1b2d46e3855fc97b3b64cf88ac00000000010000000000000000002d31010000000072debdae0b5cbceddf958644181b299f1cc4cc39e93043d4eb9c29370049d61d300fa6ff6fa052a9c0d73e73b3c468b993a0543be497d6277e9eb93a28a7ed51650f3e554700dc7ca032b669139ef9f77a1df2acc7ceb0375854029180ae9767c5e863affef4a5c0256827842b4b5342168fec45cffbf5d9b69c4875a63c5f45606d14218067db1276580f968431627ff16258061f4b50abd88f7d72c302adee572c4746fa458e480fc61e50584378781a30d6b174734003b0625e8227db82ffc3b2f2ef4dcd54980d04bf1682dfe2d8105d7161c3894a5a40ea444122e8a96e32b25f439ebcf0ddd2cf3d63b7da3f8ac460bf40c08e1e31e8a303a7a67066dd2d6fb59780101eb974ca8ed5b055e0208339c32f3d187f3bbd293e371b7e65ec7fad105b24efbf132747a14043c4b2dbf13ceca92b9e5896b6c7ed4b3ea6fe658820ed640e18aa1029927a3e469a2d490ac95e9d44d2127bf1319e1f05e2862311ab1e6b1fde56d0fb4fdfdc3760dc898f05af549ddebd0aff7a37b669220b88a1620846b8fc20ef9674e419a020419a5962e9bf820cdb0e0de9036d9b5541633fb64bc1d8b6dd46f9e13f71fac859b7d42fa46e63aef67560409b6f0769345cde085e6b3c57892f818a1664d9b5ca7b7c02ba15db16d7c60e46c01acd9edaa79e3fa2fcc111663c4f4173e414722bcdd164c1bf8042e40ad1ef3f6686560fa613758c1d39b888c8f241e8797d1e9aaad63c65f8ddcf3f0526af059cd88b01755fac84bfe1fb29d11e74154b48fc0c3e0eddd7698955b3470502bf31b1eb7e82bd0261c0cb7d965f40aaf0635adc60014a5741e8f29c8a08cc208793f9776f99ed217863bcbeeab3dbd10d885d9470f6e8d982590918bf172ce91cadb9cf9990f14588b6fb50fa72dca9a026244cfd487daa0a50295571262477d90c40875a37022c607c01b8a1397920e33a28e0a39200882d710c32f39d2d16a43d015fcf8f2cfcc61b0b77e71cc44ff2572f425edf7d4f34ba312d83b073a9c11e3ff1b9cc7936a1377aa88696ed705ad054d518a390a7c6dd94d756f1092fe328845d60ee17c8bc5640fb96117ae660d260e012a88ec88da7b644a9fbd457c6635d2b400db6b3b9e12a70af64c9e582c24722593ed8501ad2feefa8b538fc6cd7e5f3e74dbc926ddca28da456a5ec19ba2fa57628dc210b4dc66eafdb9a782df1a13544f5fdc577f6920665f7e880a18edeb321a4e3a608c2cebd1868a95b03bd567d3d6823458d0b938e07750eca1d1f0b8710caf7464f0bac7fb961ce08a4b1fb99e62af3838fba1f7b641828d254c89bbbcbd3e48220a0e7c6b3d78f327e7ec380d9da39ac308d5b09514a746afc563fa6d068fcb5fe5b02d162114e4133e16de61e95c318647b7bb96e86ae5bada6cade8e4ab3c94fcaca4a951f6e3591378be6abfcdc026bebe25e8392bb9b64a20ac40c20cedb89ea7525655218f13ed6e7543e4eda62e6db5e7dcff6272a7c0ee11f513f032f1f99ddcf0ddae34ced1b30c518e75c5f02ecd8639d292fb34dca96b0ef5da8e4377942f080fadee750f7d2d3c5bdcf498545b41e0988a3d7ef6fc12f76b99cc0a457c4ba3ba9c1013827beb22766a23f804bb56303a6ee607ebddde59f9186173271983135c14fee4b757c01d4222c058a66fb3ba931f3648da7f1b82060d8df5a027f8e1ba0198829d79e4c95e5f7f95aa8491fbe226d9df1d1148ca5df59383fc87a5038f2b82549eec90ba51f272bf89dee36e23eb9835565e44653e2f8453e77d1e901eb09b464e3bdf8449b14229e57f6bca5a0e2e41de057cda4555364d91345b0a3e17c28abdfa02b97712c2aa5a028b8d96d1c890455a83cdbf5929e0840d21365678b4cefa01b1aa5afe16f1bf21fb220436a7b4041ccb853ab75cdfd3b297ef91cde31ed73c19721c4905ce2693ff5a8c39685548eb89d53f103d0a50cbbf85af367f867e7bb9212bc73cd99eb7b60e1e6f7a691082755ed45839373a31666f9aaf988507316f78cec5747ac1da48b6810b210ce5fd88f40ff745249efbd53eac2f502ad6096ebf12fafc582679c9bcf599727d9bb7f0bbddba45b6fe6035971006d382e0226f6bf5254ae79612dc690f77c5cd7caa2c7c5008e886ebf50a983541362f2f13ce8cacd6e4bb537a4b597539733e377d4c7103830fc26479d0fa76044249363d54710a041b1c03ae89dc821bdd221eb12196ef6f34a64e331c77cc083c1a169eb7387d567be065c3174455595ee5c149391f537ce5b21973ed95e0a715211129a1f85e81255522cc087477a53a3ae243cd6995b27fbe871bba302175ca546b4e35b35ef074092830c4bc478fc7be58e7ddff09481e1dd494639af80b564b4c617a5682a8a4c928de3d77bca397f2d8b9311cdd77caf4f4ab977021b79b8d0548175545e62a26772a9bec72add1ae92316e10481ea68133131c4b4a33b042833be2a6953a0b53229fbb62e7ab1b73219048a25512cf3c4e74a2491c1be37c9de4d129fc0e6f03e7a2bb32c1478ed57e576707aa96c809809549c92a0acee354cddead51db401457d75b5abba21d818580067f782f42df73e89009073d0fc88fbc739ab322a144917ffb862a6eb3ee1a856397abba1171bdaabdbc463d4dc";
+    const SPROUT_TXID: &str = "e8048d9ae6a2aa14f1b9e87366c7ac9620703d6e5985c610ad68505ced01f045";
+
+    // version 3 (Overwinter): one input, one output and one joinsplit, still with a 296-byte proof
+    const OVERWINTER_TX: &str = "030000807082c403013ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a000000006b6a34144f8a447c4c44e720589fb532735911566a36a4261d0230f8bdfe1e91eba5d8a519ebbf6bec629351e1b85e7ec4df2ae819c3101a2cfe82926a38847fe23d7899733cd112c97f5018e52e3759101d25a48d3d2279452c76f090493bf97b4d117fc70805edcfa9568affffffff01f0053101000000001976a914173a5c80d8956eed4f1b2d46e3855fc97b3b64cf88ac0000000040420f00010000000000000000002d3101000000000e8d2965ad97b9059d1ca0578c8ff74fc285c32cf0cc6aeecdc47c7be525560f22a5208b508f7d06e0c9862f6c0b9a6e5a7d5ac4bb8ced50cd12db1d6edd071169555e94eb0ba93e4d7bc221f9e36d843c9f876f3b6737c15f3c55dc68fd0cc86e55200a35b5aa3d86fceba059d4e0485f663b3e0e054ec073a2e04f6572e701c2ca9792950650d99425b8956a2d92ed28ee0be73533ecad71130e4c89b3b40428426636bda21b2fea2f08eb3acdf92f1402fa6ce14c50300aa3162b1d4ee88be57540696d73b1f59071b2caa48b04eb88960c580f4c854175cf01f0447cfeccd12b74dcb8cf9b3aecda2353450e8e16a1cefffb088b4b75f110050f21088572f01ab20dc24092312d3cece270200466c25be950d02410be0a7cd70c9811cae5cc9b1ec2b9f6e4e00cea78966c7038060c5d932f2b63d113dfa17441c01412ead6aca7678550193437e1c33bb965094f6015305a27f912a53eb2a4ac0b623f778b47ab15a64e98396736db00ad85eeff3403e3507b3ef7dc0173398fbefd8515034e785a2efbffc1a2a8d4b0af40db6669f6d2739eb54b099e429274992376d65111d4fd59f0f1252fb75f798e2a2c0e287b55404414ceba3bd78a427d567cadcc125bff1c991761f8465a4a9ad5c5fe3c34ee944473feef0d3bcf9eb4153e11c448c434319201365b7cc56d1fb194e8ff3d3dba1524811960e3f9e97c64d74b901e41dc262fcdef4dc3e5b5c9966abe55fc0f178bd8a77e1f5c6e7b806d8510b8096d1e071394c201c7334456a4e038ada0addc7bb3901c0c96383639814d705b3bef98c32f877b9fd9065a25764ca328fc0b6c56075219d736002f5333a7c17390fa03f46931689a209ae7c95feadd645ffde99503b021583c99bdc39a5fb771be4d01a7bc25686f2e962cff274d48a94005e932ddf544c3694f6cb9bfa9b492a3e42c94cad874bdd5632e999bb611f454009964c23ce66429b79012000864220cd30eb1d58880c0cf7d74bc61b39729e35e153e8d612344efa89403cdd89f9b890d3f14a0adb025c77d5a67e8ee282b6779f9e25878dd55f67df7689cda512e63fe7e7fe46c6a3256d5ea37801ad587bc363ccc92cd9f081e1301344ffbafeeaf90f8dff051792d44c9c4d75c40e41bdf099b75efb5680260b493b66ee461d1296eee849d8cd99089d14c0f73a307e7ccfd29b3fd9796b518182c05ac6bbba628c07eee2a184d9a0fefc8dd61a30bc1cce8d5da9e336eef78d49e96892db8da48f0145e21ca0c3ae52dd439be53a3d384187c0543d5868dd558057fa284962d449f8e53c5b1a8340a948ee7afeb02cdfabef706614c077e5f8150a9cb98069fdce3ea323e4202e829020f2ec0e48f0b51b0df3c1c8c64b1796cefb7a1c3e214fce3e8e440b171feb58a7caeeba9d63d9e3da3efd62df31d6005afda7a0697663f260e77e4d0120208310ab5df547c62923fe60996d71ac63af803dd6ae6eba4558dd616a13f8c8a9c55f28336d1b614253324c8e6238b64cb340f2840f0042a49d9b1b4188ddb13c3b3d2751cca0690fde7e053fe84663ca7147255067ef029adf6199972fd52265ab72857f6f0ff1fba0e63707d622fedc1b16148aea76653bba68065ad00fd1db101a5953c5a8c2c9f3d00a6eeb330d3f705e762a34a6a0980a070f9e438d9820a00c28b135357f661413941b28eeea52d567f05587db75a7bb5976c9e2adb1c963f6ffd29fcb5ce79d783affee73218b2b53b8ebd3d9a4c7743caa2daf792faabac6b88248c2e9a5ea8550d3479fb49783755168edb199345e64baf3505b4238e724b50170700499ab1789403c580368579d6a40838e8c54eb9be269da377f2f4a45e1a1ecbd9630da748e8f3957e232d4a820a34385e855cf5d46628b211b25dec8f191b072b8675a469c536250445ae523339948fe87dcd2f372d4db1ed2d1c6b39f622fa85391fddd3de2164ca93e3a5ca682382923f0823c4c483c08f96f8d1c44a06dd7deeea72cfea001394ca240319c27103717ac5b574fdea76db526f3d95a867f3eba90b8d532349a03b58fc44046d3eca97d273446b7b6a0e15809e80b71eecf0d836b91bae65abe118de64b66a3f78705bc5336e888ea1f096962767335ce0904179902706e64f0ae81b5e6374d900ff55809c5449ac0aebd4cde171876730d6c45ab98dc9fd21af65c9c9d5c6ac25f1e08fd7de38b4048745ca5a7ee63c57a737cacbd8acf15f5a6ccdc07f1aa3cd9e0dad7a24ede86f29d70fd64e7e5e9dc6b6bb6be0ac0824f0cfe8ac9192cdca1f6b5a4f75ffa9649b98d8c193f0bca377d749cb5abce3eda6961689691e07ab90e87552fb4aef9d6ddc0661599b7528340620e6abb5c1c9d8ea9149df4514125c0ee6b2518a6a7804152f16927ab201425a2570bac5018a3d2a6fe248c522d1bf113f4d36cf2eb77fde5b7da250b06318b487d1395a0190b0875f6375fbab213a3f965f1890a40383e37508afb9fba3ec1b467ea0510b26866c0e87dc83096e8999eec64204fe2c7a69883ed6b4e98ff447e3ef4ea16ffe30d9503ca83511d1e2fd2c2e9e03285d914f1f1b7a531050d1ca541ab607899aa996a135efe6a6a4f940529216a4d230430044079b47322a3316a1e516b1c1c5ae27329a";
+    const OVERWINTER_TXID: &str =
+        "2b07e3e1093b5eb1c4e2118ff7f2d9a4bb5bc50a2c3f8e0679511c2b688262ef";
+
+    #[test]
+    fn decodes_and_encodes_a_version_1_transaction() {
+        let transaction = Transaction::from_hex(GENESIS_COINBASE).unwrap();
+        assert!(!transaction.overwintered);
+        assert_eq!(transaction.version, 1);
+        assert_eq!(transaction.vin[0].prev_vout, 0xffff_ffff);
+        assert_eq!(transaction.vout[0].value, Amount::from_sat(5_000_000_000));
+        assert_eq!(transaction.to_hex(), GENESIS_COINBASE);
+        assert_eq!(
+            transaction.txid(),
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        );
+
+        let mut trailing = GENESIS_COINBASE.to_string();
+        trailing.push_str("00");
+        assert!(Transaction::from_hex(&trailing).is_err());
+        assert!(Transaction::from_hex(&GENESIS_COINBASE[..100]).is_err());
+    }
+
+    #[test]
+    fn round_trips_a_sapling_transaction() {
+        let mut transaction = Transaction::sapling();
+        transaction.vin.push(TxIn {
+            prev_tx_hash: parse_tx_id(
+                "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+            )
+            .unwrap(),
+            prev_vout: 1,
+            script_sig: Vec::new(),
+            sequence: 0xffff_fffe,
+        });
+        transaction.vout.push(TxOut {
+            value: Amount::from_sat(-1),
+            script_pub_key: vec![0x6a; 300],
+        });
+        transaction.lock_time = 1_600_000_000;
+        transaction.expiry_height = 1_900_000;
+        transaction.value_balance = Amount::from_sat(10_000);
+        transaction.shielded_spends.push(SpendDescription {
+            cv: [1; 32],
+            anchor: [2; 32],
+            nullifier: [3; 32],
+            rk: [4; 32],
+            zkproof: vec![5; GROTH_PROOF_SIZE],
+            spend_auth_sig: vec![6; SIGNATURE_SIZE],
+        });
+        transaction.binding_sig = Some(vec![7; SIGNATURE_SIZE]);
+
+        let hex = transaction.to_hex();
+        assert!(hex.starts_with("0400008085202f8901"));
+        let decoded = Transaction::from_hex(&hex).unwrap();
+        assert_eq!(decoded, transaction);
+        assert_eq!(
+            decoded.vin[0].prev_tx_id(),
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        );
+        assert_eq!(decoded.to_hex(), hex);
+    }
+
+    #[test]
+    fn decodes_a_sapling_transaction_with_shielded_outputs() {
+        let transaction = Transaction::from_hex(SAPLING_TX).unwrap();
+        assert!(transaction.is_sapling());
+        assert_eq!(transaction.version_group_id, SAPLING_VERSION_GROUP_ID);
+        assert_eq!(
+            transaction.vin[0].prev_tx_id(),
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        );
+        assert_eq!(transaction.vin[0].prev_vout, 2);
+        assert_eq!(transaction.vin[0].script_sig.len(), 107);
+        assert_eq!(transaction.vout[0].value, Amount::from_sat(49_990_000));
+        assert_eq!(transaction.lock_time, 1_600_000_000);
+        assert_eq!(transaction.expiry_height, 1_900_000);
+        assert_eq!(transaction.value_balance, Amount::from_sat(-50_000_000));
+        assert_eq!(transaction.shielded_spends.len(), 1);
+        assert_eq!(
+            transaction.shielded_spends[0].nullifier.to_hex(),
+            "cd683cacc2e7fada9cb5914abdb076a56029cb292e71f7bb5159cf4b00ea2497"
+        );
+        assert_eq!(transaction.shielded_outputs.len(), 2);
+        assert_eq!(
+            transaction.shielded_outputs[1].cmu.to_hex(),
+            "666858cfe5f7de18df6c099602bcf029c173fd19d9b93b3f50996af30d9edeb6"
+        );
+        assert!(transaction.join_splits.is_empty());
+        assert_eq!(
+            transaction.binding_sig.as_ref().unwrap()[..8].to_hex(),
+            "945bd2ffece23996"
+        );
+        assert_eq!(transaction.to_hex(), SAPLING_TX);
+        assert_eq!(transaction.txid(), SAPLING_TXID);
+    }
+
+    #[test]
+    fn decodes_joinsplits_with_296_byte_proofs() {
+        let sprout = Transaction::from_hex(SPROUT_TX).unwrap();
+        assert!(!sprout.overwintered);
+        assert_eq!(sprout.version, 2);
+        assert!(sprout.vin.is_empty());
+        let join_split = &sprout.join_splits[0];
+        assert_eq!(join_split.vpub_old, Amount::ZERO);
+        assert_eq!(join_split.vpub_new, Amount::from_sat(20_000_000));
+        assert_eq!(join_split.proof.len(), 296);
+        assert_eq!(join_split.proof[288..].to_hex(), "bd0261c0cb7d965f");
+        assert_eq!(join_split.ciphertexts[1][..8].to_hex(), "058a66fb3ba931f3");
+        assert_eq!(sprout.to_hex(), SPROUT_TX);
+        assert_eq!(sprout.txid(), SPROUT_TXID);
+
+        let overwinter = Transaction::from_hex(OVERWINTER_TX).unwrap();
+        assert!(overwinter.overwintered);
+        assert!(!overwinter.is_sapling());
+        assert_eq!(overwinter.version, 3);
+        assert_eq!(overwinter.version_group_id, OVERWINTER_VERSION_GROUP_ID);
+        assert_eq!(overwinter.expiry_height, 1_000_000);
+        assert_eq!(overwinter.join_splits[0].proof.len(), 296);
+        assert_eq!(
+            overwinter.join_splits[0].commitments[1].to_hex(),
+            "c2ca9792950650d99425b8956a2d92ed28ee0be73533ecad71130e4c89b3b404"
+        );
+        assert_eq!(
+            overwinter.join_split_pub_key.unwrap().to_hex(),
+            "dc83096e8999eec64204fe2c7a69883ed6b4e98ff447e3ef4ea16ffe30d9503c"
+        );
+        assert_eq!(overwinter.to_hex(), OVERWINTER_TX);
+        assert_eq!(overwinter.txid(), OVERWINTER_TXID);
+    }
+}
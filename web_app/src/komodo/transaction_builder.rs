@@ -0,0 +1,454 @@
+//!
+//! Unsigned transactions built without the daemon, from the outputs the wallet can spend.
+//!
+//! # Remarks
+//!
+//! * The builder takes UTXOs from `wallet::list_unspent` or `address::get_address_utxos`,
+//! picks the largest ones until the payments and the fee are covered, and sends what is left
+//! to a change address. The fee is the fee rate times the size the transaction will have once
+//! signed, assuming pay-to-public-key-hash inputs.
+//!
+//! * The transaction is a Sapling (version 4) transaction with empty input scripts;
+//! `raw_transactions().sign_raw_transaction` signs the inputs the wallet holds the keys of.
+//!
+//! * On KMD an output earns interest, which is only paid when the spending transaction's
+//! lock time is less than an hour old. `kmd_interest` sets the lock time the way the Komodo
+//! wallets do and counts the interest of each UTXO as spendable.
+//!
+//! # Examples
+//! ```
+//! let unspent = client.wallet().list_unspent(None, None, String::new())?;
+//! let built = TransactionBuilder::new()
+//!     .add_utxos(unspent.iter().filter(|utxo| utxo.spendable).map(Utxo::from))
+//!     .pay_to("RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse()?, "1.5".parse()?)
+//!     .change_address(change)
+//!     .build()?;
+//! let signed = client.raw_transactions().sign_raw_transaction(built.transaction.to_hex())?;
+//! ```
+//!
+
+use super::address::AddressUtxo;
+use super::komodorpcutil::KomodoError;
+use super::transaction::{parse_tx_id, Transaction, TxIn, TxOut};
+use super::wallet::Unspent;
+use super::{Address, Amount};
+use rustc_serialize::hex::FromHex;
+
+/// The fee rate of the builder unless one is set: 0.0001 COIN per 1000 bytes.
+pub const DEFAULT_FEE_RATE: Amount = Amount::from_sat(10_000);
+
+/// How many seconds before now the lock time of a KMD transaction is set, as the Komodo wallets do.
+pub const KMD_LOCK_TIME_OFFSET: u32 = 777;
+
+// Change below this is left to the miner rather than paid to an output nobody could spend profitably.
+const DUST_LIMIT: Amount = Amount::from_sat(546);
+
+// The size of a signed pay-to-public-key-hash input script: a signature of up to 72 bytes,
+// a compressed public key and their two push opcodes.
+const P2PKH_SCRIPT_SIG_SIZE: usize = 107;
+
+// The sequence that makes the lock time count; the highest sequence would disable it.
+const LOCK_TIME_SEQUENCE: u32 = 0xffff_fffe;
+
+/// An output the builder may spend.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Utxo {
+    /// the txid of the transaction holding the output
+    pub txid: String,
+    /// the number of the output
+    pub vout: u32,
+    /// the script locking the output, in hex
+    pub script_pub_key: String,
+    /// the value of the output
+    pub amount: Amount,
+    /// the interest the output has earned, on KMD
+    pub interest: Amount,
+}
+
+impl<'a> From<&'a Unspent> for Utxo {
+    fn from(unspent: &'a Unspent) -> Utxo {
+        Utxo {
+            txid: unspent.txid.clone(),
+            vout: unspent.vout,
+            script_pub_key: unspent.script_pub_key.clone(),
+            amount: unspent.amount,
+            interest: unspent.interest.unwrap_or(Amount::ZERO),
+        }
+    }
+}
+
+impl<'a> From<&'a AddressUtxo> for Utxo {
+    fn from(utxo: &'a AddressUtxo) -> Utxo {
+        Utxo {
+            txid: utxo.txid.clone(),
+            vout: utxo.output_index,
+            script_pub_key: utxo.script.clone(),
+            amount: Amount::from_sat(utxo.satoshis),
+            interest: Amount::ZERO,
+        }
+    }
+}
+
+/// The result of TransactionBuilder::build.
+#[derive(Debug, Clone)]
+pub struct BuiltTransaction {
+    /// the unsigned transaction
+    pub transaction: Transaction,
+    /// the UTXOs the transaction spends, in the order of its inputs
+    pub spent: Vec<Utxo>,
+    /// the fee paid to the miner
+    pub fee: Amount,
+    /// the change paid back; ZERO when there is no change output
+    pub change: Amount,
+}
+
+/// Builds an unsigned transaction paying the given addresses from the given UTXOs.
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder {
+    utxos: Vec<Utxo>,
+    payments: Vec<(Address, Amount)>,
+    change_address: Option<Address>,
+    fee_rate: Amount,
+    lock_time: u32,
+    expiry_height: u32,
+    claim_interest: bool,
+}
+
+impl Default for TransactionBuilder {
+    fn default() -> TransactionBuilder {
+        TransactionBuilder::new()
+    }
+}
+
+impl TransactionBuilder {
+    /**
+     *Function Name: new
+     *@params: none
+     *Output: a builder without UTXOs or payments, using DEFAULT_FEE_RATE
+     */
+    pub fn new() -> TransactionBuilder {
+        TransactionBuilder {
+            utxos: Vec::new(),
+            payments: Vec::new(),
+            change_address: None,
+            fee_rate: DEFAULT_FEE_RATE,
+            lock_time: 0,
+            expiry_height: 0,
+            claim_interest: false,
+        }
+    }
+
+    /// The add_utxos method adds outputs the transaction may spend.
+    pub fn add_utxos<I: IntoIterator<Item = Utxo>>(mut self, utxos: I) -> Self {
+        self.utxos.extend(utxos);
+        self
+    }
+
+    /// The pay_to method adds a payment to a transparent address.
+    pub fn pay_to(mut self, address: Address, amount: Amount) -> Self {
+        self.payments.push((address, amount));
+        self
+    }
+
+    /// The change_address method sets where the change goes; by default, back to the first spent UTXO.
+    pub fn change_address(mut self, address: Address) -> Self {
+        self.change_address = Some(address);
+        self
+    }
+
+    /// The fee_rate method sets the fee paid per 1000 bytes of the signed transaction.
+    pub fn fee_rate(mut self, fee_rate: Amount) -> Self {
+        self.fee_rate = fee_rate;
+        self
+    }
+
+    /// The lock_time method sets the lock time of the transaction, a height or a time.
+    pub fn lock_time(mut self, lock_time: u32) -> Self {
+        self.lock_time = lock_time;
+        self
+    }
+
+    /// The expiry_height method sets the last height the transaction can be mined at; 0 never expires.
+    pub fn expiry_height(mut self, expiry_height: u32) -> Self {
+        self.expiry_height = expiry_height;
+        self
+    }
+
+    /// The kmd_interest method claims the interest of the UTXOs, with now the current unix time.
+    pub fn kmd_interest(mut self, now: u32) -> Self {
+        self.lock_time = now.saturating_sub(KMD_LOCK_TIME_OFFSET);
+        self.claim_interest = true;
+        self
+    }
+
+    /**
+     *Function Name: build
+     *@params: the instance of the struct TransactionBuilder
+     *Output: the unsigned transaction with the spent UTXOs, its fee and its change, or
+     *           KomodoError::InsufficientFunds when the UTXOs do not cover the payments and the fee
+     */
+    pub fn build(&self) -> Result<BuiltTransaction, KomodoError> {
+        let mut outputs = Vec::new();
+        let mut paid = Amount::ZERO;
+        for (address, amount) in &self.payments {
+            let script_pub_key =
+                address
+                    .script_pub_key()
+                    .ok_or_else(|| KomodoError::InvalidAddress {
+                        address: address.to_string(),
+                        reason: "a shielded address cannot be paid from transparent inputs",
+                    })?;
+            if *amount <= Amount::ZERO {
+                return Err(KomodoError::InvalidAmount(amount.to_string()));
+            }
+            paid = paid
+                .checked_add(*amount)
+                .ok_or_else(|| KomodoError::InvalidAmount(amount.to_string()))?;
+            outputs.push(TxOut {
+                value: *amount,
+                script_pub_key,
+            });
+        }
+
+        // largest first, so that few inputs are needed; ties in a fixed order
+        let mut candidates: Vec<&Utxo> = self.utxos.iter().collect();
+        candidates.sort_by(|a, b| {
+            self.value(b)
+                .cmp(&self.value(a))
+                .then_with(|| (&a.txid, a.vout).cmp(&(&b.txid, b.vout)))
+        });
+
+        let mut spent: Vec<Utxo> = Vec::new();
+        let mut available = Amount::ZERO;
+        for utxo in candidates {
+            spent.push(utxo.clone());
+            available += self.value(utxo);
+
+            let mut transaction = self.transaction(&spent, outputs.clone())?;
+            let fee = self.fee_for(&transaction);
+            let change = match available.checked_sub(paid + fee) {
+                Some(change) if !change.is_negative() => change,
+                _ => continue,
+            };
+
+            // a change output makes the transaction bigger, so its fee is worked out again
+            let change_script = self.change_script(&spent)?;
+            transaction.vout.push(TxOut {
+                value: Amount::ZERO,
+                script_pub_key: change_script,
+            });
+            let fee_with_change = self.fee_for(&transaction);
+            let change_left = available - paid - fee_with_change;
+            if change_left >= DUST_LIMIT {
+                transaction.vout.last_mut().unwrap().value = change_left;
+                return Ok(BuiltTransaction {
+                    transaction,
+                    spent,
+                    fee: fee_with_change,
+                    change: change_left,
+                });
+            }
+            transaction.vout.pop();
+            return Ok(BuiltTransaction {
+                transaction,
+                spent,
+                fee: fee + change,
+                change: Amount::ZERO,
+            });
+        }
+
+        let fee = self.fee_for(&self.transaction(&spent, outputs)?);
+        Err(KomodoError::InsufficientFunds {
+            needed: paid + fee,
+            available,
+        })
+    }
+
+    // What a UTXO is worth to this transaction.
+    fn value(&self, utxo: &Utxo) -> Amount {
+        if self.claim_interest {
+            utxo.amount + utxo.interest
+        } else {
+            utxo.amount
+        }
+    }
+
+    // The fee of the transaction once its inputs are signed, rounded up to the satoshi.
+    fn fee_for(&self, transaction: &Transaction) -> Amount {
+        let size = transaction.to_bytes().len() + P2PKH_SCRIPT_SIG_SIZE * transaction.vin.len();
+        let sats = (self.fee_rate.as_sat() * size as i64 + 999) / 1000;
+        Amount::from_sat(sats)
+    }
+
+    fn change_script(&self, spent: &[Utxo]) -> Result<Vec<u8>, KomodoError> {
+        match &self.change_address {
+            Some(address) => address
+                .script_pub_key()
+                .ok_or_else(|| KomodoError::InvalidAddress {
+                    address: address.to_string(),
+                    reason: "change cannot go to a shielded address",
+                }),
+            None => spent[0].script_pub_key.from_hex().map_err(|_| {
+                KomodoError::InvalidTransaction(format!(
+                    "invalid scriptPubKey {:?}",
+                    spent[0].script_pub_key
+                ))
+            }),
+        }
+    }
+
+    fn transaction(&self, spent: &[Utxo], outputs: Vec<TxOut>) -> Result<Transaction, KomodoError> {
+        let sequence = if self.lock_time == 0 {
+            0xffff_ffff
+        } else {
+            LOCK_TIME_SEQUENCE
+        };
+        let mut transaction = Transaction::sapling();
+        for utxo in spent {
+            transaction.vin.push(TxIn {
+                prev_tx_hash: parse_tx_id(&utxo.txid)?,
+                prev_vout: utxo.vout,
+                script_sig: Vec::new(),
+                sequence,
+            });
+        }
+        transaction.vout = outputs;
+        transaction.lock_time = self.lock_time;
+        transaction.expiry_height = self.expiry_height;
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::MockKomodod;
+    use serde_json::json;
+
+    const TXID: &str = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+    const SCRIPT: &str = "76a914173a5c80d8956eed4f1b2d46e3855fc97b3b64cf88ac";
+
+    fn utxo(vout: u32, sats: i64, interest: i64) -> Utxo {
+        Utxo {
+            txid: TXID.to_string(),
+            vout,
+            script_pub_key: SCRIPT.to_string(),
+            amount: Amount::from_sat(sats),
+            interest: Amount::from_sat(interest),
+        }
+    }
+
+    #[test]
+    fn selects_the_largest_utxos_and_pays_change() {
+        let recipient: Address = "RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp".parse().unwrap();
+        let built = TransactionBuilder::new()
+            .add_utxos(vec![
+                utxo(0, 100_000_000, 0),
+                utxo(1, 300_000_000, 0),
+                utxo(2, 50_000_000, 0),
+            ])
+            .pay_to(recipient.clone(), Amount::from_sat(350_000_000))
+            .build()
+            .unwrap();
+
+        let vouts: Vec<u32> = built.spent.iter().map(|utxo| utxo.vout).collect();
+        assert_eq!(vouts, vec![1, 0]);
+        let transaction = &built.transaction;
+        assert_eq!(
+            transaction.vout[0].script_pub_key,
+            recipient.script_pub_key().unwrap()
+        );
+        assert_eq!(
+            transaction.vout[1].script_pub_key,
+            SCRIPT.from_hex().unwrap()
+        );
+        // 2 inputs and 2 outputs are 393 bytes once signed
+        assert_eq!(built.fee, Amount::from_sat(3_930));
+        assert_eq!(built.change, Amount::from_sat(50_000_000 - 3_930));
+        assert_eq!(
+            transaction.vout[0].value + transaction.vout[1].value + built.fee,
+            Amount::from_sat(400_000_000)
+        );
+        assert_eq!(transaction.vin[0].sequence, 0xffff_ffff);
+        assert_eq!(
+            Transaction::from_hex(&transaction.to_hex()).unwrap(),
+            *transaction
+        );
+
+        let too_much = TransactionBuilder::new()
+            .add_utxos(vec![utxo(0, 100_000_000, 0)])
+            .pay_to(recipient, Amount::from_sat(100_000_000))
+            .build();
+        match too_much {
+            Err(KomodoError::InsufficientFunds { available, .. }) => {
+                assert_eq!(available, Amount::from_sat(100_000_000))
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn kmd_interest_sets_the_lock_time_and_spends_the_interest() {
+        let recipient: Address = "RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp".parse().unwrap();
+        let built = TransactionBuilder::new()
+            .add_utxos(vec![utxo(0, 1_000_000_000, 5_000_000)])
+            .pay_to(recipient, Amount::from_sat(1_000_000_000))
+            .kmd_interest(1_600_000_000)
+            .build()
+            .unwrap();
+        let transaction = &built.transaction;
+        assert_eq!(transaction.lock_time, 1_600_000_000 - 777);
+        assert_eq!(transaction.vin[0].sequence, 0xffff_fffe);
+        assert_eq!(built.change, Amount::from_sat(5_000_000) - built.fee);
+        assert_eq!(transaction.vout.len(), 2);
+    }
+
+    #[test]
+    fn spends_the_utxos_of_the_address_index() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "getaddressutxos",
+            json!([
+                {
+                    "address": "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4",
+                    "txid": TXID,
+                    "outputIndex": 3,
+                    "script": SCRIPT,
+                    "satoshis": 200_000_000,
+                    "height": 1_800_000
+                },
+                {
+                    "address": "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4",
+                    "txid": TXID,
+                    "outputIndex": 5,
+                    "script": SCRIPT,
+                    "satoshis": 20_000_000,
+                    "height": 1_800_001
+                }
+            ]),
+        );
+        let utxos = daemon
+            .client()
+            .address_index()
+            .get_address_utxos(vec![String::from("RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4")])
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("getaddressutxos"),
+            json!([{ "addresses": ["RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4"], "chainInfo": false }])
+        );
+
+        let recipient: Address = "RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp".parse().unwrap();
+        let built = TransactionBuilder::new()
+            .add_utxos(utxos.iter().map(Utxo::from))
+            .pay_to(recipient, Amount::from_sat(150_000_000))
+            .build()
+            .unwrap();
+        assert_eq!(built.spent, vec![utxo(3, 200_000_000, 0)]);
+        assert_eq!(built.transaction.vin[0].prev_vout, 3);
+        assert_eq!(
+            built.transaction.vout[0].value + built.change + built.fee,
+            Amount::from_sat(200_000_000)
+        );
+    }
+}
@@ -9,15 +9,15 @@
 //!
 //! * ? Some documentation of methods may reference a different method.
 //!
-//! * A valid KomodoRPC object type must be passed in.
+//! * Methods are called on the handle returned by `KomodoClient::wallet()`.
 //!
-//! * All examples for each method assumes a valid KomodoRPC object, named `some_user`, is used.
+//! * All examples for each method assumes a valid KomodoClient, named `client`, is used.
 //!
 //! * Use of any methods requires the following modules:
 //! ```
 //! mod komodorpcutil;
 //! mod komodo;
-//! use komodorpcutil::KomodoRPC;
+//! use komodo::KomodoApi;
 //! ```
 //!
 //! [Komodo website]: https://docs.komodoplatform.com/basic-docs/smart-chains/smart-chain-api/wallet.html
@@ -28,1694 +28,2161 @@
 //          - more advanced examples
 //       - ? potential fix for no function/method overloading if extra time
 //              -> trait object static/dynamic dispatch for multi-type parameter
-//       - ? some_user parameter may be simplified further
 
 use super::komodorpcutil;
-//use komodorpcutil::KomodoRPC;
-
+use super::rawtransactions::OutPoint;
+use super::{Address, Amount};
+use komodorpcutil::{KomodoClient, KomodoError, Response, Transport};
+use rustc_serialize::hex::ToHex;
+use serde::Serializer;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+// The fee the daemon suggests for z_sendmany, z_shieldcoinbase and z_mergetoaddress.
+const DEFAULT_FEE: Amount = Amount::from_sat(10000);
+//
 // The move method in Wallet module has been deprecated.
 //addmultisigaddress has been DEPRECATED
 
-/// The backupwallet method safely copies the wallet.dat file to the indicated destination. The destination input accepts only alphanumeric characters.
-/// This method requires that the coin daemon have the exportdir runtime parameter enabled.
-/// # Arguments
-/// * `destination` -	(string, required)	the destination filename, saved in the directory set by the exportdir runtime parameter
-/// # Response
-/// * `path`	(string)	the full path of the destination file
-/// %%%
-pub fn backup_wallet(
-    someUser: komodorpcutil::KomodoRPC,
-    destination: String,
-) -> Result<String, reqwest::Error> {
-    let method_body = String::from("[\"") + &destination + "\"]";
-    let method_name: String = String::from("backupwallet");
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
+/// The object returned by getwalletinfo.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WalletInfo {
+    /// the wallet version
+    pub walletversion: u64,
+    /// the total confirmed balance of the wallet
+    pub balance: Amount,
+    /// the total unconfirmed balance of the wallet
+    pub unconfirmed_balance: Amount,
+    /// the total immature balance of the wallet
+    pub immature_balance: Amount,
+    /// the total number of transactions in the wallet
+    pub txcount: u64,
+    /// the timestamp of the oldest pre-generated key in the key pool
+    pub keypoololdest: u64,
+    /// how many new keys are pre-generated
+    pub keypoolsize: u64,
+    /// the time the wallet is unlocked until; only present for encrypted wallets
+    pub unlocked_until: Option<u64>,
+    /// the transaction fee configuration, given as the relevant COIN per KB
+    pub paytxfee: Amount,
 }
 
-/// The dumpprivkey method reveals the private key corresponding to the indicated address.
-/// See also importprivkey.
-/// # Arguments
-/// * `address` 	(string, required) 	the address for the private key
-/// # Response
-/// * `data` 	(string) 	the private key
-/// %%%
-pub fn dump_priv_key(
-    someUser: komodorpcutil::KomodoRPC,
-    address: String,
-) -> Result<String, reqwest::Error> {
-    let method_body = String::from("[\"") + &address + "\"]";
-    let method_name: String = String::from("dumpprivkey");
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
+/// One entry of the array returned by listtransactions.
+/// Fields that the daemon leaves out for some categories are optional.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ListTransactionsEntry {
+    /// DEPRECATED the account name associated with the transaction
+    pub account: Option<String>,
+    /// the address of the transaction; not present for move transactions
+    pub address: Option<String>,
+    /// the transaction category: send, receive, generate, immature, orphan or move
+    pub category: String,
+    /// the amount; negative for the send category
+    pub amount: Amount,
+    /// the vout value
+    pub vout: Option<u32>,
+    /// the fee; negative and only available for the send category
+    pub fee: Option<Amount>,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: Option<i64>,
+    /// the raw confirmations of the transaction
+    pub rawconfirmations: Option<i64>,
+    /// the block hash containing the transaction
+    pub blockhash: Option<String>,
+    /// the block index containing the transaction
+    pub blockindex: Option<u64>,
+    /// the block time in seconds since epoch
+    pub blocktime: Option<u64>,
+    /// the transaction id
+    pub txid: Option<String>,
+    /// the transaction time in seconds since epoch
+    pub time: Option<u64>,
+    /// the time received in seconds since epoch
+    pub timereceived: Option<u64>,
+    /// the comment associated with the transaction
+    pub comment: Option<String>,
+    /// for the move category, the account the funds came from or went to
+    pub otheraccount: Option<String>,
+    /// transaction size in bytes
+    pub size: Option<u64>,
 }
 
-/// The dumpwallet method dumps all transparent-address wallet keys into a file, using a human-readable format.
-/// Overwriting an existing file is not permitted. The destination parameter accepts only alphanumeric characters.
-/// This method requires that the coin daemon have the exportdir runtime parameter enabled.
-/// # Arguments
-/// * `filename`	(string, required)	the filename, saved in the folder set by the exportdir runtime parameter
-/// # Response
-/// * `path`	(string)	the full path of the destination file
-/// %%%
-pub fn dump_wallet(
-    someUser: komodorpcutil::KomodoRPC,
-    filename: String,
-) -> Result<String, reqwest::Error> {
-    let method_body = String::from("[\"") + &filename + "\"]";
-    let method_name: String = String::from("dumpwallet");
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
+/// One entry of the array returned by listunspent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Unspent {
+    /// the transaction id
+    pub txid: String,
+    /// the vout value
+    pub vout: u32,
+    /// true if txout is a coinbase transaction output
+    pub generated: bool,
+    /// the address
+    pub address: Option<String>,
+    /// DEPRECATED the associated account, or "" for the default account
+    pub account: Option<String>,
+    /// the script key
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: String,
+    /// the transaction amount
+    pub amount: Amount,
+    /// the interest accrued by the output, on chains that pay interest
+    pub interest: Option<Amount>,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: i64,
+    /// the raw confirmations (number of blocks on top of this transaction's block)
+    pub rawconfirmations: Option<i64>,
+    /// whether the output is spendable by this wallet
+    pub spendable: bool,
 }
 
-/// Using the encryptwallet method will shutdown the Komodo daemon (komodod).
-/// This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
-/// The encryptwallet method encrypts the wallet with the indicated passphrase.
-/// For more information, please see these instructions: Encrypt Komodo's wallet.dat File
-/// This method is for first-time encryption only. After the first encryption, any calls that interact with private keys will require the passphrase via walletpassphrase prior to calling the corresponding method. This includes methods that create a transaction, dump a private key for an address, sign a transaction, etc.
-/// # Arguments
-/// * `passphrase`	(string)	the passphrase for wallet encryption; the passphrase must be at least 1 character, but should be many
-/// # Response
-/// * Text Response -
-/// wallet encrypted; Komodo server stopping, restart to run with encrypted wallet. The keypool has been flushed, you need to make a new backup
-/// %%%
-pub fn encrypt_wallet(
-    someUser: komodorpcutil::KomodoRPC,
-    passphrase: String,
-) -> Result<String, reqwest::Error> {
-    let method_body = String::from("[\"") + &passphrase + "\"]";
-    let method_name: String = String::from("encryptwallet");
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
+/// One entry of the "details" array of a gettransaction reply.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionDetail {
+    /// DEPRECATED the account name involved in the transaction
+    pub account: Option<String>,
+    /// the address involved in the transaction
+    pub address: Option<String>,
+    /// the category: send or receive
+    pub category: String,
+    /// the amount
+    pub amount: Amount,
+    /// the vout value
+    pub vout: Option<u32>,
+    /// the fee; only available for the send category
+    pub fee: Option<Amount>,
+    /// the total size of the transaction in bytes
+    pub size: Option<u64>,
 }
 
-/// The getaccount method returns the account associated with the given address.
-/// # Arguments
-/// * `address`	(string, required)	the address
-/// # Response
-/// * `accountname`	(string)	the account address
-/// %%%
-pub fn get_account(
-    someUser: komodorpcutil::KomodoRPC,
-    address: String,
-) -> Result<String, reqwest::Error> {
-    let method_body = String::from("[\"") + &address + "\"]";
-    let method_name: String = String::from("getaccount");
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
+/// One entry of the "vjoinsplit" array of a gettransaction reply.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JoinSplit {
+    /// merkle root of note commitment tree
+    pub anchor: String,
+    /// the nullifiers of the spent notes
+    pub nullifiers: Vec<String>,
+    /// the commitments of the created notes
+    pub commitments: Vec<String>,
+    /// the macs of the joinsplit
+    pub macs: Vec<String>,
+    /// the amount removed from the transparent value pool
+    pub vpub_old: Amount,
+    /// the amount added to the transparent value pool
+    pub vpub_new: Amount,
 }
 
-/// getbalance (`account" minconf includeWatchonly ).
-/// The getbalance method returns the server's total available balance.
-/// The account input is deprecated.
-/// # Arguments
-/// * `account`	(string, optional)	DEPRECATED if provided, it MUST be set to the empty string "" or to the string "*"
-/// minconf	(numeric, optional, default=1)	only include transactions confirmed at least this many times
-/// includeWatchonly	(bool, optional, default=false)	also include balance in watchonly addresses (see importaddress)
-/// # Response
-/// * amount	(numeric)	the total amount
-
-pub fn get_balance(
-    //TODO check def value and if conditions
-    SomeUser: komodorpcutil::KomodoRPC,
-    minconf: Option<u32>,
-    includeWatchonly: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_minconf = minconf.unwrap_or(1); //Default value is 1
-    let temp_includeWatchonly: String = includeWatchonly.unwrap_or(false).to_string();
-    method_body = String::from("[\"\",")
-        + &temp_minconf.to_string()
-        + &",".to_string()
-        + &temp_includeWatchonly
-        + &String::from("]");
-
-    let method_name: String = String::from("getbalance");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The object returned by gettransaction.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WalletTransaction {
+    /// the transaction amount
+    pub amount: Amount,
+    /// the fee; only available for the send category
+    pub fee: Option<Amount>,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: i64,
+    /// the raw confirmations of the transaction
+    pub rawconfirmations: Option<i64>,
+    /// the block hash
+    pub blockhash: Option<String>,
+    /// the block index
+    pub blockindex: Option<u64>,
+    /// the time in seconds since epoch
+    pub blocktime: Option<u64>,
+    /// the transaction id
+    pub txid: String,
+    /// the transaction time in seconds since epoch
+    pub time: u64,
+    /// the time received in seconds since epoch
+    pub timereceived: u64,
+    /// the outputs of the transaction that involve this wallet
+    pub details: Vec<TransactionDetail>,
+    /// the joinsplits of the transaction
+    #[serde(default)]
+    pub vjoinsplit: Vec<JoinSplit>,
+    /// transaction data translated into hex
+    pub hex: String,
 }
 
-/*
-getbalance64
-This method is part of the new ac_staked functionality.// TO DO DIDNT IMPLEMENTED NOT ENOUGH INFO....
-The getbalance64 method is used only on Smart Chains that are utilizing the ac_staked functionality.
- On KMD-based Proof-of-Stake (PoS) Smart Chains, all staked coins are placed into one of 64 segments (segid's').
- The getbalance64 method returns the balance of coins in each segid.
-*/
-
-/// getnewaddress ( "account" ).
-/// The getnewaddress method returns a new address for receiving payments.
-/// # Arguments
-/// Name	Type	Description
-/// * "account"	(string, optional)	DEPRECATED: If provided, the account MUST be set to the empty string "" to represent the default account; passing any other string will result in an error
-/// # Response
-/// Name	Type	Description
-/// * "address"	(string)	the new address
-/// %%%
-pub fn get_new_address(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getnewaddress");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// One entry of a group returned by listaddressgroupings.
+/// The daemon sends each entry as an array `[address, amount, account]`,
+/// where the account is left out for addresses without one.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "AddressGroupingEntry")]
+pub struct AddressGrouping {
+    /// the address
+    pub address: String,
+    /// the amount
+    pub amount: Amount,
+    /// (DEPRECATED) the account
+    pub account: Option<String>,
 }
 
-/// getrawchangeaddress
-/// The getrawchangeaddress returns a new address that can be used to receive change.
-/// This is for use with raw transactions, NOT normal use.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * "address"	(string)	the address
-/// %%%
-pub fn get_raw_change_address(
-    SomeUser: komodorpcutil::KomodoRPC,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getrawchangeaddress");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AddressGroupingEntry {
+    WithAccount(String, Amount, String),
+    WithoutAccount(String, Amount),
 }
 
-/// getreceivedbyaddress "address" ( minconf ).
-/// The getreceivedbyaddress method returns the total amount received by the given address in transactions with at least minconf confirmations.
-/// # Arguments
-/// * "address"	(string, required)	the address for transactions
-/// minconf  	(numeric, optional, default=1)	only include transactions confirmed at least this many times
-/// # Response
-/// * amount	(numeric)	the total amount of the relevant coin received at this address
-
-pub fn get_receive_by_address(
-    SomeUser: komodorpcutil::KomodoRPC,
-    address: String,
-    min_conf: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_min_conf: String = min_conf.unwrap_or(1).to_string(); // default 1///TO DO
-    if (temp_min_conf.is_empty()) {
-        method_body =
-            String::from("[\"") + &address.to_string() + &String::from("\"") + &String::from("]");
-    } else
-    //if (!temp_gen_proc_limit.is_empty())
-    {
-        method_body = String::from("[\"")
-            + &address.to_string()
-            + &"\",".to_string()
-            + &temp_min_conf
-            + &String::from("]");
-    }
-
-    let method_name: String = String::from("getreceivedbyaddress");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+impl From<AddressGroupingEntry> for AddressGrouping {
+    fn from(entry: AddressGroupingEntry) -> Self {
+        match entry {
+            AddressGroupingEntry::WithAccount(address, amount, account) => AddressGrouping {
+                address,
+                amount,
+                account: Some(account),
+            },
+            AddressGroupingEntry::WithoutAccount(address, amount) => AddressGrouping {
+                address,
+                amount,
+                account: None,
+            },
+        }
+    }
 }
 
-/// gettransaction "txid" ( includeWatchonly ).
-/// The gettransaction method queries detailed information about transaction txid. This command applies only to txid's that are in the user's local wallet.
-/// # Arguments
-/// * "txid"	(string, required)	the transaction id
-/// * "includeWatchonly"	(bool, optional, default=false)	whether to include watchonly addresses in the returned balance calculation and in the details[] returned values
-/// # Response
-/// * "amount"	(numeric)	the transaction amount
-/// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
-/// * "rawconfirmations"	(numeric)	the raw confirmations (number of blocks on top of this transaction's block)
-/// * "blockhash"	(string)	the block hash
-/// * "blockindex"	(numeric)	the block index
-/// * "blocktime"	(numeric)	the time in seconds since epoch (1 Jan 1970 GMT)
-/// * "txid"	(string)	the transaction id
-/// * "time"	(numeric)	the transaction time in seconds since epoch (1 Jan 1970 GMT)
-/// * "timereceived"	(numeric)	the time received in seconds since epoch (1 Jan 1970 GMT)
-/// * "details" : [ ... ]	(array)
-/// * "account"	(string)	DEPRECATED the account name involved in the transaction; can be "" for the default account
-/// * "address"	(string)	the address involved in the transaction
-/// * "category"	(string)	the category - either send or receive
-/// * "amount"	(numeric)	the amount
-/// * "vout"	(numeric)	the vout value
-/// * "vjoinsplit" : [ ... ]	(array of json objects)
-/// * "anchor"	(string)	merkle root of note commitment tree
-/// * "nullifiers" : [ ... ]	(array of strings)
-/// * "hex"	(string)
-/// * "commitments" : [ ... ]	(array of strings)
-/// * "hex"	(string)
-/// * "macs" : [ ... ]	(array of strings)
-/// * "hex"	(string)
-/// * "vpub_old"	(numeric)	the amount removed from the transparent value pool
-/// * "vpub_new"	(numeric)	the amount added to the transparent value pool
-/// * "hex"	(string)	transaction data translated into hex
-/// %%%
-pub fn get_transaction(
-    SomeUser: komodorpcutil::KomodoRPC,
-    tx_id: String,
-    include_watch_only: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_include_watch_only: String = include_watch_only.unwrap_or(false).to_string();
-    method_body = String::from("[\"")
-        + &tx_id.to_string()
-        + &String::from("\",")
-        + &temp_include_watch_only
-        + &String::from("]");
-    let method_name: String = String::from("gettransaction");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The state of an asynchronous z_ operation, as returned by z_getoperationstatus
+/// and z_getoperationresult.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OperationStatus {
+    /// the operation id
+    pub id: String,
+    /// queued, executing, success, failed or cancelled
+    pub status: String,
+    /// the creation time, in seconds since epoch (Jan 1 1970 GMT)
+    pub creation_time: u64,
+    /// the name of the method used in the operation, e.g. z_sendmany
+    pub method: Option<String>,
+    /// the parameters of the operation
+    pub params: Option<Value>,
+    /// the result of an operation that succeeded
+    pub result: Option<OperationResult>,
+    /// the error of an operation that failed
+    pub error: Option<OperationError>,
+    /// the length of time to calculate the transaction
+    pub execution_secs: Option<f64>,
 }
 
-/// getunconfirmedbalance.
-/// The getunconfirmedbalance method returns the server's total unconfirmed balance.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * (none)
-/// %%%
-pub fn get_unconfirmed_balance(
-    SomeUser: komodorpcutil::KomodoRPC,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getunconfirmedbalance");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The result of a z_ operation that succeeded.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OperationResult {
+    /// the transaction id
+    pub txid: String,
 }
 
-/// The getwalletinfo method returns an object containing various information about the wallet state.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * "walletversion"	(numeric)	the wallet version
-/// * "balance"	(numeric)	the total confirmed balance of the wallet
-/// * "unconfirmed_balance"	(numeric)	the total unconfirmed balance of the wallet
-/// * "immature_balance"	(numeric)	the total immature balance of the wallet
-/// * "txcount"	(numeric)	the total number of transactions in the wallet
-/// * "keypoololdest"	(numeric)	the timestamp (seconds since GMT epoch) of the oldest pre-generated key in the key pool
-/// * "keypoolsize"	(numeric)	how many new keys are pre-generated
-/// * "unlocked_until"	(numeric)	the timestamp in seconds since epoch (midnight Jan 1 1970 GMT) that the wallet is unlocked for transfers, or 0 if the wallet is locked
-/// * "paytxfee"	(numeric)	the transaction fee configuration, given as the relevant COIN per KB
-/// %%%
-
-pub fn get_wallet_info(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("getwalletinfo");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The error of a z_ operation that failed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OperationError {
+    /// the associated error code
+    pub code: i64,
+    /// a message to indicate the nature of the error
+    pub message: String,
 }
 
-/// importaddress "address" ( "label" rescan ).
-/// The importaddress method adds an address or script (in hex) that can be watched as if it were in your wallet, although it cannot be used to spend.
-/// This call can take an increased amount of time to complete if rescan is true.
-/// # Arguments
-/// * "address"	(string, required)	the address to watch
-/// * "label"	(string, optional, default="")	an optional label
-/// * rescan	(boolean, optional, default=true)	rescan the wallet for transactions
-/// # Response
-/// * (none)
-/// %%%
-
-pub fn import_address(
-    //TODO check def value and if conditions
-    SomeUser: komodorpcutil::KomodoRPC,
-    address: String,
-    label: Option<String>,
-    rescan: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_label: String = label.unwrap_or("".to_string()).to_string();
-    let temp_rescan: String = rescan.unwrap_or(true).to_string();
-
-    if (temp_label.is_empty()) {
-        method_body =
-            String::from("[\"") + &address + &"\",".to_string() + &temp_rescan + &String::from("]");
-    } else {
-        method_body = String::from("[\"")
-            + &address
-            + &"\",\"".to_string()
-            + &temp_label
-            + &"\",".to_string()
-            + &temp_rescan
-            + &String::from("]");
-    }
-    let method_name: String = String::from("importaddress");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The balance of an ac_staked chain by segid, as returned by getbalance64.
+/// Entry n of staking and notstaking holds the balance of segid n.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Balance64 {
+    /// the balance of mature coins, which can stake
+    pub mature: Amount,
+    /// the balance of immature coins
+    pub immature: Amount,
+    /// the mature balance of each of the 64 segids
+    pub staking: Vec<Amount>,
+    /// the immature balance of each of the 64 segids
+    pub notstaking: Vec<Amount>,
 }
 
-/// importkey "komodoprivkey" ( "label" rescan ).
-/// The importprivkey method adds a private key to your wallet.
-/// This call can take minutes to complete if rescan is true.
-/// See also dumpprivkey.
-/// # Arguments
-/// * "privkey"	(string, required)	the private key (see dumpprivkey)
-/// * "label"	(string, optional, default="")	an optional label
-/// * rescan	(boolean, optional, default=true)	rescan the wallet for transactions
-/// # Response
-/// * addresses	(string)	the public address
-/// %%%
-pub fn import_priv_key(
-    //TODO check def value and if conditions
-    SomeUser: komodorpcutil::KomodoRPC,
-    priv_key: String,
-    label: Option<String>,
-    rescan: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_label: String = label.unwrap_or("".to_string()).to_string();
-    let temp_rescan: String = rescan.unwrap_or(true).to_string();
-
-    if (temp_label.is_empty()) {
-        method_body = String::from("[\"")
-            + &priv_key
-            + &"\",".to_string()
-            + &temp_rescan
-            + &String::from("]");
-    } else {
-        method_body = String::from("[\"")
-            + &priv_key
-            + &"\",\"".to_string()
-            + &temp_label
-            + &"\",".to_string()
-            + &temp_rescan
-            + &String::from("]");
-    }
-    let method_name: String = String::from("importprivkey");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The balances returned by z_gettotalbalance. The daemon sends them as strings;
+/// they are read into Amounts, which take strings as well as numbers.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShieldedBalance {
+    /// the total balance of transparent funds
+    pub transparent: Amount,
+    /// the total balance of unclaimed interest earned, only sent by the KMD chain
+    #[serde(default)]
+    pub interest: Option<Amount>,
+    /// the total balance of private funds
+    pub private: Amount,
+    /// the total balance of both transparent and private funds
+    pub total: Amount,
 }
 
-/// importwallet "filename".
-/// The importwallet method imports transparent-address keys from a wallet-dump file (see dumpwallet).
-/// # Arguments
-/// * "filename"	(string, required)	the wallet file
-/// # Response
-/// * (none)
-///  %%%
-
-pub fn import_wallet(
-    someUser: komodorpcutil::KomodoRPC,
-    file_name: String,
-) -> Result<String, reqwest::Error> {
-    let params = String::from("[\"") + &file_name + "\"]";
-
-    let method_name: String = String::from("importwallet");
-    let method_body: String = String::from(params);
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
+/// A note received by a z address, as returned by z_listreceivedbyaddress.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReceivedNote {
+    /// the transaction id
+    pub txid: String,
+    /// the amount of value in the note
+    pub amount: Amount,
+    /// hexadecimal string representation of the memo field
+    pub memo: String,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: Option<i64>,
+    /// the raw confirmations (number of blocks on top of this transaction's block)
+    pub rawconfirmations: Option<i64>,
+    /// the joinsplit index, for sprout addresses
+    pub jsindex: Option<u32>,
+    /// the output index of the joinsplit, for sprout addresses
+    pub jsoutindex: Option<u32>,
+    /// the output index, for sapling addresses
+    pub outindex: Option<u32>,
+    /// true if the address that received the note is also one of the sending addresses
+    pub change: Option<bool>,
 }
 
-/// keypoolrefill ( newsize ).
-/// The keypoolrefill method refills the keypool.
-/// # Arguments
-/// * newsize	(numeric, optional, default=100)	the new keypool size
-/// # Response
-/// * (none)
-/// %%%
-pub fn key_pool_refill(
-    SomeUser: komodorpcutil::KomodoRPC,
-    new_size: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_new_size: String = new_size.unwrap_or(100).to_string();
-    method_body = String::from("[") + &temp_new_size + &String::from("]");
-    let method_name: String = String::from("keypoolrefill");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// A note sent from a z address, as returned by z_listsentbyaddress.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SentNote {
+    /// the transaction id
+    pub txid: String,
+    /// the receiving address
+    pub address: Option<String>,
+    /// the amount of value in the note
+    pub amount: Amount,
+    /// hexadecimal string representation of the memo field
+    pub memo: Option<String>,
+    /// a confirmation number that is aware of the dPoW security service
+    pub confirmations: Option<i64>,
+    /// the output index, for sapling notes
+    pub outindex: Option<u32>,
+    /// true if the note returns change to the sending address
+    pub change: Option<bool>,
 }
 
-/// listaddressgroupings.
-/// The listaddressgroupings method lists groups of addresses which have had their common ownership made public by common use as inputs or as the resulting change in past transactions.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * "address",	(string)	the address
-/// * amount,	(numeric)	the amount
-/// * "account"	(string, optional)	(DEPRECATED) the account
-///  %%%
-
-pub fn list_address_groupings(
-    SomeUser: komodorpcutil::KomodoRPC,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("listaddressgroupings");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// The number of notes in the wallet, as returned by z_getnotescount.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NotesCount {
+    /// the number of sprout notes
+    pub sprout: u64,
+    /// the number of sapling notes
+    pub sapling: u64,
 }
 
-/// listlockunspent.
-/// The listlockunspent method returns a list of temporarily non-spendable outputs.
-/// See the lockunspent call to lock and unlock transactions for spending.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * "txid"	(string)	the transaction id locked
-/// * "vout"	(numeric)	the vout value
-
-pub fn list_lock_unspent(SomeUser: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("listlockunspent");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// A shielded spend of a transaction, as returned by z_viewtransaction.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ViewSpend {
+    /// the pool of the spent note, sprout or sapling
+    #[serde(rename = "type")]
+    pub pool: String,
+    /// the index of the spend within vShieldedSpend, for sapling
+    pub spend: Option<u32>,
+    /// the id of the transaction that created the spent note
+    #[serde(rename = "txidPrev")]
+    pub txid_prev: String,
+    /// the index of the output that created the spent note, for sapling
+    #[serde(rename = "outputPrev")]
+    pub output_prev: Option<u32>,
+    /// the address that owned the spent note
+    pub address: String,
+    /// the value of the spent note
+    pub value: Amount,
+    /// the value of the spent note in satoshis
+    #[serde(rename = "valueZat")]
+    pub value_zat: i64,
 }
 
-/// listreceivedbyaddress ( minconf includeempty includeWatchonly).
-/// The listreceivedbyaddress method lists balances by receiving address.
-/// # Arguments
-/// * minconf	(numeric, optional, default=1)	the minimum number of confirmations before payments are included
-/// * includeempty	(numeric, optional, default=false)	whether to include addresses that haven't received any payments// TO DO must be bool
-/// * includeWatchonly	(bool, optional, default=false)	whether to include watchonly addresses (see 'importaddress')
-/// # Response
-/// * "involvesWatchonly"	(bool)	only returned if imported addresses were involved in transaction
-/// * "address"	(string)	the receiving address
-/// * "account"	(string)	DEPRECATED the account of the receiving address; the default account is ""
-/// * "amount"	(numeric)	the total amount received by the address
-/// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
-/// * "rawconfirmations"	(numeric)	the raw confirmations of the most recent transaction included (number of blocks on top of this transaction's block
-/// %%%
-
-pub fn list_received_by_address(
-    SomeUser: komodorpcutil::KomodoRPC,
-    min_conf: Option<u32>,
-    include_empty: Option<bool>,
-    include_watch_only: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    let temp_min_conf: String = min_conf.unwrap_or(1).to_string();
-    let temp_include_empty: String = include_empty.unwrap_or(false).to_string();
-    let temp_include_watch_only: String = include_watch_only.unwrap_or(false).to_string();
-
-    method_body = String::from("[")
-        + &temp_min_conf
-        + &String::from(",")
-        + &temp_include_empty
-        + &String::from(",")
-        + &temp_include_watch_only
-        + &String::from("]");
-    let method_name: String = String::from("listreceivedbyaddress");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+/// A shielded output of a transaction, as returned by z_viewtransaction.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ViewOutput {
+    /// the pool of the note, sprout or sapling
+    #[serde(rename = "type")]
+    pub pool: String,
+    /// the index of the output within vShieldedOutput, for sapling
+    pub output: Option<u32>,
+    /// the address the note was sent to
+    pub address: String,
+    /// true if the note was recovered with an outgoing viewing key
+    pub recovered: Option<bool>,
+    /// the value of the note
+    pub value: Amount,
+    /// the value of the note in satoshis
+    #[serde(rename = "valueZat")]
+    pub value_zat: i64,
+    /// hexadecimal string representation of the memo field
+    pub memo: String,
+    /// the memo as text, when it is valid UTF-8
+    #[serde(rename = "memoStr")]
+    pub memo_str: Option<String>,
 }
 
-// TODO COMPLETE
-
-/// listsinceblock ( "blockhash" target-confirmations includeWatchonly ).
-/// The listsinceblock method queries all transactions in blocks since block blockhash, or all transactions if blockhash is omitted.
-/// # Arguments
-/// * "blockhash"	(string, optional)	the block hash from which to list transactions
-/// * target-confirmations	(numeric, optional)	the confirmations required (must be 1 or more)
-/// * includeWatchonly	(bool, optional, default=false)	include transactions to watchonly addresses (see also 'importaddress')
-/// # Response
-/// * "transactions":
-/// * "account"	(string)	DEPRECATED the account name associated with the transaction; will be "" for the default account
-/// * "address"	(string)	the address of the transaction (not present for move transactions -- category = move)
-/// * "category"	(string)	the transaction category; send has negative amounts, receive has positive amounts
-/// * "amount"	(numeric)	the amount of the relevant currency -- negative for the send category, and for the move category for moves outbound. It is positive for the receive category, and for the move category for inbound funds.
-/// * "vout"	(numeric)	the vout value
-/// * "fee"	(numeric)	the amount of the fee; this value is negative and only available for the send category of transactions
-/// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
-/// * "rawconfirmations"	(numeric)	the raw confirmations of the transaction; available for send and receive category of transactions (number of blocks on top of this transaction's block)
-/// * "blockhash"	(string)	the block hash containing the transaction; available for the send and receive categories of transactions
-/// * "blockindex"	(numeric)	the block index containing the transaction; available for the send and receive categories of transactions
-/// * "blocktime"	(numeric)	the block time in seconds since epoch (1 Jan 1970 GMT)
-/// * "txid"	(string)	the transaction id; available for send and receive categories of transactions
-/// * "time"	(numeric)	the transaction time in seconds since epoch (Jan 1 1970 GMT)
-/// * "timereceived"	(numeric)	the time received in seconds since epoch (Jan 1 1970 GMT); available for send and receive category of transactions
-/// * "comment"	(string)	whether a comment is associated with the transaction
-/// * "to"	(string)	whether a 'to' comment is associated with the transaction
-/// * "lastblock"	(string)	the hash of the last block
-/// %%%
-
-pub fn list_since_block(
-    some_user: komodorpcutil::KomodoRPC,
-    block_hash: Option<String>,
-    target_conformations: Option<u32>,
-    include_watch_only: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("listsinceblock");
-    let temp_block_hash: String = block_hash.unwrap_or("".to_string());
-    let temp_target_conformations = target_conformations.unwrap_or(1);
-    let temp_watch_only = include_watch_only.unwrap_or(false);
-
-    let method_body: String = String::from("[\"")
-        + &temp_block_hash
-        + &String::from("\",")
-        + &temp_target_conformations.to_string()
-        + &String::from(",")
-        + &temp_watch_only.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+/// The shielded parts of a wallet transaction, as returned by z_viewtransaction.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ViewTransaction {
+    /// the transaction id
+    pub txid: String,
+    /// the notes spent by the transaction
+    pub spends: Vec<ViewSpend>,
+    /// the notes created by the transaction
+    pub outputs: Vec<ViewOutput>,
 }
 
-// TODO
-
-/// The listtransactions method returns up to count most recent transactions skipping the first from transactions for account.
-/// # Arguments
-/// "account"       	(string, optional)            	DEPRECATED the account name; should be "*"
-/// * count	            (numeric, optional, default=10)	the number of transactions to return
-/// * from	            (numeric, optional, default=0)	the number of transactions to skip
-/// includeWatchonly	(bool, optional, default=false)	include transactions to watchonly addresses (see importaddress)
-/// # Response
-/// * "account"	(string)	DEPRECATED the account name associated with the transaction; it will be "" for the default account
-/// * "address"	(string)	the address of the transaction; not present for move transactions (category = move)
-/// * "category"	(string)	The transaction category. This property can be send
-/// * "amount"	(numeric)	The amount. This value is negative for the send category, and for the move category for moves outbound. It is positive for the receive category and for the move category for inbound funds.
-/// * "vout"	(numeric)	the vout value
-/// * "fee"	(numeric)	the amount of the fee; this is negative and only available for the send category of transactions
-/// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
-/// * "rawconfirmations"	(numeric)	the raw confirmations of the transaction; available for send and receive category of transactions (number of blocks on top of this transaction's block)
-/// * "blockhash"	(string)	the block hash containing the transaction; available for the send and receive categories of transactions
-/// * "blockindex"	(numeric)	the block index containing the transaction; available for the send and receive categories of transactions
-/// * "txid"	(string)	the transaction id; available for the send and receive categories of transactions
-/// * "time"	(numeric)	the transaction time in seconds since epoch (midnight Jan 1 1970 GMT)
-/// * "timereceived"	(numeric)	the time received in seconds since epoch (midnight Jan 1 1970 GMT); available for the send and receive categories of transactions
-/// * "comment"	(string)	whether a comment is associated with the transaction
-/// * "otheraccount"	(string)	for the move category of transactions; indicates the account which sent the funds (for receiving funds, positive amounts), or went to (for sending funds, negative amounts)
-/// * "size"	(numeric)	transaction size in bytes
-/// * %%%
-
-pub fn list_transactions(
-    some_user: komodorpcutil::KomodoRPC,
-    accoount: Option<String>,
-    count: Option<u32>,
-    from: Option<u32>,
-    include_watch_only: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("listtransactions");
-
-    let method_body: String = String::from("[\"")
-        + &"*".to_string()
-        + &String::from("\",")
-        + &count.unwrap_or(10).to_string()
-        + &from.unwrap_or(0).to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+/// The result of z_mergetoaddress.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MergeResult {
+    /// the number of utxos still available for merging
+    #[serde(rename = "remainingUTXOs")]
+    pub remaining_utxos: u64,
+    /// the value of utxos still available for merging
+    #[serde(rename = "remainingTransparentValue")]
+    pub remaining_transparent_value: Amount,
+    /// the number of notes still available for merging
+    #[serde(rename = "remainingNotes")]
+    pub remaining_notes: u64,
+    /// the value of notes still available for merging
+    #[serde(rename = "remainingShieldedValue")]
+    pub remaining_shielded_value: Amount,
+    /// the number of utxos being merged
+    #[serde(rename = "mergingUTXOs")]
+    pub merging_utxos: u64,
+    /// the value of utxos being merged
+    #[serde(rename = "mergingTransparentValue")]
+    pub merging_transparent_value: Amount,
+    /// the number of notes being merged
+    #[serde(rename = "mergingNotes")]
+    pub merging_notes: u64,
+    /// the value of notes being merged
+    #[serde(rename = "mergingShieldedValue")]
+    pub merging_shielded_value: Amount,
+    /// an operationid to follow with an OperationTracker or z_getoperationstatus
+    pub opid: String,
 }
 
-//TODO
-/*
-/// * listunspent ( minconf maxconf ["address", ... ] ).
-/// * The listunspent method returns an array of unspent transaction outputs, with a range between minconf and maxconf (inclusive) confirmations. The method can, optionally, filter to only include txouts paid to specified addresses.
-/// # Arguments
-/// * minconf	(numeric, optional, default=1)	the minimum confirmations to filter
-/// * maxconf	(numeric, optional, default=9999999)	the maximum confirmations to filter
-/// * "address"	(string)	a series of addresses
-///  # Response
-/// * "txid"	(string)	the transaction id
-/// * "vout"	(numeric)	the vout value
-/// * "generated"	(boolean)	true if txout is a coinbase transaction output
-/// * "address"	(string)	the address
-/// * "account"	(string)	DEPRECATED the associated account, or "" for the default account
-/// * "scriptPubKey"	(string)	the script key
-/// * "amount"	(numeric)	the transaction amount
-/// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
-/// * "rawconfirmations"	(numeric)	the raw confirmations (number of blocks on top of this transaction's block)
-/// * %%%
-*/
-pub fn list_unspent(
-    some_user: komodorpcutil::KomodoRPC,
-    minconf: Option<u32>,
-    maxconf: Option<u32>,
-    address: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("listunspent");
-
-    let temp_minconf = minconf.unwrap_or(1);
-    let temp_maxconf = maxconf.unwrap_or(9999999);
-
-    let method_body: String = String::from("[\"")
-        + &temp_minconf.to_string()
-        + &String::from(", ")
-        + &temp_maxconf.to_string()
-        + &String::from(" [\"")
-        + &address
-        + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+/// The size of the memo field of a shielded note, in bytes.
+pub const MEMO_SIZE: usize = 512;
+
+/// A text memo for a shielded note. It is hex-encoded when sent, as the daemon expects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Memo(String);
+
+impl Memo {
+    /**
+     *Function Name: new
+     *@params: text - the memo
+     *Output: the memo
+     *           KomodoError::InvalidMemo - when the text is longer than MEMO_SIZE bytes
+     */
+    pub fn new(text: &str) -> Result<Memo, KomodoError> {
+        if text.len() > MEMO_SIZE {
+            return Err(KomodoError::InvalidMemo(format!(
+                "{} bytes, at most {} fit in a note",
+                text.len(),
+                MEMO_SIZE
+            )));
+        }
+        Ok(Memo(text.to_string()))
+    }
 
-//  TODO - unfinished
-
-/// * lockunspent unlock [{ "txid": "txid", "vout": n }, ... ].
-/// * The lockunspent method locks (unlock = false) or unlocks (unlock = true) specified transaction outputs. A locked transaction output will not be chosen by automatic coin selection, when spending the relevant coin. The locks are stored in memory only; at runtime a node always starts with zero locked outputs, and the locked output list is always cleared when a node stops or fails.
-/// * See the listunspent and listlockunspent calls to determine local transaction state and info.
-/// # Arguments
-/// * unlock	(boolean, required)	whether to unlock (true) or lock (false) the specified transactions
-/// * "txid"	(string)	the transaction id
-/// * "vout"	(numeric)	the output number
-/// # Response
-/// * true/false	(boolean)	whether the command was successful
-/// * %%%
-
-pub fn lock_unspent(
-    someUser: komodorpcutil::KomodoRPC,
-    unlock: bool,
-    txid: String,
-    vout: u32,
-) -> Result<String, reqwest::Error> {
-    let method_body = String::from("[")
-        + &unlock.to_string()
-        + &(",[{\"txid\":\"").to_string()
-        + &txid
-        + &("\",\"vout\":").to_string()
-        + &vout.to_string()
-        + &("}]]").to_string();
-    let method_name: String = String::from("lockunspent");
-    let data: String = String::from(komodorpcutil::generate_body(
-        someUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(someUser.clone(), data)
+    /**
+     *Function Name: as_str
+     *@params: the instance of the struct Memo
+     *Output: the text of the memo
+     */
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
-/// * resendwallettransactions.
-/// * The resendwallettransactions method immediately re-broadcasts unconfirmed wallet transactions to all peers. This method is intended only for testing; the wallet code periodically re-broadcasts automatically.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * "transaction_id"	(string)	an array of the rebroadcasted transaction id's
-/// * %%%  
-
-pub fn resend_wallet_transactions(
-    SomeUser: komodorpcutil::KomodoRPC,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("resendwallettransactions");
-    let method_body: String = String::from("[]");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
+impl FromStr for Memo {
+    type Err = KomodoError;
 
-/** TODO - unfinished
- * sendmany "account" { "address": amount, ... } ( minconf "comment" [ "address", ... ] )
-The sendmany method can send multiple transactions at once. Amounts are double-precision floating point numbers.
-#Arguments
-Name	Type	Description
-"account"	(string, required)	MUST be set to the empty string "" to represent the default account; passing any other string will result in an error
-"amounts" { "address":amount, ... }	("string":numeric)	the address (string) and the value (double-precision floating numeric)
-minconf	(numeric, optional, default=1)	only use the balance confirmed at least this many times
-"comment"	(string, optional)	a comment
-subtractfeefromamount	(string, optional)	a json array with addresses. The fee will be equally deducted from the amount of each selected address; the recipients will receive less than you enter in their corresponding amount field. If no addresses are specified here, the sender pays the fee.
-"address"	(string)	subtract fee from this address
-#Response
-Name	Type	Description
-"transaction_id"	(string)	the transaction id for the send; only 1 transaction is created regardless of the number of addresses
- */
-
-/// * sendmany "account" { "address": amount, ... } ( minconf "comment" [ "address", ... ] ).
-/// * The sendmany method can send multiple transactions at once. Amounts are double-precision floating point numbers.
-/// # Arguments
-/// * "account" 	(string, required) 	MUST be set to the empty string "" to represent the default account; passing any other string will result in an error
-/// * "amounts" { "address":amount, ... } 	("string":numeric) 	the address (string) and the value (double-precision floating numeric)
-/// * minconf 	(numeric, optional, default=1) 	only use the balance confirmed at least this many times
-/// * "comment" 	(string, optional) 	a comment
-/// * subtractfeefromamount 	(string, optional) 	a json array with addresses. The fee will be equally deducted from the amount of each selected address; the recipients will receive less than you enter in their corresponding amount field. If no addresses are specified here, the sender pays the fee.
-/// * "address" 	(string) 	subtract fee from this address
-/// # Response
-/// * "transaction_id" 	(string) 	the transaction id for the send; only 1 transaction is created regardless of the number of addresses
-/// * %%%
-pub fn send_many(
-    some_user: komodorpcutil::KomodoRPC,
-    account: String,
-    amounts: String,
-    minconf: Option<u32>,
-    comment: Option<String>,
-    subtract_fee_from_amount: Option<String>,
-    address: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("sendmany");
-    let temp_minconf = minconf.unwrap_or(1);
-    let temp_comment: String = comment.unwrap_or("".to_string());
-    let temp_subtract_fee_from_amount: String = subtract_fee_from_amount.unwrap_or("".to_string());
-    // dont use account?
-    let method_body: String = String::from("[\"")
-        + &String::from("\",")
-        + &temp_minconf.to_string()
-        + &String::from(",")
-        + &String::from("\"")
-        + &temp_comment.to_string()
-        + &String::from("\"")
-        + &String::from(",")
-        + &temp_subtract_fee_from_amount.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+    fn from_str(text: &str) -> Result<Memo, KomodoError> {
+        Memo::new(text)
+    }
 }
 
-/// * sendtoaddress "address" amount ( "comment" "comment-to" subtractfeefromamount )
-/// *
-/// * The sendtoaddress method sends an amount to a given address. The amount is real and is rounded to the nearest 0.00000001.
-/// # Arguments
-/// * "komodoaddress" 	(string, required) 	the receiving address
-/// * "amount" 	(numeric, required) 	the amount to send (json requires all decimals values less than 1 begin with the characters '0.')
-/// * "comment" 	(string, optional) 	a comment used to store what the transaction is for; this is not part of the transaction, just kept in your wallet
-/// * "comment-to" 	(string, optional) 	a comment to store the name of the person or organization to which you're sending the transaction; this is stored in your local wallet file only
-/// * subtractfeefromamount 	(boolean, optional, default=false) 	when true, the fee will be deducted from the amount being sent
-/// # Response
-/// * "transaction_id" 	(string) 	the transaction id
-
-pub fn send_to_address(
-    some_user: komodorpcutil::KomodoRPC,
-    komodo_address: String,
-    amount: f64,
-    comment: Option<String>,
-    comment_to: Option<String>,
-    subtract_fee_from_amount: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("sendtoaddress");
-    let temp_comment: String = comment.unwrap_or("".to_string());
-    let temp_comment_to: String = comment_to.unwrap_or("".to_string());
-    let temp_subtract_fee_from_amount = subtract_fee_from_amount.unwrap_or(false);
-
-    let mut method_body: String = String::from("[\"")
-        + &komodo_address.to_string()
-        + &String::from("\",")
-        + &amount.to_string();
-
-    if (!temp_comment.is_empty()) {
-        let temp_string: String = String::from(",\"") + &temp_comment + &String::from("\"");
-        method_body.push_str(&temp_string);
-    }
-
-    if (!temp_comment_to.is_empty()) {
-        let temp_string: String = String::from(",\"") + &temp_comment_to + &String::from("\"");
-        method_body.push_str(&temp_string);
-    }
-    if (temp_subtract_fee_from_amount) {
-        method_body.push_str(&String::from(", true"));
-    }
-
-    method_body.push_str(&String::from("]"));
-    println!("{}", method_body);
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    let result = komodorpcutil::request(some_user.clone(), data);
-    return result;
-}
-/// * setpubkey pubkey.
-/// * The setpubkey method sets the indicated pubkey. This method can be used in place of the pubkey launch parameter, when necessary.
-/// * Visit the section pubkey to understand when it is essential to set a pubkey and the consequences of setting it.
-/// * This method works only once per daemon start. It can't be used to change the pubkey that has already been set.
-/// # Arguments
-/// * pubkey 	(string) 	the desired pubkey
-/// # Response
-/// * pubkey 	(string) 	the pubkey
-/// * ismine 	(boolean) 	indicates whether the address belongs to the user
-/// * R-address 	(string) 	the public address associated with the pubkey
-/// * %%%
-pub fn set_pub_key(
-    some_user: komodorpcutil::KomodoRPC,
-    pub_key: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("setpubkey");
-
-    let method_body: String = String::from("[\"") + &pub_key.to_string() + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+impl serde::Serialize for Memo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.as_bytes().to_hex())
+    }
 }
 
-/// * settxfee amount
-/// * The settxfee method sets the transaction fee per kB.
-/// * # Arguments
-/// * amount 	(numeric, required) 	the transaction fee in COIN/kB rounded to the nearest 0.00000001
-/// * # Response
-/// * true/false 	(boolean) 	returns true if successful
-/// * %%%
-pub fn set_tx_fee(
-    some_user: komodorpcutil::KomodoRPC,
-    amount: f64,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("settxfee");
-
-    let method_body: String = String::from("[") + &amount.to_string() + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+/// One recipient of z_sendmany, serialized as `{ "address": ..., "amount": ..., "memo": ... }`.
+/// The memo is left out of the request when there is none.
+#[derive(Serialize, Debug, Clone)]
+pub struct ZRecipient {
+    /// the receiving t address or z address
+    pub address: Address,
+    /// the amount to send
+    pub amount: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memo: Option<Memo>,
 }
 
-/// * signmessage "address" "message"
-/// * The signmessage method signs a message via the private key of an address.
-/// # Arguments
-/// * "address" 	(string, required) 	the address to use for the private key
-/// * "message" 	(string, required) 	the message
-/// # Response
-/// * "signature" 	(string) 	the signature of the message encoded in base 64
-
-pub fn sign_message(
-    some_user: komodorpcutil::KomodoRPC,
-    address: String,
-    message: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("signmessage");
-
-    let method_body: String = String::from("[\"")
-        + &address.to_string()
-        + &String::from("\",\"")
-        + &message.to_string()
-        + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+impl ZRecipient {
+    /// The new method of ZRecipient creates a recipient without a memo.
+    pub fn new(address: Address, amount: Amount) -> ZRecipient {
+        ZRecipient {
+            address,
+            amount,
+            memo: None,
+        }
+    }
 
-/// * walletlock
-/// * The walletlock method is neither active nor visible in the help method until the encryptwallet passphrase is set.
-/// * This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
-/// * The walletlock method re-locks a wallet that has a passphrase enabled via encryptwallet.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * (none)
-
-pub fn wallet_lock(some_user: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("walletlock");
-
-    let method_body: String = String::from("[]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
-/// * walletpassphrase "passphrase" (timeout)
-/// * The walletpassphrase method is neither active nor visible in the help method until the encryptwallet passphrase is set.
-/// * This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
-/// * The walletpassphrase method unlocks the wallet using the passphrase that was set by the encryptwallet method.
-/// * The timeout argument can be included to limit the length of time (in seconds) the wallet will remain unlocked.
-/// # Arguments
-/// * "passphrase" 	(string) 	the passphrase that was set by the encryptwallet method
-/// * timeout 	(number in seconds, optional) 	the amount of time for which the wallet should remember the passphrase
-/// # Response
-/// * (none)
-/// * %%%
-
-pub fn wallet_pass_phrase(
-    some_user: komodorpcutil::KomodoRPC,
-    pass_phrase: String,
-    timeout: Option<f64>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("walletpassphrase");
-
-    let mut method_body: String =
-        String::from("[\"") + &pass_phrase.to_string() + &String::from("\"");
-
-    if let Some(x) = timeout {
-        method_body = method_body + &String::from(",") + &x.to_string();
-    }
-    method_body = method_body + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// The with_memo method attaches a memo to the recipient; only z addresses take one.
+    pub fn with_memo(mut self, memo: Memo) -> Result<ZRecipient, KomodoError> {
+        if !self.address.is_shielded() {
+            return Err(KomodoError::InvalidMemo(format!(
+                "{} is a t address; only z addresses take a memo",
+                self.address
+            )));
+        }
+        self.memo = Some(memo);
+        Ok(self)
+    }
 
-/// * walletpassphrasechange "oldpassphrase" "newpassphrase"
-/// * The walletpassphrasechange method is neither active nor visible in the help method until the encryptwallet passphrase is set.
-/// * This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
-/// * The walletpassphrasechange method changes "oldpassphrase" to "newpassphrase".
-/// # Arguments
-/// * "oldpassphrase" 	(string) 	the old passphrase
-/// * "newpassphrase" 	(string) 	the new passphrase
-/// # Response
-/// * (none)
-/// * %%%
-pub fn wallet_pass_phrase_change(
-    some_user: komodorpcutil::KomodoRPC,
-    old_pass_phrase: String,
-    new_pass_phrase: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("walletpassphrasechange");
-
-    let method_body: String = String::from("[\"")
-        + &old_pass_phrase.to_string()
-        + &String::from("\",\"")
-        + &new_pass_phrase.to_string()
-        + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
-/// * z_exportkey "z_address"
-/// * The z_exportkey method reveals the private z_key corresponding to z_address.
-/// * See also z_importkey.
-/// # Arguments
-/// * "z_address" 	(string, required) 	the z_address for the private key
-/// # Response
-/// * "key" 	(string) 	the private key
-/// * %%%
-pub fn z_export_key(
-    some_user: komodorpcutil::KomodoRPC,
-    z_address: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_exportkey");
-
-    let method_body: String = String::from("[\"") + &z_address.to_string() + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+    /// The memo method returns the memo of the recipient, if any.
+    pub fn memo(&self) -> Option<&Memo> {
+        self.memo.as_ref()
+    }
 }
 
-/// * z_exportviewingkey "z_address"
-/// * The z_exportviewingkey method reveals the viewing key corresponding to z_address.
-/// * See also z_importviewingkey.
-/// # Arguments
-/// * "z_address" 	(string, required) 	the z_address for the viewing key
-/// # Response
-/// * "vkey" 	(string) 	the viewing key
-/// * %%%
-
-pub fn z_export_viewing_key(
-    some_user: komodorpcutil::KomodoRPC,
-    z_address: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_exportviewingkey");
-
-    let method_body: String = String::from("[\"") + &z_address.to_string() + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
+/// Calls of the wallet API, borrowed from a KomodoClient with `client.wallet()`.
+pub struct Wallet<'a, C = KomodoClient> {
+    pub(super) client: &'a C,
 }
 
-/// * z_exportwallet "filename"
-/// * The z_exportwallet method exports all wallet keys, including both t address and z address types, in a human-readable format. Overwriting an existing file is not permitted.
-/// # Arguments
-/// * "filename" 	(string, required) 	the filename, saved to the directory indicated by the exportdir parameter at daemon runtime (required)
-/// # Response
-/// * "path" 	(string) 	the full path of the destination file
-/// * %%%
-pub fn z_export_wallet(
-    some_user: komodorpcutil::KomodoRPC,
-    file_name: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_exportwallet");
-
-    let method_body: String = String::from("[\"") + &file_name.to_string() + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+impl<'a, C> Wallet<'a, C> {
+    /// The backupwallet method safely copies the wallet.dat file to the indicated destination. The destination input accepts only alphanumeric characters.
+    /// This method requires that the coin daemon have the exportdir runtime parameter enabled.
+    /// # Arguments
+    /// * `destination` -	(string, required)	the destination filename, saved in the directory set by the exportdir runtime parameter
+    /// # Response
+    /// * `path`	(string)	the full path of the destination file
+    /// %%%
+    pub fn backup_wallet(&self, destination: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("backupwallet");
+        let method_body = vec![json!(destination)];
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_getbalance "address" ( minconf )
-/// * The z_getbalance method returns the balance of a t address or z address belonging to the node’s wallet.
-/// * CAUTION: If address is a watch-only z address, the returned balance may be larger than the actual balance, as spends cannot be detected with incoming viewing keys.
-/// # Arguments
-/// * "address" 	(string) 	the selected z or t address
-/// * minconf 	(numeric, optional, default=1) 	only include transactions confirmed at least this many times
-/// # Response
-/// * amount 	(numeric) 	the total amount received at this address (in the relevant COIN value)
-pub fn z_get_balance(
-    some_user: komodorpcutil::KomodoRPC,
-    address: String,
-    minconf: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_getbalance");
-    let temp_minconf: String = minconf.unwrap_or(1).to_string();
-    let method_body: String = String::from("[\"")
-        + &address.to_string()
-        + &String::from("\",")
-        + &temp_minconf.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
-/// * z_getnewaddress
-/// * The z_getnewaddress method returns a new z_address for receiving payments.
-/// # Arguments
-/// * (none)
-/// # Response
-/// * "z_address" 	(string) 	the new z_address
-
-pub fn z_get_new_address(some_user: komodorpcutil::KomodoRPC) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_getnewaddress");
-
-    let method_body: String = String::from("[]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// The dumpprivkey method reveals the private key corresponding to the indicated address.
+    /// See also importprivkey.
+    /// # Arguments
+    /// * `address` 	(string, required) 	the address for the private key
+    /// # Response
+    /// * `data` 	(string) 	the private key
+    /// %%%
+    pub fn dump_priv_key(&self, address: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("dumpprivkey");
+        let method_body = vec![json!(address)];
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_getoperationresult ([ "operationid", ... ])
-/// * The z_getoperationresult method retrieves the result and status of an operation which has finished, and then removes the operation from memory.
-/// * See also z_getoperationstatus.
-/// # Arguments
-/// * "operationid" 	(string, optional) 	a list of operation ids to query; if not provided, the method examines all operations known to the node
-/// # Response
-/// * "id" 	(string) 	the operation id
-/// * "status" 	(string) 	the result of the operation; can be success
-/// * "creation_time" 	(numeric) 	the creation time, in seconds since epoch (Jan 1 1970 GMT)
-/// * "result": { ... } 	(array of json objects)
-/// * "txid": 	(string) 	the transaction id
-/// * "execution_secs" 	(numeric) 	the length of time to calculate the transaction
-/// * "method" 	(string) 	the name of the method used in the operation
-/// * "params": { ... } 	(json)
-/// * "fromaddress" 	(string) 	the address from which funds are drawn
-/// * "amounts": [ ... ] 	(array of json objects)
-/// * "address" 	(string) 	the receiving address
-/// * "amount" 	(numeric) 	the amount to receive
-/// * "minconf" 	(numeric) 	the minimum number of confirmations required
-/// * "fee" 	(numeric) 	the transaction fee
-/// * %%%
-pub fn z_get_operation_result(
-    some_user: komodorpcutil::KomodoRPC,
-    operation_id: Option<String>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_getoperationresult");
-
-    let temp_operation_id: String = operation_id.unwrap_or("".to_string());
-    let method_body: String =
-        String::from("[[\"") + &temp_operation_id.to_string() + &String::from("\"]]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// The dumpwallet method dumps all transparent-address wallet keys into a file, using a human-readable format.
+    /// Overwriting an existing file is not permitted. The destination parameter accepts only alphanumeric characters.
+    /// This method requires that the coin daemon have the exportdir runtime parameter enabled.
+    /// # Arguments
+    /// * `filename`	(string, required)	the filename, saved in the folder set by the exportdir runtime parameter
+    /// # Response
+    /// * `path`	(string)	the full path of the destination file
+    /// %%%
+    pub fn dump_wallet(&self, filename: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("dumpwallet");
+        let method_body = vec![json!(filename)];
+        self.client.call(&method_name, method_body)
+    }
 
-// TODO: arrays as argument?
-
-/// * z_getoperationstatus ([ "operationid", ... ])
-/// * The z_getoperationstatus message queries the operation status and any associated result or error data of any operationid stored in local memory. The operation will remain in memory (unlike z_getoperationresult, which removes the data from the local memory).
-/// # Arguments
-/// * "operationid" 	(array, optional) 	a list of operation ids we are interested in; if an array is not provided, the method examines all operations known to the node
-/// # Response
-/// * "id" 	(string) 	the operation id
-/// * "status" 	(string) 	the status of the operation; can be success
-/// * "creation_time" 	(numeric) 	the creation time, in seconds since epoch (Jan 1 1970 GMT)
-/// * "error" : { ... } 	(array of json objects)
-/// * "code" 	(numeric) 	the associated error code
-/// * "message" 	(string) 	a message to indicate the nature of the error, if such a message is available
-/// * "method" 	(string) 	the name of the method used in the operation
-/// * "params" : { ... } 	(array of json objects)
-/// * "fromaddress" 	(string) 	the address from which funds are drawn
-/// * "amounts": [ ... ] 	(array of json objects)
-/// * "address" 	(string) 	the receiving address
-/// * "amount" 	(numeric) 	the amount to receive
-/// * "minconf" 	(numeric) 	indicates the required number of mining confirmations
-/// * "fee" 	(numeric) 	the fee
-/// * %%%
-pub fn z_get_operation_status(
-    some_user: komodorpcutil::KomodoRPC,
-    operation_id: Option<String>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_getoperationstatus");
-
-    let temp_operation_id: String = operation_id.unwrap_or("".to_string());
-    let method_body: String =
-        String::from("[[\"") + &temp_operation_id.to_string() + &String::from("\"]]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// Using the encryptwallet method will shutdown the Komodo daemon (komodod).
+    /// This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
+    /// The encryptwallet method encrypts the wallet with the indicated passphrase.
+    /// For more information, please see these instructions: Encrypt Komodo's wallet.dat File
+    /// This method is for first-time encryption only. After the first encryption, any calls that interact with private keys will require the passphrase via walletpassphrase prior to calling the corresponding method. This includes methods that create a transaction, dump a private key for an address, sign a transaction, etc.
+    /// # Arguments
+    /// * `passphrase`	(string)	the passphrase for wallet encryption; the passphrase must be at least 1 character, but should be many
+    /// # Response
+    /// * Text Response -
+    /// wallet encrypted; Komodo server stopping, restart to run with encrypted wallet. The keypool has been flushed, you need to make a new backup
+    /// %%%
+    pub fn encrypt_wallet(&self, passphrase: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("encryptwallet");
+        let method_body = vec![json!(passphrase)];
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_gettotalbalance ( minconf includeWatchonly )
-/// * The z_gettotalbalance method returns the total value of funds, including both transparent and private, stored in the node’s wallet.
-/// * CAUTION: If the wallet contains watch-only z addresses the returned private balance may be larger than the actual balance, as spends cannot be detected with incoming viewing keys.
-/// * While the interest property is returned for all KMD-based coin daemons, only the main KMD chain utilizes the interest feature. KMD-based Smart Chains will always return a 0.00 interest value.
-/// # Arguments
-/// * minconf 	(numeric, optional, default=1) 	only include private and transparent transactions confirmed at least this many times
-/// * includeWatchonly 	(bool, optional, default=false) 	also include balance in watchonly addresses (see 'importaddress' and 'z_importviewingkey')
-/// # Response
-/// * "transparent" 	(numeric) 	the total balance of transparent funds
-/// * "interest" 	(numeric) 	the total balance of unclaimed interest earned
-/// * "private" 	(numeric) 	the total balance of private funds
-/// * "total" 	(numeric) 	the total balance of both transparent and private funds
-/// * %%%
-pub fn z_get_total_balance(
-    some_user: komodorpcutil::KomodoRPC,
-    minconf: Option<u32>,
-    include_watch_only: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_gettotalbalance");
-    let temp_minconf = minconf.unwrap_or(1);
-    let temp_include_watch_only = include_watch_only.unwrap_or(false);
-    let method_body: String = String::from("[")
-        + &temp_minconf.to_string()
-        + &String::from(",")
-        + &temp_include_watch_only.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// The getaccount method returns the account associated with the given address.
+    /// # Arguments
+    /// * `address`	(string, required)	the address
+    /// # Response
+    /// * `accountname`	(string)	the account address
+    /// %%%
+    pub fn get_account(&self, address: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getaccount");
+        let method_body = vec![json!(address)];
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_importkey "z_privatekey" ( rescan startHeight )
-/// * The z_importkey method imports z_privatekey to your wallet.
-/// * This call can take minutes to complete if rescan is true.
-/// * The optional parameters are currently not functional with KMD-based blockchains.
-/// * See also z_exportkey.
-/// # Arguments
-/// * "z_privatekey" 	(string, required) 	the z_privatekey (see z_exportkey)
-/// * rescan 	(string, optional, default="whenkeyisnew") 	rescan the wallet for transactions; can be yes
-/// * startHeight 	(numeric, optional, default=0) 	the block height at which to begin the rescan
-/// # Response
-/// * (none)
-/// * %%%
-pub fn z_import_key(
-    some_user: komodorpcutil::KomodoRPC,
-    z_private_key: String,
-    rescan: Option<String>,
-    start_height: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_importkey");
-    let temp_rescan = rescan.unwrap_or("whenkeyisnew".to_string());
-    let temp_start_height = start_height.unwrap_or(0);
-    let method_body: String = String::from("[\"")
-        + &z_private_key.to_string()
-        + &String::from("\",\"")
-        + &temp_rescan.to_string()
-        + &String::from("\",")
-        + &temp_start_height.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// getbalance (`account" minconf includeWatchonly ).
+    /// The getbalance method returns the server's total available balance.
+    /// The account input is deprecated.
+    /// # Arguments
+    /// * `account`	(string, optional)	DEPRECATED if provided, it MUST be set to the empty string "" or to the string "*"
+    /// minconf	(numeric, optional, default=1)	only include transactions confirmed at least this many times
+    /// includeWatchonly	(bool, optional, default=false)	also include balance in watchonly addresses (see importaddress)
+    /// # Response
+    /// * amount	(numeric)	the total amount
+
+    pub fn get_balance(
+        &self, //TODO check def value and if conditions
+        minconf: Option<u32>,
+        includeWatchonly: Option<bool>,
+    ) -> Response<C, Amount>
+    where
+        C: Transport<Amount>,
+    {
+        let method_name: String = String::from("getbalance");
+        let temp_minconf = minconf.unwrap_or(1); //Default value is 1
+        let temp_includeWatchonly = includeWatchonly.unwrap_or(false);
+        // the deprecated account argument must be the empty string
+        let method_body = vec![json!(""), json!(temp_minconf), json!(temp_includeWatchonly)];
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_importviewingkey "viewing_key" ( rescan startHeight )
-/// * The z_importviewingkey adds a viewing key to your wallet. This method allows you to view the balance in a z address that otherwise does not belong to your wallet.
-/// * See also z_exportviewingkey.
-/// * This call can take minutes to complete if rescan is true.
-/// * The optional parameters are currently not functional for KMD-based blockchains.
-/// # Arguments
-/// * "viewing_key" 	(string, required) 	the viewing key
-/// * rescan 	(string, optional, default="whenkeyisnew") 	whether to rescan the wallet for transactions; can be "yes"
-/// * startHeight 	(numeric, optional, default=0) 	block height to start rescan
-/// # Response
-/// * (none)
-pub fn z_import_viewing_key(
-    some_user: komodorpcutil::KomodoRPC,
-    z_private_key: String,
-    rescan: Option<String>,
-    start_height: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_importviewingkey");
-    let temp_rescan = rescan.unwrap_or("whenkeyisnew".to_string());
-    let temp_start_height = start_height.unwrap_or(0);
-    let method_body: String = String::from("[\"")
-        + &z_private_key.to_string()
-        + &String::from("\",\"")
-        + &temp_rescan.to_string()
-        + &String::from("\",")
-        + &temp_start_height.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// getbalance64
+    /// The getbalance64 method is used only on Smart Chains that are utilizing the ac_staked functionality.
+    /// On KMD-based Proof-of-Stake (PoS) Smart Chains, all staked coins are placed into one of 64 segments (segid's').
+    /// The getbalance64 method returns the balance of coins in each segid.
+    /// # Arguments
+    /// * `(none)`
+    /// # Response
+    /// * "mature" 	(numeric) 	the balance of mature coins, which can stake
+    /// * "immature" 	(numeric) 	the balance of immature coins
+    /// * "staking" 	(array of numeric) 	the mature balance of each of the 64 segids
+    /// * "notstaking" 	(array of numeric) 	the immature balance of each of the 64 segids
+    /// %%%
+    pub fn get_balance64(&self) -> Response<C, Balance64>
+    where
+        C: Transport<Balance64>,
+    {
+        let method_name: String = String::from("getbalance64");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_importwallet "filename"
-/// * The z_importwallet method imports t address and z address keys from a wallet export file.
-/// * See also z_exportwallet.
-/// # Arguments
-/// * "filename" 	(string, required) 	the wallet file
-/// # Response
-/// * (none)
-/// * %%%
-pub fn z_import_wallet(
-    some_user: komodorpcutil::KomodoRPC,
-    file_name: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_importwallet");
-
-    let method_body: String = String::from("[\"") + &file_name.to_string() + &String::from("\"]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
-/// * z_listaddresses ( includeWatchonly )
-/// * The z_listaddresses method returns the list of z addresses belonging to the wallet.
-/// * See also z_importviewingkey.
-/// # Arguments
-/// * includeWatchonly 	(bool, optional, default=false) 	also include watchonly addresses
-/// # Response
-/// * "z_address" 	(string) 	a z address belonging to the wallet
-/// * %%%
-pub fn z_list_addresses(
-    some_user: komodorpcutil::KomodoRPC,
-    include_watch_only: Option<bool>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_listaddresses");
-    let temp_include_watch_only = include_watch_only.unwrap_or(false);
-    let method_body: String =
-        String::from("[") + &temp_include_watch_only.to_string() + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// getnewaddress ( "account" ).
+    /// The getnewaddress method returns a new address for receiving payments.
+    /// # Arguments
+    /// Name	Type	Description
+    /// * "account"	(string, optional)	DEPRECATED: If provided, the account MUST be set to the empty string "" to represent the default account; passing any other string will result in an error
+    /// # Response
+    /// Name	Type	Description
+    /// * "address"	(string)	the new address
+    /// %%%
+    pub fn get_new_address(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getnewaddress");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
 
-// TODO: handle if empty case
-
-/// * z_listoperationids
-/// * The z_listoperationids method returns the list of operation ids currently known to the wallet.
-/// # Arguments
-/// * "status" 	(string, optional) 	filter result by the operation's state e.g. "success"
-/// # Response
-/// * "operationid" 	(string) 	an operation id belonging to the wallet
-/// * %%%
-pub fn z_list_operation_ids(
-    some_user: komodorpcutil::KomodoRPC,
-    status: Option<String>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_listoperationids");
-    let temp_status = status.unwrap_or("".to_string());
-    let method_body: String = String::from("[") + &temp_status.to_string() + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        some_user.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(some_user.clone(), data)
-}
+    /// getrawchangeaddress
+    /// The getrawchangeaddress returns a new address that can be used to receive change.
+    /// This is for use with raw transactions, NOT normal use.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * "address"	(string)	the address
+    /// %%%
+    pub fn get_raw_change_address(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getrawchangeaddress");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_listreceivedbyaddress "address" ( minconf )
-/// * The z_listreceivedbyaddress method returns a list of amounts received by a z address belonging to the node’s wallet.
-/// # Arguments
-/// * address 	(string) 	the private address.
-/// * minconf 	(numeric, optional, default=1) 	only include transactions confirmed at least this many times
-/// # Result
-/// * An array of json objects, each having the properties below.
-/// * txid 	(string) 	the transaction id
-/// * amount 	(numeric) 	the amount of value in the note
-/// * memo 	(string) 	hexadecimal string representation of memo field
-/// * "confirmations" 	(numeric) 	a confirmation number that is aware of the dPoW security service
-/// * "rawconfirmations" 	(numeric) 	the raw confirmations (number of blocks on top of this transaction's block)
-/// * jsindex 	(sprout) 	(numeric, received only by sprout addresses) the joinsplit index
-/// * jsoutindex 	(numeric, received only by sprout addresses) 	the output index of the joinsplit
-/// * outindex 	(numeric, sapling) 	the output index
-/// * change 	(boolean) 	true if the address that received the note is also one of the sending addresses
-/// * %%%
-
-pub fn z_list_received_by_address(
-    SomeUser: komodorpcutil::KomodoRPC,
-    address: String,
-    min_conf: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_body: String;
-    method_body = String::from("[\"")
-        + &address.to_string()
-        + &String::from(" \",")
-        + &min_conf.unwrap_or(1).to_string()
-        + &String::from("]");
-    let method_name: String = String::from("z_listreceivedbyaddress");
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
-}
+    /// getreceivedbyaddress "address" ( minconf ).
+    /// The getreceivedbyaddress method returns the total amount received by the given address in transactions with at least minconf confirmations.
+    /// # Arguments
+    /// * "address"	(string, required)	the address for transactions
+    /// minconf  	(numeric, optional, default=1)	only include transactions confirmed at least this many times
+    /// # Response
+    /// * amount	(numeric)	the total amount of the relevant coin received at this address
+
+    pub fn get_receive_by_address(
+        &self,
+        address: String,
+        min_conf: Option<u32>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getreceivedbyaddress");
+        let temp_min_conf = min_conf.unwrap_or(1);
+        let method_body = vec![json!(address), json!(temp_min_conf)];
+        self.client.call(&method_name, method_body)
+    }
 
-/// * z_listunspent ( minconf maxconf includeWatchonly ["zaddr", ...] )
-/// * The z_listunspent method returns an array of unspent shielded notes.
-/// * The method can also filter to only include results that have between minconf and maxconf (inclusive) confirmations, and also for specified z_addresses (["zaddr", ...]).
-/// * When minconf is 0 unspent notes with zero confirmations are returned, even though they are not immediately spendable.
-/// * Results are an array of Objects, each of which has: {txid, jsindex, jsoutindex, confirmations, address, amount, memo} (Sprout) {txid, outindex, confirmations, address, amount, memo} (Sapling)
-/// # Arguments
-/// * minconf 	(numeric, optional, default=1) 	the minimum confirmations to filter
-/// * maxconf 	(numeric, optional, default=9999999) 	the maximum confirmations to filter
-/// * includeWatchonly 	(bool, optional, default=false) 	whether to also include watchonly addresses (see z_importviewingkey)
-/// * addresses 	(array) 	a json array of z addresses (both Sprout and Sapling) to act as a filter; duplicate addresses are not allowed
-/// * address 	(string) 	a z address
-/// # Results
-/// * An array of json objects, each having the properties below.
-/// * txid 	(string) 	the transaction id
-/// * jsindex 	(numeric) 	the joinsplit index
-/// * jsoutindex 	(numeric, only returned on sprout addresses) 	the output index of the joinsplit
-/// * outindex 	(numeric, only returned on sapling addresses) 	the output index
-/// * "confirmations" 	(numeric) 	a confirmation number that is aware of the dPoW security service
-/// * "rawconfirmations" 	(numeric) 	the raw confirmations (number of blocks on top of this transaction's block)
-/// * spendable 	(boolean) 	true if note can be spent by wallet, false if note has zero confirmations, false if address is watchonly
-/// * address 	(string) 	the shielded address
-/// * amount 	(numeric) 	the amount of value in the note
-/// * memo 	(string) 	hexadecimal string representation of memo field
-/// * change 	(boolean) 	true if the address that received the note is also one of the sending addresses/
-/// * %%%
-
-pub fn z_list_unspent(
-    SomeUser: komodorpcutil::KomodoRPC,
-    min_conf: Option<u32>,
-    max_conf: Option<u32>,
-    include_watch_only: Option<bool>,
-    addresses: Vec<String>,
-    address: String,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_listunspent");
-    let method_body: String;
-
-    let mut addr_list = String::from("[");
-
-    for addr in &addresses {
-        addr_list = addr_list + "\"" + addr + "\"" + &","; //parsing error
-    }
-    if (addresses.len() > 0)
-    // >1
+    /// gettransaction "txid" ( includeWatchonly ).
+    /// The gettransaction method queries detailed information about transaction txid. This command applies only to txid's that are in the user's local wallet.
+    /// # Arguments
+    /// * "txid"	(string, required)	the transaction id
+    /// * "includeWatchonly"	(bool, optional, default=false)	whether to include watchonly addresses in the returned balance calculation and in the details[] returned values
+    /// # Response
+    /// * "amount"	(numeric)	the transaction amount
+    /// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
+    /// * "rawconfirmations"	(numeric)	the raw confirmations (number of blocks on top of this transaction's block)
+    /// * "blockhash"	(string)	the block hash
+    /// * "blockindex"	(numeric)	the block index
+    /// * "blocktime"	(numeric)	the time in seconds since epoch (1 Jan 1970 GMT)
+    /// * "txid"	(string)	the transaction id
+    /// * "time"	(numeric)	the transaction time in seconds since epoch (1 Jan 1970 GMT)
+    /// * "timereceived"	(numeric)	the time received in seconds since epoch (1 Jan 1970 GMT)
+    /// * "details" : [ ... ]	(array)
+    /// * "account"	(string)	DEPRECATED the account name involved in the transaction; can be "" for the default account
+    /// * "address"	(string)	the address involved in the transaction
+    /// * "category"	(string)	the category - either send or receive
+    /// * "amount"	(numeric)	the amount
+    /// * "vout"	(numeric)	the vout value
+    /// * "vjoinsplit" : [ ... ]	(array of json objects)
+    /// * "anchor"	(string)	merkle root of note commitment tree
+    /// * "nullifiers" : [ ... ]	(array of strings)
+    /// * "hex"	(string)
+    /// * "commitments" : [ ... ]	(array of strings)
+    /// * "hex"	(string)
+    /// * "macs" : [ ... ]	(array of strings)
+    /// * "hex"	(string)
+    /// * "vpub_old"	(numeric)	the amount removed from the transparent value pool
+    /// * "vpub_new"	(numeric)	the amount added to the transparent value pool
+    /// * "hex"	(string)	transaction data translated into hex
+    /// %%%
+    pub fn get_transaction(
+        &self,
+        tx_id: String,
+        include_watch_only: Option<bool>,
+    ) -> Response<C, WalletTransaction>
+    where
+        C: Transport<WalletTransaction>,
     {
-        addr_list = addr_list[0..(addr_list.len() - 1)].to_string(); // cutting the last ,
-    }
-    addr_list = addr_list + &"]"; //& for String -> &string
-
-    method_body = String::from("[")
-        + &min_conf.unwrap_or(1).to_string()
-        + &String::from(",")
-        + &max_conf.unwrap_or(9999999).to_string()
-        + &String::from(",")
-        + &include_watch_only.unwrap_or(false).to_string()
-        + &String::from(",")
-        + &addr_list.to_string()
-        + &String::from(",")
-        + &address.to_string()
-        + &String::from("]");
-
-    komodorpcutil::request(
-        SomeUser.clone(),
-        komodorpcutil::generate_body(SomeUser.clone(), method_name, method_body),
-    )
-}
+        let method_name: String = String::from("gettransaction");
+        let temp_include_watch_only = include_watch_only.unwrap_or(false);
+        let method_body = vec![json!(tx_id), json!(temp_include_watch_only)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// getunconfirmedbalance.
+    /// The getunconfirmedbalance method returns the server's total unconfirmed balance.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * (none)
+    /// %%%
+    pub fn get_unconfirmed_balance(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("getunconfirmedbalance");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// The getwalletinfo method returns an object containing various information about the wallet state.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * "walletversion"	(numeric)	the wallet version
+    /// * "balance"	(numeric)	the total confirmed balance of the wallet
+    /// * "unconfirmed_balance"	(numeric)	the total unconfirmed balance of the wallet
+    /// * "immature_balance"	(numeric)	the total immature balance of the wallet
+    /// * "txcount"	(numeric)	the total number of transactions in the wallet
+    /// * "keypoololdest"	(numeric)	the timestamp (seconds since GMT epoch) of the oldest pre-generated key in the key pool
+    /// * "keypoolsize"	(numeric)	how many new keys are pre-generated
+    /// * "unlocked_until"	(numeric)	the timestamp in seconds since epoch (midnight Jan 1 1970 GMT) that the wallet is unlocked for transfers, or 0 if the wallet is locked
+    /// * "paytxfee"	(numeric)	the transaction fee configuration, given as the relevant COIN per KB
+    /// %%%
+
+    pub fn get_wallet_info(&self) -> Response<C, WalletInfo>
+    where
+        C: Transport<WalletInfo>,
+    {
+        let method_name: String = String::from("getwalletinfo");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// importaddress "address" ( "label" rescan ).
+    /// The importaddress method adds an address or script (in hex) that can be watched as if it were in your wallet, although it cannot be used to spend.
+    /// This call can take an increased amount of time to complete if rescan is true.
+    /// # Arguments
+    /// * "address"	(string, required)	the address to watch
+    /// * "label"	(string, optional, default="")	an optional label
+    /// * rescan	(boolean, optional, default=true)	rescan the wallet for transactions
+    /// # Response
+    /// * (none)
+    /// %%%
+
+    pub fn import_address(
+        &self, //TODO check def value and if conditions
+        address: String,
+        label: Option<String>,
+        rescan: Option<bool>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("importaddress");
+        // the label is always sent so that rescan stays in third position
+        let temp_label: String = label.unwrap_or("".to_string());
+        let temp_rescan = rescan.unwrap_or(true);
+        let method_body = vec![json!(address), json!(temp_label), json!(temp_rescan)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// importkey "komodoprivkey" ( "label" rescan ).
+    /// The importprivkey method adds a private key to your wallet.
+    /// This call can take minutes to complete if rescan is true.
+    /// See also dumpprivkey.
+    /// # Arguments
+    /// * "privkey"	(string, required)	the private key (see dumpprivkey)
+    /// * "label"	(string, optional, default="")	an optional label
+    /// * rescan	(boolean, optional, default=true)	rescan the wallet for transactions
+    /// # Response
+    /// * addresses	(string)	the public address
+    /// %%%
+    pub fn import_priv_key(
+        &self, //TODO check def value and if conditions
+        priv_key: String,
+        label: Option<String>,
+        rescan: Option<bool>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("importprivkey");
+        // the label is always sent so that rescan stays in third position
+        let temp_label: String = label.unwrap_or("".to_string());
+        let temp_rescan = rescan.unwrap_or(true);
+        let method_body = vec![json!(priv_key), json!(temp_label), json!(temp_rescan)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// importwallet "filename".
+    /// The importwallet method imports transparent-address keys from a wallet-dump file (see dumpwallet).
+    /// # Arguments
+    /// * "filename"	(string, required)	the wallet file
+    /// # Response
+    /// * (none)
+    ///  %%%
+
+    pub fn import_wallet(&self, file_name: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("importwallet");
+        let method_body = vec![json!(file_name)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// keypoolrefill ( newsize ).
+    /// The keypoolrefill method refills the keypool.
+    /// # Arguments
+    /// * newsize	(numeric, optional, default=100)	the new keypool size
+    /// # Response
+    /// * (none)
+    /// %%%
+    pub fn key_pool_refill(&self, new_size: Option<u32>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("keypoolrefill");
+        let temp_new_size = new_size.unwrap_or(100);
+        let method_body = vec![json!(temp_new_size)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// listaddressgroupings.
+    /// The listaddressgroupings method lists groups of addresses which have had their common ownership made public by common use as inputs or as the resulting change in past transactions.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * "address",	(string)	the address
+    /// * amount,	(numeric)	the amount
+    /// * "account"	(string, optional)	(DEPRECATED) the account
+    ///  %%%
+
+    pub fn list_address_groupings(&self) -> Response<C, Vec<Vec<AddressGrouping>>>
+    where
+        C: Transport<Vec<Vec<AddressGrouping>>>,
+    {
+        let method_name: String = String::from("listaddressgroupings");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// listlockunspent.
+    /// The listlockunspent method returns a list of temporarily non-spendable outputs.
+    /// See the lockunspent call to lock and unlock transactions for spending.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * "txid"	(string)	the transaction id locked
+    /// * "vout"	(numeric)	the vout value
+
+    pub fn list_lock_unspent(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("listlockunspent");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// listreceivedbyaddress ( minconf includeempty includeWatchonly).
+    /// The listreceivedbyaddress method lists balances by receiving address.
+    /// # Arguments
+    /// * minconf	(numeric, optional, default=1)	the minimum number of confirmations before payments are included
+    /// * includeempty	(numeric, optional, default=false)	whether to include addresses that haven't received any payments// TO DO must be bool
+    /// * includeWatchonly	(bool, optional, default=false)	whether to include watchonly addresses (see 'importaddress')
+    /// # Response
+    /// * "involvesWatchonly"	(bool)	only returned if imported addresses were involved in transaction
+    /// * "address"	(string)	the receiving address
+    /// * "account"	(string)	DEPRECATED the account of the receiving address; the default account is ""
+    /// * "amount"	(numeric)	the total amount received by the address
+    /// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
+    /// * "rawconfirmations"	(numeric)	the raw confirmations of the most recent transaction included (number of blocks on top of this transaction's block
+    /// %%%
+
+    pub fn list_received_by_address(
+        &self,
+        min_conf: Option<u32>,
+        include_empty: Option<bool>,
+        include_watch_only: Option<bool>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("listreceivedbyaddress");
+        let temp_min_conf = min_conf.unwrap_or(1);
+        let temp_include_empty = include_empty.unwrap_or(false);
+        let temp_include_watch_only = include_watch_only.unwrap_or(false);
+        let method_body = vec![
+            json!(temp_min_conf),
+            json!(temp_include_empty),
+            json!(temp_include_watch_only),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    // TODO COMPLETE
+
+    /// listsinceblock ( "blockhash" target-confirmations includeWatchonly ).
+    /// The listsinceblock method queries all transactions in blocks since block blockhash, or all transactions if blockhash is omitted.
+    /// # Arguments
+    /// * "blockhash"	(string, optional)	the block hash from which to list transactions
+    /// * target-confirmations	(numeric, optional)	the confirmations required (must be 1 or more)
+    /// * includeWatchonly	(bool, optional, default=false)	include transactions to watchonly addresses (see also 'importaddress')
+    /// # Response
+    /// * "transactions":
+    /// * "account"	(string)	DEPRECATED the account name associated with the transaction; will be "" for the default account
+    /// * "address"	(string)	the address of the transaction (not present for move transactions -- category = move)
+    /// * "category"	(string)	the transaction category; send has negative amounts, receive has positive amounts
+    /// * "amount"	(numeric)	the amount of the relevant currency -- negative for the send category, and for the move category for moves outbound. It is positive for the receive category, and for the move category for inbound funds.
+    /// * "vout"	(numeric)	the vout value
+    /// * "fee"	(numeric)	the amount of the fee; this value is negative and only available for the send category of transactions
+    /// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
+    /// * "rawconfirmations"	(numeric)	the raw confirmations of the transaction; available for send and receive category of transactions (number of blocks on top of this transaction's block)
+    /// * "blockhash"	(string)	the block hash containing the transaction; available for the send and receive categories of transactions
+    /// * "blockindex"	(numeric)	the block index containing the transaction; available for the send and receive categories of transactions
+    /// * "blocktime"	(numeric)	the block time in seconds since epoch (1 Jan 1970 GMT)
+    /// * "txid"	(string)	the transaction id; available for send and receive categories of transactions
+    /// * "time"	(numeric)	the transaction time in seconds since epoch (Jan 1 1970 GMT)
+    /// * "timereceived"	(numeric)	the time received in seconds since epoch (Jan 1 1970 GMT); available for send and receive category of transactions
+    /// * "comment"	(string)	whether a comment is associated with the transaction
+    /// * "to"	(string)	whether a 'to' comment is associated with the transaction
+    /// * "lastblock"	(string)	the hash of the last block
+    /// %%%
+
+    pub fn list_since_block(
+        &self,
+        block_hash: Option<String>,
+        target_conformations: Option<u32>,
+        include_watch_only: Option<bool>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("listsinceblock");
+        let temp_block_hash: String = block_hash.unwrap_or("".to_string());
+        let temp_target_conformations = target_conformations.unwrap_or(1);
+        let temp_watch_only = include_watch_only.unwrap_or(false);
+        let method_body = vec![
+            json!(temp_block_hash),
+            json!(temp_target_conformations),
+            json!(temp_watch_only),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    // TODO
+
+    /// The listtransactions method returns up to count most recent transactions skipping the first from transactions for account.
+    /// # Arguments
+    /// "account"       	(string, optional)            	DEPRECATED the account name; should be "*"
+    /// * count	            (numeric, optional, default=10)	the number of transactions to return
+    /// * from	            (numeric, optional, default=0)	the number of transactions to skip
+    /// includeWatchonly	(bool, optional, default=false)	include transactions to watchonly addresses (see importaddress)
+    /// # Response
+    /// * "account"	(string)	DEPRECATED the account name associated with the transaction; it will be "" for the default account
+    /// * "address"	(string)	the address of the transaction; not present for move transactions (category = move)
+    /// * "category"	(string)	The transaction category. This property can be send
+    /// * "amount"	(numeric)	The amount. This value is negative for the send category, and for the move category for moves outbound. It is positive for the receive category and for the move category for inbound funds.
+    /// * "vout"	(numeric)	the vout value
+    /// * "fee"	(numeric)	the amount of the fee; this is negative and only available for the send category of transactions
+    /// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
+    /// * "rawconfirmations"	(numeric)	the raw confirmations of the transaction; available for send and receive category of transactions (number of blocks on top of this transaction's block)
+    /// * "blockhash"	(string)	the block hash containing the transaction; available for the send and receive categories of transactions
+    /// * "blockindex"	(numeric)	the block index containing the transaction; available for the send and receive categories of transactions
+    /// * "txid"	(string)	the transaction id; available for the send and receive categories of transactions
+    /// * "time"	(numeric)	the transaction time in seconds since epoch (midnight Jan 1 1970 GMT)
+    /// * "timereceived"	(numeric)	the time received in seconds since epoch (midnight Jan 1 1970 GMT); available for the send and receive categories of transactions
+    /// * "comment"	(string)	whether a comment is associated with the transaction
+    /// * "otheraccount"	(string)	for the move category of transactions; indicates the account which sent the funds (for receiving funds, positive amounts), or went to (for sending funds, negative amounts)
+    /// * "size"	(numeric)	transaction size in bytes
+    /// * %%%
+
+    pub fn list_transactions(
+        &self,
+        accoount: Option<String>,
+        count: Option<u32>,
+        from: Option<u32>,
+        include_watch_only: Option<bool>,
+    ) -> Response<C, Vec<ListTransactionsEntry>>
+    where
+        C: Transport<Vec<ListTransactionsEntry>>,
+    {
+        let method_name: String = String::from("listtransactions");
+        // the deprecated account argument must be "*"
+        let method_body = vec![
+            json!("*"),
+            json!(count.unwrap_or(10)),
+            json!(from.unwrap_or(0)),
+            json!(include_watch_only.unwrap_or(false)),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    //TODO
+    /*
+    /// * listunspent ( minconf maxconf ["address", ... ] ).
+    /// * The listunspent method returns an array of unspent transaction outputs, with a range between minconf and maxconf (inclusive) confirmations. The method can, optionally, filter to only include txouts paid to specified addresses.
+    /// # Arguments
+    /// * minconf	(numeric, optional, default=1)	the minimum confirmations to filter
+    /// * maxconf	(numeric, optional, default=9999999)	the maximum confirmations to filter
+    /// * "address"	(string)	a series of addresses
+    ///  # Response
+    /// * "txid"	(string)	the transaction id
+    /// * "vout"	(numeric)	the vout value
+    /// * "generated"	(boolean)	true if txout is a coinbase transaction output
+    /// * "address"	(string)	the address
+    /// * "account"	(string)	DEPRECATED the associated account, or "" for the default account
+    /// * "scriptPubKey"	(string)	the script key
+    /// * "amount"	(numeric)	the transaction amount
+    /// * "confirmations"	(numeric)	a confirmation number that is aware of the dPoW security service
+    /// * "rawconfirmations"	(numeric)	the raw confirmations (number of blocks on top of this transaction's block)
+    /// * %%%
+     */
+    pub fn list_unspent(
+        &self,
+        minconf: Option<u32>,
+        maxconf: Option<u32>,
+        address: String,
+    ) -> Response<C, Vec<Unspent>>
+    where
+        C: Transport<Vec<Unspent>>,
+    {
+        let method_name: String = String::from("listunspent");
+        let temp_minconf = minconf.unwrap_or(1);
+        let temp_maxconf = maxconf.unwrap_or(9999999);
+        let mut temp_addresses = Vec::new();
+        if !address.is_empty() {
+            temp_addresses.push(address);
+        }
+        let method_body = vec![
+            json!(temp_minconf),
+            json!(temp_maxconf),
+            json!(temp_addresses),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    //  TODO - unfinished
+
+    /// * lockunspent unlock [{ "txid": "txid", "vout": n }, ... ].
+    /// * The lockunspent method locks (unlock = false) or unlocks (unlock = true) specified transaction outputs. A locked transaction output will not be chosen by automatic coin selection, when spending the relevant coin. The locks are stored in memory only; at runtime a node always starts with zero locked outputs, and the locked output list is always cleared when a node stops or fails.
+    /// * See the listunspent and listlockunspent calls to determine local transaction state and info.
+    /// # Arguments
+    /// * unlock	(boolean, required)	whether to unlock (true) or lock (false) the specified transactions
+    /// * "txid"	(string)	the transaction id
+    /// * "vout"	(numeric)	the output number
+    /// # Response
+    /// * true/false	(boolean)	whether the command was successful
+    /// * %%%
+
+    pub fn lock_unspent(&self, unlock: bool, txid: String, vout: u32) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("lockunspent");
+        let method_body = vec![json!(unlock), json!([OutPoint { txid, vout }])];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * resendwallettransactions.
+    /// * The resendwallettransactions method immediately re-broadcasts unconfirmed wallet transactions to all peers. This method is intended only for testing; the wallet code periodically re-broadcasts automatically.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * "transaction_id"	(string)	an array of the rebroadcasted transaction id's
+    /// * %%%  
+
+    pub fn resend_wallet_transactions(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("resendwallettransactions");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * sendmany "account" { "address": amount, ... } ( minconf "comment" [ "address", ... ] ).
+    /// * The sendmany method can send multiple transactions at once. Amounts are exact to the satoshi (see Amount).
+    /// # Arguments
+    /// * "account" 	(string, required) 	always sent as the empty string "", the default account; the daemon rejects any other account
+    /// * "amounts" { "address":amount, ... } 	(map of Address to Amount) 	the addresses and the value to send to each of them
+    /// * minconf 	(numeric, optional, default=1) 	only use the balance confirmed at least this many times
+    /// * "comment" 	(string, optional) 	a comment
+    /// * subtract_fee_from 	(list of Address) 	the fee will be equally deducted from the amount of each listed address; the recipients will receive less than you enter in their corresponding amount field. If the list is empty, the sender pays the fee.
+    /// # Response
+    /// * "transaction_id" 	(string) 	the transaction id for the send; only 1 transaction is created regardless of the number of addresses
+    /// # Examples
+    /// ```
+    /// let mut amounts = BTreeMap::new();
+    /// amounts.insert("RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse()?, "1.5".parse()?);
+    /// amounts.insert("RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp".parse()?, "0.25".parse()?);
+    /// let txid = client.wallet().send_many(amounts, None, None, Vec::new())?;
+    /// ```
+    /// * %%%
+    pub fn send_many(
+        &self,
+        amounts: BTreeMap<Address, Amount>,
+        minconf: Option<u32>,
+        comment: Option<String>,
+        subtract_fee_from: Vec<Address>,
+    ) -> Response<C, String>
+    where
+        C: Transport<String>,
+    {
+        let method_name: String = String::from("sendmany");
+        let temp_minconf = minconf.unwrap_or(1);
+        let temp_comment: String = comment.unwrap_or("".to_string());
+        let method_body = vec![
+            json!(""),
+            json!(amounts),
+            json!(temp_minconf),
+            json!(temp_comment),
+            json!(subtract_fee_from),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * sendtoaddress "address" amount ( "comment" "comment-to" subtractfeefromamount )
+    /// *
+    /// * The sendtoaddress method sends an amount to a given address. The amount is exact to the satoshi (see Amount).
+    /// # Arguments
+    /// * "komodoaddress" 	(Address, required) 	the receiving address, checked before it is sent (see Address)
+    /// * "amount" 	(numeric, required) 	the amount to send (json requires all decimals values less than 1 begin with the characters '0.')
+    /// * "comment" 	(string, optional) 	a comment used to store what the transaction is for; this is not part of the transaction, just kept in your wallet
+    /// * "comment-to" 	(string, optional) 	a comment to store the name of the person or organization to which you're sending the transaction; this is stored in your local wallet file only
+    /// * subtractfeefromamount 	(boolean, optional, default=false) 	when true, the fee will be deducted from the amount being sent
+    /// # Response
+    /// * "transaction_id" 	(string) 	the transaction id
+
+    pub fn send_to_address(
+        &self,
+        komodo_address: Address,
+        amount: Amount,
+        comment: Option<String>,
+        comment_to: Option<String>,
+        subtract_fee_from_amount: Option<bool>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("sendtoaddress");
+        // empty comments are ignored by the daemon, so every position is always filled
+        let temp_comment: String = comment.unwrap_or("".to_string());
+        let temp_comment_to: String = comment_to.unwrap_or("".to_string());
+        let temp_subtract_fee_from_amount = subtract_fee_from_amount.unwrap_or(false);
+        let method_body = vec![
+            json!(komodo_address),
+            json!(amount),
+            json!(temp_comment),
+            json!(temp_comment_to),
+            json!(temp_subtract_fee_from_amount),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+    /// * setpubkey pubkey.
+    /// * The setpubkey method sets the indicated pubkey. This method can be used in place of the pubkey launch parameter, when necessary.
+    /// * Visit the section pubkey to understand when it is essential to set a pubkey and the consequences of setting it.
+    /// * This method works only once per daemon start. It can't be used to change the pubkey that has already been set.
+    /// # Arguments
+    /// * pubkey 	(string) 	the desired pubkey
+    /// # Response
+    /// * pubkey 	(string) 	the pubkey
+    /// * ismine 	(boolean) 	indicates whether the address belongs to the user
+    /// * R-address 	(string) 	the public address associated with the pubkey
+    /// * %%%
+    pub fn set_pub_key(&self, pub_key: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("setpubkey");
+        let method_body = vec![json!(pub_key)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * settxfee amount
+    /// * The settxfee method sets the transaction fee per kB.
+    /// * # Arguments
+    /// * amount 	(numeric, required) 	the transaction fee in COIN/kB rounded to the nearest 0.00000001
+    /// * # Response
+    /// * true/false 	(boolean) 	returns true if successful
+    /// * %%%
+    pub fn set_tx_fee(&self, amount: Amount) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("settxfee");
+        let method_body = vec![json!(amount)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * signmessage "address" "message"
+    /// * The signmessage method signs a message via the private key of an address.
+    /// # Arguments
+    /// * "address" 	(string, required) 	the address to use for the private key
+    /// * "message" 	(string, required) 	the message
+    /// # Response
+    /// * "signature" 	(string) 	the signature of the message encoded in base 64
+
+    pub fn sign_message(&self, address: String, message: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("signmessage");
+        let method_body = vec![json!(address), json!(message)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * walletlock
+    /// * The walletlock method is neither active nor visible in the help method until the encryptwallet passphrase is set.
+    /// * This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
+    /// * The walletlock method re-locks a wallet that has a passphrase enabled via encryptwallet.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * (none)
+
+    pub fn wallet_lock(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("walletlock");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+    /// * walletpassphrase "passphrase" (timeout)
+    /// * The walletpassphrase method is neither active nor visible in the help method until the encryptwallet passphrase is set.
+    /// * This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
+    /// * The walletpassphrase method unlocks the wallet using the passphrase that was set by the encryptwallet method.
+    /// * The timeout argument can be included to limit the length of time (in seconds) the wallet will remain unlocked.
+    /// # Arguments
+    /// * "passphrase" 	(string) 	the passphrase that was set by the encryptwallet method
+    /// * timeout 	(number in seconds, optional) 	the amount of time for which the wallet should remember the passphrase
+    /// # Response
+    /// * (none)
+    /// * %%%
+
+    pub fn wallet_pass_phrase(
+        &self,
+        pass_phrase: String,
+        timeout: Option<f64>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("walletpassphrase");
+        let mut method_body = vec![json!(pass_phrase)];
+        if let Some(x) = timeout {
+            method_body.push(json!(x));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * walletpassphrasechange "oldpassphrase" "newpassphrase"
+    /// * The walletpassphrasechange method is neither active nor visible in the help method until the encryptwallet passphrase is set.
+    /// * This feature is available only on chains where -ac_public is enabled. Chains that feature private transactions cannot use this feature.
+    /// * The walletpassphrasechange method changes "oldpassphrase" to "newpassphrase".
+    /// # Arguments
+    /// * "oldpassphrase" 	(string) 	the old passphrase
+    /// * "newpassphrase" 	(string) 	the new passphrase
+    /// # Response
+    /// * (none)
+    /// * %%%
+    pub fn wallet_pass_phrase_change(
+        &self,
+        old_pass_phrase: String,
+        new_pass_phrase: String,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("walletpassphrasechange");
+        let method_body = vec![json!(old_pass_phrase), json!(new_pass_phrase)];
+        self.client.call(&method_name, method_body)
+    }
+    /// * z_exportkey "z_address"
+    /// * The z_exportkey method reveals the private z_key corresponding to z_address.
+    /// * See also z_importkey.
+    /// # Arguments
+    /// * "z_address" 	(string, required) 	the z_address for the private key
+    /// # Response
+    /// * "key" 	(string) 	the private key
+    /// * %%%
+    pub fn z_export_key(&self, z_address: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_exportkey");
+        let method_body = vec![json!(z_address)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_exportviewingkey "z_address"
+    /// * The z_exportviewingkey method reveals the viewing key corresponding to z_address.
+    /// * See also z_importviewingkey.
+    /// # Arguments
+    /// * "z_address" 	(string, required) 	the z_address for the viewing key
+    /// # Response
+    /// * "vkey" 	(string) 	the viewing key
+    /// * %%%
+
+    pub fn z_export_viewing_key(&self, z_address: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_exportviewingkey");
+        let method_body = vec![json!(z_address)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_exportwallet "filename"
+    /// * The z_exportwallet method exports all wallet keys, including both t address and z address types, in a human-readable format. Overwriting an existing file is not permitted.
+    /// # Arguments
+    /// * "filename" 	(string, required) 	the filename, saved to the directory indicated by the exportdir parameter at daemon runtime (required)
+    /// # Response
+    /// * "path" 	(string) 	the full path of the destination file
+    /// * %%%
+    pub fn z_export_wallet(&self, file_name: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_exportwallet");
+        let method_body = vec![json!(file_name)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_getbalance "address" ( minconf )
+    /// * The z_getbalance method returns the balance of a t address or z address belonging to the node’s wallet.
+    /// * CAUTION: If address is a watch-only z address, the returned balance may be larger than the actual balance, as spends cannot be detected with incoming viewing keys.
+    /// # Arguments
+    /// * "address" 	(string) 	the selected z or t address
+    /// * minconf 	(numeric, optional, default=1) 	only include transactions confirmed at least this many times
+    /// # Response
+    /// * amount 	(numeric) 	the total amount received at this address (in the relevant COIN value)
+    pub fn z_get_balance(&self, address: String, minconf: Option<u32>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_getbalance");
+        let temp_minconf = minconf.unwrap_or(1);
+        let method_body = vec![json!(address), json!(temp_minconf)];
+        self.client.call(&method_name, method_body)
+    }
+    /// * z_getnewaddress
+    /// * The z_getnewaddress method returns a new z_address for receiving payments.
+    /// # Arguments
+    /// * (none)
+    /// # Response
+    /// * "z_address" 	(string) 	the new z_address
+
+    pub fn z_get_new_address(&self) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_getnewaddress");
+        let method_body = Vec::new();
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_getnotescount ( minconf )
+    /// * The z_getnotescount method returns the number of sprout and sapling notes available in the wallet.
+    /// # Arguments
+    /// * minconf 	(numeric, optional, default=1) 	only include notes in transactions confirmed at least this many times
+    /// # Response
+    /// * "sprout" 	(numeric) 	the number of sprout notes in the wallet
+    /// * "sapling" 	(numeric) 	the number of sapling notes in the wallet
+    /// * %%%
+    pub fn z_get_notes_count(&self, minconf: Option<u32>) -> Response<C, NotesCount>
+    where
+        C: Transport<NotesCount>,
+    {
+        let method_name: String = String::from("z_getnotescount");
+        let method_body = vec![json!(minconf.unwrap_or(1))];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_getoperationresult ([ "operationid", ... ])
+    /// * The z_getoperationresult method retrieves the result and status of an operation which has finished, and then removes the operation from memory.
+    /// * See also z_getoperationstatus.
+    /// # Arguments
+    /// * "operationid" 	(string, optional) 	a list of operation ids to query; if not provided, the method examines all operations known to the node
+    /// # Response
+    /// * "id" 	(string) 	the operation id
+    /// * "status" 	(string) 	the result of the operation; can be success
+    /// * "creation_time" 	(numeric) 	the creation time, in seconds since epoch (Jan 1 1970 GMT)
+    /// * "result": { ... } 	(array of json objects)
+    /// * "txid": 	(string) 	the transaction id
+    /// * "execution_secs" 	(numeric) 	the length of time to calculate the transaction
+    /// * "method" 	(string) 	the name of the method used in the operation
+    /// * "params": { ... } 	(json)
+    /// * "fromaddress" 	(string) 	the address from which funds are drawn
+    /// * "amounts": [ ... ] 	(array of json objects)
+    /// * "address" 	(string) 	the receiving address
+    /// * "amount" 	(numeric) 	the amount to receive
+    /// * "minconf" 	(numeric) 	the minimum number of confirmations required
+    /// * "fee" 	(numeric) 	the transaction fee
+    /// * %%%
+    pub fn z_get_operation_result(
+        &self,
+        operation_id: Option<String>,
+    ) -> Response<C, Vec<OperationStatus>>
+    where
+        C: Transport<Vec<OperationStatus>>,
+    {
+        let method_name: String = String::from("z_getoperationresult");
+        let mut method_body = Vec::new();
+        if let Some(temp_operation_id) = operation_id {
+            method_body.push(json!([temp_operation_id]));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_getoperationstatus ([ "operationid", ... ])
+    /// * The z_getoperationstatus message queries the operation status and any associated result or error data of any operationid stored in local memory. The operation will remain in memory (unlike z_getoperationresult, which removes the data from the local memory).
+    /// # Arguments
+    /// * "operationid" 	(array, optional) 	a list of operation ids we are interested in; if an array is not provided, the method examines all operations known to the node
+    /// # Response
+    /// * "id" 	(string) 	the operation id
+    /// * "status" 	(string) 	the status of the operation; can be success
+    /// * "creation_time" 	(numeric) 	the creation time, in seconds since epoch (Jan 1 1970 GMT)
+    /// * "error" : { ... } 	(array of json objects)
+    /// * "code" 	(numeric) 	the associated error code
+    /// * "message" 	(string) 	a message to indicate the nature of the error, if such a message is available
+    /// * "method" 	(string) 	the name of the method used in the operation
+    /// * "params" : { ... } 	(array of json objects)
+    /// * "fromaddress" 	(string) 	the address from which funds are drawn
+    /// * "amounts": [ ... ] 	(array of json objects)
+    /// * "address" 	(string) 	the receiving address
+    /// * "amount" 	(numeric) 	the amount to receive
+    /// * "minconf" 	(numeric) 	indicates the required number of mining confirmations
+    /// * "fee" 	(numeric) 	the fee
+    /// * %%%
+    pub fn z_get_operation_status(
+        &self,
+        operation_id: Option<String>,
+    ) -> Response<C, Vec<OperationStatus>>
+    where
+        C: Transport<Vec<OperationStatus>>,
+    {
+        let method_name: String = String::from("z_getoperationstatus");
+        let mut method_body = Vec::new();
+        if let Some(temp_operation_id) = operation_id {
+            method_body.push(json!([temp_operation_id]));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_gettotalbalance ( minconf includeWatchonly )
+    /// * The z_gettotalbalance method returns the total value of funds, including both transparent and private, stored in the node’s wallet.
+    /// * CAUTION: If the wallet contains watch-only z addresses the returned private balance may be larger than the actual balance, as spends cannot be detected with incoming viewing keys.
+    /// * While the interest property is returned for all KMD-based coin daemons, only the main KMD chain utilizes the interest feature. KMD-based Smart Chains will always return a 0.00 interest value.
+    /// # Arguments
+    /// * minconf 	(numeric, optional, default=1) 	only include private and transparent transactions confirmed at least this many times
+    /// * includeWatchonly 	(bool, optional, default=false) 	also include balance in watchonly addresses (see 'importaddress' and 'z_importviewingkey')
+    /// # Response
+    /// * "transparent" 	(numeric) 	the total balance of transparent funds
+    /// * "interest" 	(numeric) 	the total balance of unclaimed interest earned
+    /// * "private" 	(numeric) 	the total balance of private funds
+    /// * "total" 	(numeric) 	the total balance of both transparent and private funds
+    /// * %%%
+    pub fn z_get_total_balance(
+        &self,
+        minconf: Option<u32>,
+        include_watch_only: Option<bool>,
+    ) -> Response<C, ShieldedBalance>
+    where
+        C: Transport<ShieldedBalance>,
+    {
+        let method_name: String = String::from("z_gettotalbalance");
+        let temp_minconf = minconf.unwrap_or(1);
+        let temp_include_watch_only = include_watch_only.unwrap_or(false);
+        let method_body = vec![json!(temp_minconf), json!(temp_include_watch_only)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_importkey "z_privatekey" ( rescan startHeight )
+    /// * The z_importkey method imports z_privatekey to your wallet.
+    /// * This call can take minutes to complete if rescan is true.
+    /// * The optional parameters are currently not functional with KMD-based blockchains.
+    /// * See also z_exportkey.
+    /// # Arguments
+    /// * "z_privatekey" 	(string, required) 	the z_privatekey (see z_exportkey)
+    /// * rescan 	(string, optional, default="whenkeyisnew") 	rescan the wallet for transactions; can be yes
+    /// * startHeight 	(numeric, optional, default=0) 	the block height at which to begin the rescan
+    /// # Response
+    /// * (none)
+    /// * %%%
+    pub fn z_import_key(
+        &self,
+        z_private_key: String,
+        rescan: Option<String>,
+        start_height: Option<u32>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_importkey");
+        let temp_rescan = rescan.unwrap_or("whenkeyisnew".to_string());
+        let temp_start_height = start_height.unwrap_or(0);
+        let method_body = vec![
+            json!(z_private_key),
+            json!(temp_rescan),
+            json!(temp_start_height),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_importviewingkey "viewing_key" ( rescan startHeight )
+    /// * The z_importviewingkey adds a viewing key to your wallet. This method allows you to view the balance in a z address that otherwise does not belong to your wallet.
+    /// * See also z_exportviewingkey.
+    /// * This call can take minutes to complete if rescan is true.
+    /// * The optional parameters are currently not functional for KMD-based blockchains.
+    /// # Arguments
+    /// * "viewing_key" 	(string, required) 	the viewing key
+    /// * rescan 	(string, optional, default="whenkeyisnew") 	whether to rescan the wallet for transactions; can be "yes"
+    /// * startHeight 	(numeric, optional, default=0) 	block height to start rescan
+    /// # Response
+    /// * (none)
+    pub fn z_import_viewing_key(
+        &self,
+        z_private_key: String,
+        rescan: Option<String>,
+        start_height: Option<u32>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_importviewingkey");
+        let temp_rescan = rescan.unwrap_or("whenkeyisnew".to_string());
+        let temp_start_height = start_height.unwrap_or(0);
+        let method_body = vec![
+            json!(z_private_key),
+            json!(temp_rescan),
+            json!(temp_start_height),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_importwallet "filename"
+    /// * The z_importwallet method imports t address and z address keys from a wallet export file.
+    /// * See also z_exportwallet.
+    /// # Arguments
+    /// * "filename" 	(string, required) 	the wallet file
+    /// # Response
+    /// * (none)
+    /// * %%%
+    pub fn z_import_wallet(&self, file_name: String) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_importwallet");
+        let method_body = vec![json!(file_name)];
+        self.client.call(&method_name, method_body)
+    }
+    /// * z_listaddresses ( includeWatchonly )
+    /// * The z_listaddresses method returns the list of z addresses belonging to the wallet.
+    /// * See also z_importviewingkey.
+    /// # Arguments
+    /// * includeWatchonly 	(bool, optional, default=false) 	also include watchonly addresses
+    /// # Response
+    /// * "z_address" 	(string) 	a z address belonging to the wallet
+    /// * %%%
+    pub fn z_list_addresses(&self, include_watch_only: Option<bool>) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_listaddresses");
+        let temp_include_watch_only = include_watch_only.unwrap_or(false);
+        let method_body = vec![json!(temp_include_watch_only)];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_listoperationids
+    /// * The z_listoperationids method returns the list of operation ids currently known to the wallet.
+    /// # Arguments
+    /// * "status" 	(string, optional) 	filter result by the operation's state e.g. "success"
+    /// # Response
+    /// * "operationid" 	(string) 	an operation id belonging to the wallet
+    /// * %%%
+    pub fn z_list_operation_ids(&self, status: Option<String>) -> Response<C, Vec<String>>
+    where
+        C: Transport<Vec<String>>,
+    {
+        let method_name: String = String::from("z_listoperationids");
+        let mut method_body = Vec::new();
+        if let Some(temp_status) = status {
+            method_body.push(json!(temp_status));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_listreceivedbyaddress "address" ( minconf )
+    /// * The z_listreceivedbyaddress method returns a list of amounts received by a z address belonging to the node’s wallet.
+    /// # Arguments
+    /// * address 	(string) 	the private address.
+    /// * minconf 	(numeric, optional, default=1) 	only include transactions confirmed at least this many times
+    /// # Result
+    /// * An array of json objects, each having the properties below.
+    /// * txid 	(string) 	the transaction id
+    /// * amount 	(numeric) 	the amount of value in the note
+    /// * memo 	(string) 	hexadecimal string representation of memo field
+    /// * "confirmations" 	(numeric) 	a confirmation number that is aware of the dPoW security service
+    /// * "rawconfirmations" 	(numeric) 	the raw confirmations (number of blocks on top of this transaction's block)
+    /// * jsindex 	(sprout) 	(numeric, received only by sprout addresses) the joinsplit index
+    /// * jsoutindex 	(numeric, received only by sprout addresses) 	the output index of the joinsplit
+    /// * outindex 	(numeric, sapling) 	the output index
+    /// * change 	(boolean) 	true if the address that received the note is also one of the sending addresses
+    /// * %%%
+
+    pub fn z_list_received_by_address(
+        &self,
+        address: String,
+        min_conf: Option<u32>,
+    ) -> Response<C, Vec<ReceivedNote>>
+    where
+        C: Transport<Vec<ReceivedNote>>,
+    {
+        let method_name: String = String::from("z_listreceivedbyaddress");
+        let method_body = vec![json!(address), json!(min_conf.unwrap_or(1))];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_listsentbyaddress "address" ( minconf )
+    /// * The z_listsentbyaddress method returns a list of notes sent from a z address belonging to the node’s wallet.
+    /// # Arguments
+    /// * address 	(string) 	the private address
+    /// * minconf 	(numeric, optional, default=1) 	only include transactions confirmed at least this many times
+    /// # Result
+    /// * An array of json objects, each having the properties below.
+    /// * txid 	(string) 	the transaction id
+    /// * address 	(string) 	the receiving address
+    /// * amount 	(numeric) 	the amount of value in the note
+    /// * memo 	(string) 	hexadecimal string representation of memo field
+    /// * "confirmations" 	(numeric) 	a confirmation number that is aware of the dPoW security service
+    /// * outindex 	(numeric, sapling) 	the output index
+    /// * change 	(boolean) 	true if the note returns change to the sending address
+    /// * %%%
+    pub fn z_list_sent_by_address(
+        &self,
+        address: String,
+        min_conf: Option<u32>,
+    ) -> Response<C, Vec<SentNote>>
+    where
+        C: Transport<Vec<SentNote>>,
+    {
+        let method_name: String = String::from("z_listsentbyaddress");
+        let method_body = vec![json!(address), json!(min_conf.unwrap_or(1))];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_listunspent ( minconf maxconf includeWatchonly ["zaddr", ...] )
+    /// * The z_listunspent method returns an array of unspent shielded notes.
+    /// * The method can also filter to only include results that have between minconf and maxconf (inclusive) confirmations, and also for specified z_addresses (["zaddr", ...]).
+    /// * When minconf is 0 unspent notes with zero confirmations are returned, even though they are not immediately spendable.
+    /// * Results are an array of Objects, each of which has: {txid, jsindex, jsoutindex, confirmations, address, amount, memo} (Sprout) {txid, outindex, confirmations, address, amount, memo} (Sapling)
+    /// # Arguments
+    /// * minconf 	(numeric, optional, default=1) 	the minimum confirmations to filter
+    /// * maxconf 	(numeric, optional, default=9999999) 	the maximum confirmations to filter
+    /// * includeWatchonly 	(bool, optional, default=false) 	whether to also include watchonly addresses (see z_importviewingkey)
+    /// * addresses 	(array) 	a json array of z addresses (both Sprout and Sapling) to act as a filter; duplicate addresses are not allowed
+    /// * address 	(string) 	a z address
+    /// # Results
+    /// * An array of json objects, each having the properties below.
+    /// * txid 	(string) 	the transaction id
+    /// * jsindex 	(numeric) 	the joinsplit index
+    /// * jsoutindex 	(numeric, only returned on sprout addresses) 	the output index of the joinsplit
+    /// * outindex 	(numeric, only returned on sapling addresses) 	the output index
+    /// * "confirmations" 	(numeric) 	a confirmation number that is aware of the dPoW security service
+    /// * "rawconfirmations" 	(numeric) 	the raw confirmations (number of blocks on top of this transaction's block)
+    /// * spendable 	(boolean) 	true if note can be spent by wallet, false if note has zero confirmations, false if address is watchonly
+    /// * address 	(string) 	the shielded address
+    /// * amount 	(numeric) 	the amount of value in the note
+    /// * memo 	(string) 	hexadecimal string representation of memo field
+    /// * change 	(boolean) 	true if the address that received the note is also one of the sending addresses/
+    /// * %%%
+
+    pub fn z_list_unspent(
+        &self,
+        min_conf: Option<u32>,
+        max_conf: Option<u32>,
+        include_watch_only: Option<bool>,
+        addresses: Vec<String>,
+        address: String,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_listunspent");
+        let mut temp_addresses = addresses;
+        if !address.is_empty() {
+            temp_addresses.push(address);
+        }
+        let method_body = vec![
+            json!(min_conf.unwrap_or(1)),
+            json!(max_conf.unwrap_or(9999999)),
+            json!(include_watch_only.unwrap_or(false)),
+            json!(temp_addresses),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_mergetoaddress [ "fromaddress", ... ] "toaddress" ( fee ) ( transparent_limit ) ( shielded_limit ) ( "memo" )
+    /// * The z_mergetoaddress method merges multiple utxos and notes into a single utxo or note. The method works for both transparent and shielded addresses. This is an asynchronous operation, and utxos selected for merging will be locked. If there is an error, they are unlocked.
+    /// * This is an experimental feature; the daemon must be started with -experimentalfeatures and -zmergetoaddress.
+    /// # Arguments
+    /// * fromaddresses 	(list of strings, required) 	t addresses or z addresses; "*" merges all utxos and notes, "ANY_TADDR" all utxos and "ANY_ZADDR" all notes
+    /// * "toaddress" 	(string, required) 	the t address or z address to receive the combined utxo
+    /// * fee 	(numeric, optional, default=0.0001) 	the fee amount to attach to this transaction
+    /// * transparent_limit 	(numeric, optional, default=50) 	limit on the maximum number of transparent utxos to merge; you may set this value to 0 to use the node option mempooltxinputlimit
+    /// * shielded_limit 	(numeric, optional, default=10) 	limit on the maximum number of hidden notes to merge; you may set this value to 0 to merge as many as will fit in the transaction
+    /// * "memo" 	(Memo, optional) 	a text memo of at most 512 bytes, sent to the daemon as hex; when toaddress is a z address, it is stored in the memo field of the new note
+    /// # Response
+    /// * "remainingUTXOs" 	(numeric) 	the number of utxos still available for merging
+    /// * "remainingTransparentValue" 	(numeric) 	the value of utxos still available for merging
+    /// * "remainingNotes" 	(numeric) 	the number of notes still available for merging
+    /// * "remainingShieldedValue" 	(numeric) 	the value of notes still available for merging
+    /// * "mergingUTXOs" 	(numeric) 	the number of utxos being merged
+    /// * "mergingTransparentValue" 	(numeric) 	the value of utxos being merged
+    /// * "mergingNotes" 	(numeric) 	the number of notes being merged
+    /// * "mergingShieldedValue" 	(numeric) 	the value of notes being merged
+    /// * "opid" 	(string) 	an operationid to pass to z_getoperationstatus to get the result of the operation
+    /// * %%%
+    pub fn z_merge_to_address(
+        &self,
+        from_addresses: Vec<String>,
+        to_address: String,
+        fee: Option<Amount>,
+        transparent_limit: Option<u32>,
+        shielded_limit: Option<u32>,
+        memo: Option<Memo>,
+    ) -> Response<C, MergeResult>
+    where
+        C: Transport<MergeResult>,
+    {
+        let method_name: String = String::from("z_mergetoaddress");
+        let temp_fee: Amount = fee.unwrap_or(DEFAULT_FEE);
+        let temp_transparent_limit: u32 = transparent_limit.unwrap_or(50);
+        let temp_shielded_limit: u32 = shielded_limit.unwrap_or(10);
+        let mut method_body = vec![
+            json!(from_addresses),
+            json!(to_address),
+            json!(temp_fee),
+            json!(temp_transparent_limit),
+            json!(temp_shielded_limit),
+        ];
+        if let Some(temp_memo) = memo {
+            method_body.push(json!(temp_memo));
+        }
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_sendmany "fromaddress" [ { "address": ..., "amount": ... }, ... ] ( minconf ) ( fee )
+    /// * The z_sendmany method sends one or more transactions at once, and allows for sending transactions of types t --> t, t --> z, z --> z, z --> t. It is the principle method for dealing with shielded z transactions in the Komodo ecosystem.
+    /// * The amount values are exact to the satoshi (see Amount). Change from a t address flows to a new t address address, while change from z address returns to itself. When sending coinbase utxos to a z address, change is not allowed. The entire value of the utxo(s) must be consumed. Currently, the maximum number of z address outputs is 54 due to transaction-size limits.
+    /// # Arguments
+    /// * "fromaddress" 	(string, required) 	the sending t address or z address
+    /// * "amounts" 	(array of ZRecipient)
+    /// * "address" 	(string, required) 	the receiving address; can be a t address or z address
+    /// * "amount" 	(numeric, required) 	the numeric amount
+    /// * "memo" 	(Memo, optional) 	if the address is a z address, a text memo of at most 512 bytes; it is sent to the daemon as hex
+    /// * minconf 	(numeric, optional, default=1) 	only use funds confirmed at least this many times
+    /// * fee 	(numeric, optional, default=0.0001) 	the fee amount to attach to this transaction
+    /// # Response
+    /// * "operationid" 	(string) 	an operationid to pass to z_getoperationstatus to get the result of the operation
+    /// # Examples
+    /// ```
+    /// let recipients = vec![ZRecipient::new(z_address, "1".parse()?).with_memo("invoice 42".parse()?)?];
+    /// let opid = client.wallet().z_send_many(from_address, recipients, None, None)?;
+    /// ```
+    /// %%%
+    pub fn z_send_many(
+        &self,
+        from_address: String,
+        amounts: Vec<ZRecipient>,
+        minconf: Option<u32>,
+        fee: Option<Amount>,
+    ) -> Response<C, String>
+    where
+        C: Transport<String>,
+    {
+        let method_name: String = String::from("z_sendmany");
+        let temp_minconf: u32 = minconf.unwrap_or(1);
+        let temp_fee: Amount = fee.unwrap_or(DEFAULT_FEE);
+        let method_body = vec![
+            json!(from_address),
+            json!(amounts),
+            json!(temp_minconf),
+            json!(temp_fee),
+        ];
+        self.client.call(&method_name, method_body)
+    }
+
+    /// * z_shieldcoinbase "fromaddress" "tozaddress" ( fee ) ( limit )
+    /// * The z_shieldcoinbase method shields transparent coinbase funds by sending the funds to a shielded z address. This is an asynchronous operation and utxos selected for shielding will be locked. If there is an error, they are unlocked.
+    /// * The RPC call listlockunspent can be used to return a list of locked utxos. The number of coinbase utxos selected for shielding can be limited by the caller. If the limit parameter is set to zero, the mempooltxinputlimit option will determine the number of uxtos. Any limit is constrained by the consensus rule defining a maximum transaction size of 100000 bytes.
+    /// # Arguments
+    /// * "fromaddress" 	(string, required) 	the address is a t address or "*" for all t address belonging to the wallet
+    /// * "toaddress" 	(string, required) 	the address is a z address
+    /// * fee 	(numeric, optional, default=0.0001) 	the fee amount to attach to this transaction
+    /// * limit 	(numeric, optional, default=50) 	limit on the maximum number of utxos to shield; set to 0 to use node option mempooltxinputlimit
+    /// # Response
+    /// * "remainingUTXOs" 	(numeric) 	the number of coinbase utxos still available for shielding
+    /// * "remainingValue" 	(numeric) 	the value of coinbase utxos still available for shielding
+    /// * "shieldingUTXOs" 	(numeric) 	the number of coinbase utxos being shielded
+    /// * "shieldingValue" 	(numeric) 	the value of coinbase utxos being shielded
+    /// * "opid" 	(string) 	an operationid to pass to z_getoperationstatus to get the result of the operation
+    /// * %%%
+
+    pub fn z_shield_coinbase(
+        &self,
+        from_address: String,
+        to_address: String,
+        fee: Option<Amount>,
+        limit: Option<u32>,
+    ) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("z_shieldcoinbase");
+        let temp_fee: Amount = fee.unwrap_or(DEFAULT_FEE);
+        let temp_limit: u32 = limit.unwrap_or(50);
+        let method_body = vec![
+            json!(from_address),
+            json!(to_address),
+            json!(temp_fee),
+            json!(temp_limit),
+        ];
+        self.client.call(&method_name, method_body)
+    }
 
-/*
-TODO - unfinished
-                                        E X P E R I M E N T A L                    F E A T U R E
-Check with Dr. Datta & Komodo Team
-Function: z_mergetoaddress
-Arguments
-Name 	Type 	Description
-fromaddresses 	(string, required)
-"address" 	(string) 	can be a t address or a z address
-"toaddress" 	(string, required) 	the t address or z address to receive the combined utxo
-fee 	(numeric, optional, default=0.0001) 	the fee amount to attach to this transaction
-transparent_limit 	(numeric, optional, default=50) 	limit on the maximum number of transparent utxos to merge; you may set this value to 0 to use the node option mempooltxinputlimit
-shielded_limit 	(numeric, optional, default=10) 	limit on the maximum number of hidden notes to merge; you may set this value to 0 to merge as many as will fit in the transaction
-"memo" 	(string, optional) 	encoded as hex; when toaddress is a z address, this value will be stored in the memo field of the new note
-#
-Response
-Name 	Type 	Description
-"remainingUTXOs" 	(numeric) 	the number of utxos still available for merging
-"remainingTransparentValue" 	(numeric) 	the value of utxos still available for merging
-"remainingNotes" 	(numeric) 	the number of notes still available for merging
-"remainingShieldedValue" 	(numeric) 	the value of notes still available for merging
-"mergingUTXOs" 	(numeric) 	the number of utxos being merged
-"mergingTransparentValue" 	(numeric) 	the value of utxos being merged
-"mergingNotes" 	(numeric) 	the number of notes being merged
-"mergingShieldedValue" 	(numeric) 	the value of notes being merged
-"opid" 	(string) 	an operationid to pass to z_getoperationstatus to get the result of the operation
-*/
-
-// - Incomplete documentation, missing complete function
-//      (https://docs.komodoplatform.com/basic-docs/smart-chains/smart-chain-api/wallet.html#z-sendmany)
-// - unfinished function: amounts array arguments, memo argument
-/// * z_sendmany "fromaddress" [ { "address": ..., "amount": ... }, ... ] ( minconf ) ( fee )
-/// * The z_sendmany method sends one or more transactions at once, and allows for sending transactions of types t --> t, t --> z, z --> z, z --> t. It is the principle method for dealing with shielded z transactions in the Komodo ecosystem.
-/// * The amount values are double-precision floating point numbers. Change from a t address flows to a new t address address, while change from z address returns to itself. When sending coinbase utxos to a z address, change is not allowed. The entire value of the utxo(s) must be consumed. Currently, the maximum number of z address outputs is 54 due to transaction-size limits.
-/// # Arguments
-/// * "fromaddress" 	(string, required) 	the sending t address or z address
-/// * "amounts" 	(array of json objects)
-/// * "address" 	(string, required) 	the receiving address; can be a t address or z address
-/// * "amount" 	(numeric, required) 	the numeric amount
-/// * "memo" 	(string, optional) 	if the address is a z address, this property accepts raw data represented in hexadecimal string format
-/// * minconf 	(numeric, optional, default=1) 	only use funds confirmed at least this many times
-/// * fee 	(numeric, optional, default=0.0001) 	the fee amount to attach to this transaction
-/// # Response
-/// * "operationid" 	(string) 	an operationid to pass to z_getoperationstatus to get the result of the operation
-/*
-pub fn z_send_many(
-    SomeUser: komodorpcutil::KomodoRPC,
-    from_address: String,
-    amounts: f32,
-    address: String,
-    amount: f32,
-    memo: Option<String>,
-    minconf: Option<u32>,
-    fee: Option<f32>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_sendmany");
-    let method_body: String;
-    let temp_memo: String = memo.unwrap_or("");
-    let temp_minconf: u32 = minconf.unwrap_or(1);
-    let temp_fee: f32 = fee.unwrap_or(.0001);
-
-    method_body = String::from("[\"")
-    + &from_address
-    + &String::from("\", ")
-    + &address
-    + &String::from(",")
-    + &amount.to_string()
-    + &String::from(",")
-    + &temp_memo.to_string()
-    + &String::from(",")
-    + &temp_minconf.to_string()
-    + &String::from(",")
-    + &temp_fee.to_string()
-    + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-            SomeUser.clone(),
-            method_name,
-            method_body));
-    komodorpcutil::request(SomeUser.clone(), data)
-}*/
-
-/// * z_shieldcoinbase "fromaddress" "tozaddress" ( fee ) ( limit )
-/// * The z_shieldcoinbase method shields transparent coinbase funds by sending the funds to a shielded z address. This is an asynchronous operation and utxos selected for shielding will be locked. If there is an error, they are unlocked.
-/// * The RPC call listlockunspent can be used to return a list of locked utxos. The number of coinbase utxos selected for shielding can be limited by the caller. If the limit parameter is set to zero, the mempooltxinputlimit option will determine the number of uxtos. Any limit is constrained by the consensus rule defining a maximum transaction size of 100000 bytes.
-/// # Arguments
-/// * "fromaddress" 	(string, required) 	the address is a t address or "*" for all t address belonging to the wallet
-/// * "toaddress" 	(string, required) 	the address is a z address
-/// * fee 	(numeric, optional, default=0.0001) 	the fee amount to attach to this transaction
-/// * limit 	(numeric, optional, default=50) 	limit on the maximum number of utxos to shield; set to 0 to use node option mempooltxinputlimit
-/// # Response
-/// * "remainingUTXOs" 	(numeric) 	the number of coinbase utxos still available for shielding
-/// * "remainingValue" 	(numeric) 	the value of coinbase utxos still available for shielding
-/// * "shieldingUTXOs" 	(numeric) 	the number of coinbase utxos being shielded
-/// * "shieldingValue" 	(numeric) 	the value of coinbase utxos being shielded
-/// * "opid" 	(string) 	an operationid to pass to z_getoperationstatus to get the result of the operation
-/// * %%%
-
-pub fn z_shield_coinbase(
-    SomeUser: komodorpcutil::KomodoRPC,
-    from_address: String,
-    to_address: String,
-    fee: Option<f32>,
-    limit: Option<u32>,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("z_shieldcoinbase");
-    let method_body: String;
-    let temp_fee: f32 = fee.unwrap_or(0.0001);
-    let temp_limit: u32 = limit.unwrap_or(50);
-
-    method_body = String::from("[\"")
-        + &from_address.to_string()
-        + &String::from("\", \"")
-        + &to_address.to_string()
-        + &String::from("\", ")
-        + &temp_fee.to_string()
-        + &String::from(",")
-        + &temp_limit.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+    /// * z_viewtransaction "txid"
+    /// * The z_viewtransaction method returns detailed shielded information about an in-wallet transaction.
+    /// # Arguments
+    /// * "txid" 	(string, required) 	the transaction id
+    /// # Response
+    /// * "txid" 	(string) 	the transaction id
+    /// * "spends" 	(array of json objects) 	the notes spent: type, spend, txidPrev, outputPrev, address, value, valueZat
+    /// * "outputs" 	(array of json objects) 	the notes created: type, output, address, recovered, value, valueZat, memo, memoStr
+    /// * %%%
+    pub fn z_view_transaction(&self, tx_id: String) -> Response<C, ViewTransaction>
+    where
+        C: Transport<ViewTransaction>,
+    {
+        let method_name: String = String::from("z_viewtransaction");
+        let method_body = vec![json!(tx_id)];
+        self.client.call(&method_name, method_body)
+    }
+
+    //  TODO #zcbenchmark - unfinished, no example provided on Komodo documentation
+    /// * zcbenchmark benchmarktype samplecount
+    /// * The zcbenchmark method runs a benchmark of the selected benchmarktype. This benchmark is calculated samplecount times.
+    /// * When finished, the method returns the running times of each sample.
+    /// # Arguments
+    /// * "benchmarktype" 	(string, required) 	the type of the benchmark
+    /// * "samplecount" 	(numeric) 	the number of samples to take
+    /// # Response
+    /// * runningtime" 	(numeric) 	the time it took to run the selected benchmarktype
+    /// %%%
+    pub fn z_cbenchmark(&self, benchmark_type: String, sample_count: u32) -> Response<C, Value>
+    where
+        C: Transport<Value>,
+    {
+        let method_name: String = String::from("zcbenchmark");
+        let method_body = vec![json!(benchmark_type), json!(sample_count)];
+        self.client.call(&method_name, method_body)
+    }
 }
 
-//  TODO #zcbenchmark - unfinished, no example provided on Komodo documentation
-/// * zcbenchmark benchmarktype samplecount
-/// * The zcbenchmark method runs a benchmark of the selected benchmarktype. This benchmark is calculated samplecount times.
-/// * When finished, the method returns the running times of each sample.
-/// # Arguments
-/// * "benchmarktype" 	(string, required) 	the type of the benchmark
-/// * "samplecount" 	(numeric) 	the number of samples to take
-/// # Response
-/// * runningtime" 	(numeric) 	the time it took to run the selected benchmarktype
-/// %%%
-pub fn z_cbenchmark(
-    SomeUser: komodorpcutil::KomodoRPC,
-    benchmark_type: String,
-    sample_count: u32,
-) -> Result<String, reqwest::Error> {
-    let method_name: String = String::from("zcbenchmark");
-    let method_body: String;
-
-    method_body = String::from("[\"")
-        + &benchmark_type.to_string()
-        + &String::from("\", \"")
-        + &sample_count.to_string()
-        + &String::from("]");
-
-    let data: String = String::from(komodorpcutil::generate_body(
-        SomeUser.clone(),
-        method_name,
-        method_body,
-    ));
-    komodorpcutil::request(SomeUser.clone(), data)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::KomodoApi;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+
+    const SAPLING: &str =
+        "zs1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7ruszzg3rysjjvfeg9y4zkvtfdeq";
+
+    #[test]
+    fn get_balance_sends_defaults() {
+        let daemon = MockKomodod::start();
+        let balance = daemon.client().wallet().get_balance(None, None).unwrap();
+        assert_eq!(balance, Amount::from_sat(1_050_000_000));
+        assert_eq!(daemon.last_params("getbalance"), json!(["", 1, false]));
+    }
+
+    #[test]
+    fn get_wallet_info_is_typed() {
+        let daemon = MockKomodod::start();
+        let info = daemon.client().wallet().get_wallet_info().unwrap();
+        assert_eq!(info.txcount, 2);
+        assert_eq!(info.unlocked_until, None);
+    }
+
+    #[test]
+    fn send_to_address_escapes_comments() {
+        let daemon = MockKomodod::start();
+        let txid = daemon
+            .client()
+            .wallet()
+            .send_to_address(
+                "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse().unwrap(),
+                Amount::from_sat(150_000_000),
+                Some(String::from("rent \"May\"")),
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(txid, json!(MOCK_TXID));
+        assert_eq!(
+            daemon.last_params("sendtoaddress"),
+            json!([
+                "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4",
+                Amount::from_sat(150_000_000),
+                "rent \"May\"",
+                "",
+                false
+            ])
+        );
+    }
+
+    #[test]
+    fn list_transactions_is_typed() {
+        let daemon = MockKomodod::start();
+        let history = daemon
+            .client()
+            .wallet()
+            .list_transactions(None, Some(2), None, None)
+            .unwrap();
+        assert_eq!(
+            daemon.last_params("listtransactions"),
+            json!(["*", 2, 0, false])
+        );
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].category, "receive");
+        assert_eq!(history[1].fee, Some(Amount::from_sat(-10_000)));
+        assert_eq!(history[1].blockhash, None);
+    }
+
+    #[test]
+    fn list_address_groupings_reads_optional_account() {
+        let daemon = MockKomodod::start();
+        let groupings = daemon.client().wallet().list_address_groupings().unwrap();
+        assert_eq!(groupings[0][0].account, Some(String::new()));
+        assert_eq!(
+            groupings[1][0].address,
+            "RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp"
+        );
+        assert_eq!(groupings[1][0].account, None);
+    }
+
+    #[test]
+    fn z_send_many_hex_encodes_memos() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "z_sendmany",
+            json!("opid-8e9b5f0b-8b1c-4a8c-9d2e-3f4a5b6c7d8e"),
+        );
+        let recipients = vec![
+            ZRecipient::new(SAPLING.parse().unwrap(), Amount::from_sat(125_000_000))
+                .with_memo("hi".parse().unwrap())
+                .unwrap(),
+            ZRecipient::new(
+                "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse().unwrap(),
+                Amount::from_sat(50_000_000),
+            ),
+        ];
+        let opid = daemon
+            .client()
+            .wallet()
+            .z_send_many(String::from("zs1mocksender"), recipients, None, None)
+            .unwrap();
+        assert_eq!(opid, "opid-8e9b5f0b-8b1c-4a8c-9d2e-3f4a5b6c7d8e");
+        assert_eq!(
+            daemon.last_params("z_sendmany"),
+            json!([
+                "zs1mocksender",
+                [
+                    { "address": SAPLING, "amount": Amount::from_sat(125_000_000), "memo": "6869" },
+                    { "address": "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4", "amount": Amount::from_sat(50_000_000) }
+                ],
+                1,
+                Amount::from_sat(10_000)
+            ])
+        );
+    }
+
+    #[test]
+    fn memos_fit_a_note_and_go_to_z_addresses() {
+        assert!(Memo::new(&"m".repeat(MEMO_SIZE)).is_ok());
+        match "m".repeat(MEMO_SIZE + 1).parse::<Memo>() {
+            Err(KomodoError::InvalidMemo(message)) => {
+                assert_eq!(message, "513 bytes, at most 512 fit in a note")
+            }
+            other => panic!("expected an invalid memo, got {:?}", other),
+        }
+        let transparent = ZRecipient::new(
+            "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse().unwrap(),
+            Amount::from_sat(50_000_000),
+        );
+        match transparent.with_memo("hi".parse().unwrap()) {
+            Err(KomodoError::InvalidMemo(message)) => assert!(message.contains("t address")),
+            other => panic!("expected an invalid memo, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn send_many_sends_the_amounts_map() {
+        let daemon = MockKomodod::start();
+        daemon.set_result("sendmany", json!(MOCK_TXID));
+        let mut amounts = BTreeMap::new();
+        let first: Address = "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4".parse().unwrap();
+        amounts.insert(
+            "RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp".parse().unwrap(),
+            Amount::from_sat(25_000_000),
+        );
+        amounts.insert(first.clone(), Amount::from_sat(150_000_000));
+        let txid = daemon
+            .client()
+            .wallet()
+            .send_many(amounts, None, Some(String::from("payroll")), vec![first])
+            .unwrap();
+        assert_eq!(txid, MOCK_TXID);
+        assert_eq!(
+            daemon.last_params("sendmany"),
+            json!([
+                "",
+                {
+                    "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4": Amount::from_sat(150_000_000),
+                    "RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp": Amount::from_sat(25_000_000)
+                },
+                1,
+                "payroll",
+                ["RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4"]
+            ])
+        );
+    }
+
+    #[test]
+    fn z_get_total_balance_reads_string_amounts() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "z_gettotalbalance",
+            json!({ "transparent": "10.50", "private": "2.25", "total": "12.75" }),
+        );
+        let balance = daemon
+            .client()
+            .wallet()
+            .z_get_total_balance(None, None)
+            .unwrap();
+        assert_eq!(balance.transparent, Amount::from_sat(1_050_000_000));
+        assert_eq!(balance.private, Amount::from_sat(225_000_000));
+        assert_eq!(balance.total, Amount::from_sat(1_275_000_000));
+        assert_eq!(balance.interest, None);
+    }
+
+    #[test]
+    fn z_merge_to_address_hex_encodes_the_memo() {
+        let daemon = MockKomodod::start();
+        daemon.set_result(
+            "z_mergetoaddress",
+            json!({
+                "remainingUTXOs": 0,
+                "remainingTransparentValue": 0.0,
+                "remainingNotes": 3,
+                "remainingShieldedValue": 0.003,
+                "mergingUTXOs": 0,
+                "mergingTransparentValue": 0.0,
+                "mergingNotes": 10,
+                "mergingShieldedValue": 0.01,
+                "opid": "opid-4a6b9c2d-1e3f-4a5b-8c7d-9e0f1a2b3c4d"
+            }),
+        );
+        let merge = daemon
+            .client()
+            .wallet()
+            .z_merge_to_address(
+                vec![String::from("ANY_ZADDR")],
+                String::from("zs1mockrecipient"),
+                None,
+                None,
+                None,
+                Some("dust".parse().unwrap()),
+            )
+            .unwrap();
+        assert_eq!(merge.merging_notes, 10);
+        assert_eq!(merge.opid, "opid-4a6b9c2d-1e3f-4a5b-8c7d-9e0f1a2b3c4d");
+        assert_eq!(
+            daemon.last_params("z_mergetoaddress"),
+            json!([
+                ["ANY_ZADDR"],
+                "zs1mockrecipient",
+                Amount::from_sat(10_000),
+                50,
+                10,
+                "64757374"
+            ])
+        );
+    }
 }
@@ -1,5 +1,20 @@
 extern crate reqwest;
+use super::komodo::Amount;
+#[cfg(feature = "async")]
+use futures::{future, Future};
 use reqwest::header::*;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
 /**
@@ -24,16 +39,16 @@ impl KomodoRPC {
     /**
      * The default method of Komodo RPC initializes Komodo RPC with
      * default address, port, method, username, passoword, RPC version, RPC ID
+     * The username and password are left empty; they are read from the daemon's
+     * .conf file with from_conf() or set with apply_settings() / apply_env().
      */
     pub fn default() -> KomodoRPC {
         KomodoRPC {
             rpc_address: String::from("127.0.0.1"),
-            rpc_port: 8158,
+            rpc_port: KMD_RPC_PORT,
             req_method: String::from("POST"),
-            rpc_username: String::from("user2509089925"),
-            rpc_password: String::from(
-                "passf2e2f61a68e19ce65fe8211aa5d42163fb5c507c8d8d63dabd2c3f66c5358527eb",
-            ),
+            rpc_username: String::new(),
+            rpc_password: String::new(),
             json_rpc_ver: String::from("1.0"),
             rpc_id: String::from("curltest"),
         }
@@ -64,6 +79,70 @@ impl KomodoRPC {
         }
     }
 
+    /**
+     *Function Name: from_conf
+     *@params: path - the .conf file of a komodod daemon, e.g. ~/.komodo/KMD/KMD.conf (see conf_path)
+     *Output: a KomodoRPC built from the rpcuser, rpcpassword, rpcport and rpcbind keys of the file
+     *           KomodoError::Config - when the file cannot be read or has no rpcuser/rpcpassword
+     */
+    pub fn from_conf<P: AsRef<Path>>(path: P) -> Result<KomodoRPC, KomodoError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|err| KomodoError::Config(format!("{}: {}", path.display(), err)))?;
+        let settings = parse_conf(&text);
+        for key in ["rpcuser", "rpcpassword"].iter() {
+            if !settings.contains_key(*key) {
+                return Err(KomodoError::Config(format!(
+                    "{}: no {} set",
+                    path.display(),
+                    key
+                )));
+            }
+        }
+        let mut rpc = KomodoRPC::default();
+        rpc.apply_settings(|key| settings.get(key).cloned())?;
+        Ok(rpc)
+    }
+
+    /**
+     *Function Name: apply_settings
+     *@params: lookup - returns the value of a setting by its komodod .conf name:
+     *           rpcuser, rpcpassword, rpcport or rpcbind
+     *Output: the settings that lookup returns replace the current ones
+     *           KomodoError::Config - when rpcport is not a number
+     */
+    pub fn apply_settings<F>(&mut self, lookup: F) -> Result<(), KomodoError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(user) = lookup("rpcuser") {
+            self.rpc_username = user;
+        }
+        if let Some(password) = lookup("rpcpassword") {
+            self.rpc_password = password;
+        }
+        if let Some(port) = lookup("rpcport") {
+            self.rpc_port = port
+                .trim()
+                .parse()
+                .map_err(|_| KomodoError::Config(format!("rpcport {} is not a number", port)))?;
+        }
+        if let Some(bind) = lookup("rpcbind") {
+            self.rpc_address = bind;
+        }
+        Ok(())
+    }
+
+    /**
+     *Function Name: apply_env
+     *@params: the instance of the struct KomodoRPC
+     *Output: the settings overridden by the environment variables KOMODO_RPCUSER,
+     *           KOMODO_RPCPASSWORD, KOMODO_RPCPORT and KOMODO_RPCBIND, when set
+     */
+    pub fn apply_env(&mut self) -> Result<(), KomodoError> {
+        self.apply_settings(|key| env::var(format!("KOMODO_{}", key.to_uppercase())).ok())
+    }
+
     /**
      *Function Name: get_rpc_address
      *@params: the instance of the struct KomodoRPC
@@ -109,19 +188,6 @@ impl KomodoRPC {
         return self.rpc_password.to_string();
     }
 
-    /**
-     *Function Name: get_request_metadata
-     *@params: the instance of the struct KomodoRPC
-     *Output: string that has RPC request meta data in following format
-     *{"jsonrpc": "1", "id" : "curltest"}
-     */
-    pub fn get_request_metadata(&self) -> String {
-        let request_metadata: String = format!(
-            "{{\"jsonrpc\": \"{0}\", \"id\":\"{1}\", ",
-            self.json_rpc_ver, self.rpc_id
-        );
-        return request_metadata;
-    }
     /**
      *Function Name: get_json_ver
      *@params: the instance of the struct KomodoRPC
@@ -133,27 +199,514 @@ impl KomodoRPC {
     }
 }
 
+/// RPC port of the KMD main chain, used when a .conf file does not set rpcport.
+pub const KMD_RPC_PORT: i32 = 7771;
+
+/**
+*Function Name: komodo_data_dir
+*@params: none
+*Output: the data directory of komodod for the current user, i.e.
+*           ~/.komodo on Linux, ~/Library/Application Support/Komodo on macOS
+*           and %APPDATA%\Komodo on Windows; None when the home directory is unknown
+*/
+pub fn komodo_data_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(|dir| PathBuf::from(dir).join("Komodo"))
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|dir| {
+            PathBuf::from(dir)
+                .join("Library")
+                .join("Application Support")
+                .join("Komodo")
+        })
+    } else {
+        env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".komodo"))
+    }
+}
+
+/**
+*Function Name: conf_path
+*@params: chain - the name of the chain, e.g. KMD or KENNYCOIN
+*Output: the .conf file komodod writes for the chain: komodo.conf in the data directory
+*           for KMD, <CHAIN>/<CHAIN>.conf for smart chains
+*/
+pub fn conf_path(chain: &str) -> Option<PathBuf> {
+    let data_dir = komodo_data_dir()?;
+    if chain.eq_ignore_ascii_case("KMD") || chain.eq_ignore_ascii_case("KOMODO") {
+        Some(data_dir.join("komodo.conf"))
+    } else {
+        Some(data_dir.join(chain).join(format!("{}.conf", chain)))
+    }
+}
+
+/**
+*Function Name: parse_conf
+*@params: text - the contents of a komodod .conf file
+*Output: the key=value settings of the file; blank lines and # comments are skipped
+*/
+fn parse_conf(text: &str) -> HashMap<String, String> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => {
+                    Some((key.trim().to_string(), value.trim().to_string()))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/**
+ * KomodoClient is the entry point for calling the Komodo Daemon.
+ * It owns the KomodoRPC configuration and one HTTP client, so that every
+ * call reuses the same connection pool. The methods of each API module are
+ * reached through accessors such as client.wallet() or client.blockchain().
+ */
+#[derive(Debug, Clone)]
+pub struct KomodoClient {
+    rpc: KomodoRPC,
+    http: reqwest::Client,
+}
+
+impl KomodoClient {
+    /**
+     * The new method of KomodoClient takes the configuration of the daemon
+     * and creates the HTTP client that is shared by all calls.
+     */
+    pub fn new(rpc: KomodoRPC) -> KomodoClient {
+        KomodoClient {
+            rpc,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /**
+     *Function Name: get_rpc
+     *@params: the instance of the struct KomodoClient
+     *Output: the KomodoRPC configuration the client talks to
+     */
+    pub fn get_rpc(&self) -> &KomodoRPC {
+        &self.rpc
+    }
+
+    /**
+    *Function Name: call
+    *@params: method_name - name of the method to query to the Komodo Daemon
+               method_parameter - positional paramaters for the method
+    *Output: the "result" member of the reply deserialized into the type T
+    *           KomodoError - describing why the call failed (see KomodoError)
+    */
+    pub fn call<T: DeserializeOwned>(
+        &self,
+        method_name: &str,
+        method_parameter: Vec<Value>,
+    ) -> Result<T, KomodoError> {
+        let data = generate_body(&self.rpc, method_name, &method_parameter);
+        post(&self.http, &self.rpc, data).and_then(parse_result)
+    }
+
+    /**
+     *Function Name: batch
+     *@params: the instance of the struct KomodoClient
+     *Output: an empty Batch; calls made through it are sent together by Batch::send
+     */
+    pub fn batch(&self) -> Batch<'_> {
+        Batch {
+            client: self,
+            calls: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+/**
+ * Transport is implemented by every client that can run a call of the
+ * Komodo Daemon returning a T. The API modules are written once against
+ * it, and each client decides what a call gives back: KomodoClient returns
+ * the result directly, AsyncKomodoClient returns a future of it.
+ */
+pub trait Transport<T> {
+    /// What a call returning T gives back to the caller
+    type Output;
+
+    /**
+    *Function Name: call
+    *@params: method_name - name of the method to query to the Komodo Daemon
+               method_parameter - positional paramaters for the method
+    *Output: see the Output type of the client
+    */
+    fn call(&self, method_name: &str, method_parameter: Vec<Value>) -> Self::Output;
+}
+
+/// Response<C, T> is what the client C gives back for a call returning T.
+pub type Response<C, T> = <C as Transport<T>>::Output;
+
+impl<T: DeserializeOwned> Transport<T> for KomodoClient {
+    type Output = Result<T, KomodoError>;
+
+    fn call(&self, method_name: &str, method_parameter: Vec<Value>) -> Self::Output {
+        KomodoClient::call(self, method_name, method_parameter)
+    }
+}
+
+/**
+ * Batch collects calls of the Komodo Daemon and sends them as one JSON-RPC
+ * batch, i.e. a single HTTP POST carrying an array of requests. Every call
+ * made through a Batch returns a BatchCall handle instead of a result; after
+ * send() the handle is used to take its typed result out of the BatchReply.
+ *
+ * let batch = client.batch();
+ * let balance = batch.wallet().get_balance(None, None);
+ * let info = batch.control().get_info();
+ * let reply = batch.send()?;
+ * let balance: Amount = reply.get(balance)?;
+ */
+pub struct Batch<'c> {
+    client: &'c KomodoClient,
+    calls: RefCell<Vec<Value>>,
+}
+
+/**
+ * BatchCall identifies one call of a Batch by its request id.
+ * T is the type its result is deserialized into.
+ */
+#[derive(Debug)]
+pub struct BatchCall<T> {
+    id: String,
+    result: PhantomData<T>,
+}
+
+/**
+ * BatchReply holds the replies of a sent Batch, indexed by request id,
+ * since the daemon does not have to answer in the order of the calls.
+ */
+#[derive(Debug)]
+pub struct BatchReply {
+    replies: HashMap<String, Value>,
+}
+
+impl<'c> Batch<'c> {
+    /**
+     *Function Name: len
+     *@params: the instance of the struct Batch
+     *Output: the number of calls collected so far
+     */
+    pub fn len(&self) -> usize {
+        self.calls.borrow().len()
+    }
+
+    /**
+     *Function Name: is_empty
+     *@params: the instance of the struct Batch
+     *Output: true when no call has been collected
+     */
+    pub fn is_empty(&self) -> bool {
+        self.calls.borrow().is_empty()
+    }
+
+    /**
+     *Function Name: send
+     *@params: the instance of the struct Batch
+     *Output: the replies to all collected calls, from one round trip to the daemon
+     *           KomodoError - when the batch as a whole failed (see KomodoError);
+     *           errors of single calls are returned by BatchReply::get
+     */
+    pub fn send(self) -> Result<BatchReply, KomodoError> {
+        let calls = self.calls.into_inner();
+        if calls.is_empty() {
+            return Ok(BatchReply {
+                replies: HashMap::new(),
+            });
+        }
+        let data = serde_json::to_string(&calls)?;
+        let response = post(&self.client.http, &self.client.rpc, data)?;
+        let replies: Vec<Value> = serde_json::from_str(&response)?;
+        let replies = replies
+            .into_iter()
+            .filter_map(|reply| reply["id"].as_str().map(String::from).map(|id| (id, reply)))
+            .collect();
+        Ok(BatchReply { replies })
+    }
+}
+
+impl<'c, T> Transport<T> for Batch<'c> {
+    type Output = BatchCall<T>;
+
+    fn call(&self, method_name: &str, method_parameter: Vec<Value>) -> Self::Output {
+        let mut calls = self.calls.borrow_mut();
+        // the configured id is kept as a prefix so that calls stay recognizable in daemon logs
+        let id = format!("{}-{}", self.client.rpc.rpc_id, calls.len());
+        let envelope = RequestEnvelope {
+            jsonrpc: &self.client.rpc.json_rpc_ver,
+            id: &id,
+            method: method_name,
+            params: &method_parameter,
+        };
+        // serializing strings, numbers and arrays into JSON cannot fail
+        calls.push(
+            serde_json::to_value(&envelope).expect("JSON-RPC request is always serializable"),
+        );
+        BatchCall {
+            id,
+            result: PhantomData,
+        }
+    }
+}
+
+impl BatchReply {
+    /**
+     *Function Name: get
+     *@params: call - the handle returned when the call was added to the Batch
+     *Output: the "result" member of the call's reply deserialized into the type T
+     *           KomodoError::Rpc - when the daemon returned an error for this call
+     *           KomodoError::MissingReply - when the daemon did not answer this call
+     */
+    pub fn get<T: DeserializeOwned>(&self, call: BatchCall<T>) -> Result<T, KomodoError> {
+        let reply = match self.replies.get(&call.id) {
+            Some(reply) => reply,
+            None => return Err(KomodoError::MissingReply(call.id)),
+        };
+        if let Some(err) = rpc_error(reply) {
+            return Err(err);
+        }
+        Ok(T::deserialize(&reply["result"])?)
+    }
+}
+
+/**
+ * AsyncKomodoClient is the non-blocking counterpart of KomodoClient.
+ * Calls return futures that run on the caller's tokio runtime, so many
+ * daemon calls can be in flight at once without holding a thread each.
+ * Available with the "async" feature.
+ */
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub struct AsyncKomodoClient {
+    rpc: KomodoRPC,
+    http: reqwest::r#async::Client,
+}
+
+/// The future returned by every call of AsyncKomodoClient.
+#[cfg(feature = "async")]
+pub type KomodoFuture<T> = Box<dyn Future<Item = T, Error = KomodoError> + Send>;
+
+#[cfg(feature = "async")]
+impl AsyncKomodoClient {
+    /**
+     * The new method of AsyncKomodoClient takes the configuration of the daemon
+     * and creates the HTTP client that is shared by all calls.
+     */
+    pub fn new(rpc: KomodoRPC) -> AsyncKomodoClient {
+        AsyncKomodoClient {
+            rpc,
+            http: reqwest::r#async::Client::new(),
+        }
+    }
+
+    /**
+     *Function Name: get_rpc
+     *@params: the instance of the struct AsyncKomodoClient
+     *Output: the KomodoRPC configuration the client talks to
+     */
+    pub fn get_rpc(&self) -> &KomodoRPC {
+        &self.rpc
+    }
+
+    /**
+    *Function Name: call
+    *@params: method_name - name of the method to query to the Komodo Daemon
+               method_parameter - positional paramaters for the method
+    *Output: a future of the "result" member of the reply deserialized into the type T,
+    *           failing with the same KomodoError as the blocking client
+    */
+    pub fn call<T: DeserializeOwned + Send + 'static>(
+        &self,
+        method_name: &str,
+        method_parameter: Vec<Value>,
+    ) -> KomodoFuture<T> {
+        if let Err(err) = check_credentials(&self.rpc) {
+            return Box::new(future::err(err));
+        }
+        let data = generate_body(&self.rpc, method_name, &method_parameter);
+        let response = self
+            .http
+            .post(&self.rpc.get_request_url())
+            .basic_auth(self.rpc.get_username(), Some(self.rpc.get_password()))
+            .header(CONTENT_TYPE, "text/plain;")
+            .body(data)
+            .send()
+            .from_err::<KomodoError>()
+            .and_then(|mut response| {
+                let status = response.status();
+                future::result(check_status(status)).and_then(move |_| {
+                    response
+                        .text()
+                        .from_err()
+                        .and_then(move |body| check_response(status, body))
+                })
+            })
+            .and_then(parse_result);
+        Box::new(response)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned + Send + 'static> Transport<T> for AsyncKomodoClient {
+    type Output = KomodoFuture<T>;
+
+    fn call(&self, method_name: &str, method_parameter: Vec<Value>) -> Self::Output {
+        AsyncKomodoClient::call(self, method_name, method_parameter)
+    }
+}
+
+/**
+ * KomodoError describes everything that can go wrong while talking to the
+ * Komodo Daemon, so that callers can tell a dead connection apart from a
+ * wrong password or a command the daemon refused.
+ */
+#[derive(Debug)]
+pub enum KomodoError {
+    /// The HTTP request could not be sent or its response could not be read
+    Transport(reqwest::Error),
+    /// The daemon answered with a non-success HTTP status and no JSON-RPC error object
+    HttpStatus { status: u16, body: String },
+    /// The RPC username is missing or the daemon rejected the credentials (HTTP 401/403)
+    Authentication(String),
+    /// The daemon's reply was not the JSON that was expected
+    Json(serde_json::Error),
+    /// The daemon processed the request and returned a JSON-RPC error, e.g. code -5 for an invalid address
+    Rpc { code: i64, message: String },
+    /// A batch reply did not contain an answer for the call with this id
+    MissingReply(String),
+    /// The RPC settings could not be read, e.g. a missing .conf file or a bad rpcport
+    Config(String),
+    /// No daemon is registered for the chain with this name (see ChainRegistry)
+    UnknownChain(String),
+    /// The daemon knows no z_ operation with this id (see OperationTracker)
+    UnknownOperation(String),
+    /// The z_ operation with this id did not finish before the tracker's timeout
+    OperationTimeout(String),
+    /// Waiting for the z_ operation with this id was cancelled
+    OperationCancelled(String),
+    /// An Antara (CryptoConditions) module answered with "result": "error" and this message
+    Antara(String),
+    /// The values of oracle data do not match the oracle's format (see encode_oracle_data)
+    OracleData(String),
+    /// This text is not an amount of at most 8 decimal places (see Amount)
+    InvalidAmount(String),
+    /// This text is not an address of the chain, e.g. its checksum does not match (see Address)
+    InvalidAddress {
+        address: String,
+        reason: &'static str,
+    },
+    /// These bytes are not a transaction, e.g. they end in the middle of a field (see Transaction)
+    InvalidTransaction(String),
+    /// This memo cannot be sent, e.g. it is longer than a note's memo field (see wallet::Memo)
+    InvalidMemo(String),
+    /// The UTXOs given to a TransactionBuilder do not cover its payments and fee
+    InsufficientFunds { needed: Amount, available: Amount },
+}
+
+impl fmt::Display for KomodoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KomodoError::Transport(err) => write!(f, "transport error: {}", err),
+            KomodoError::HttpStatus { status, body } => {
+                write!(f, "HTTP status {}: {}", status, body)
+            }
+            KomodoError::Authentication(message) => write!(f, "authentication failed: {}", message),
+            KomodoError::Json(err) => write!(f, "invalid JSON in reply: {}", err),
+            KomodoError::Rpc { code, message } => write!(f, "RPC error {}: {}", code, message),
+            KomodoError::MissingReply(id) => write!(f, "no reply for batch call {}", id),
+            KomodoError::Config(message) => write!(f, "RPC configuration error: {}", message),
+            KomodoError::UnknownChain(chain) => {
+                write!(f, "no daemon registered for chain {:?}", chain)
+            }
+            KomodoError::UnknownOperation(opid) => write!(f, "unknown operation {}", opid),
+            KomodoError::OperationTimeout(opid) => {
+                write!(f, "timed out waiting for operation {}", opid)
+            }
+            KomodoError::OperationCancelled(opid) => {
+                write!(f, "stopped waiting for operation {}", opid)
+            }
+            KomodoError::Antara(message) => write!(f, "Antara module error: {}", message),
+            KomodoError::OracleData(message) => write!(f, "invalid oracle data: {}", message),
+            KomodoError::InvalidAmount(text) => write!(f, "invalid amount {:?}", text),
+            KomodoError::InvalidAddress { address, reason } => {
+                write!(f, "invalid address {:?}: {}", address, reason)
+            }
+            KomodoError::InvalidTransaction(message) => {
+                write!(f, "invalid transaction: {}", message)
+            }
+            KomodoError::InvalidMemo(message) => write!(f, "invalid memo: {}", message),
+            KomodoError::InsufficientFunds { needed, available } => write!(
+                f,
+                "insufficient funds: {} needed, {} available",
+                needed, available
+            ),
+        }
+    }
+}
+
+impl Error for KomodoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KomodoError::Transport(err) => Some(err),
+            KomodoError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for KomodoError {
+    fn from(err: reqwest::Error) -> KomodoError {
+        KomodoError::Transport(err)
+    }
+}
+
+impl From<serde_json::Error> for KomodoError {
+    fn from(err: serde_json::Error) -> KomodoError {
+        KomodoError::Json(err)
+    }
+}
+
+/**
+ * RequestEnvelope is the JSON-RPC request sent to the Komodo Daemon.
+ * It is serialized with serde so that every string passed in by the user
+ * is escaped correctly, e.g.
+ * {"jsonrpc":"1.0","id":"curltest","method":"getbalance","params":["",1,false]}
+ */
+#[derive(Serialize)]
+struct RequestEnvelope<'a> {
+    jsonrpc: &'a str,
+    id: &'a str,
+    method: &'a str,
+    params: &'a [Value],
+}
+
 /**
 *Function Name: generate_body
 *@params: KomodoRPC - the instance of the struct KomodoRPC
             method_name - name of the method to query to the Komodo Daemon
-            method_paramaters - paramaters for the method passed
+            method_parameter - positional paramaters for the method, in the order the daemon expects them
 *Output: the function generates an output for the HTTP request method encoding
-* 			the method, paramaters and metadata generated by get_request_metadata() function.
+* 			the method, paramaters and the RPC version and ID of the KomodoRPC instance.
 */
 pub fn generate_body(
-    some_user: KomodoRPC,
-    method_name: String,
-    method_parameter: String,
+    some_user: &KomodoRPC,
+    method_name: &str,
+    method_parameter: &[Value],
 ) -> String {
-    let method = format!("\"method\":\"{0}\",", method_name);
-    let paramater = format!("\"params\": {0} }}", method_parameter);
-    let mut body: String = some_user.get_request_metadata();
-    body = body + &method + &paramater;
-    //body.push_str(method.to_string());
-    //body.push_str(paramater);
-    //print!("{:?}",body);
-    return body;
+    let envelope = RequestEnvelope {
+        jsonrpc: &some_user.json_rpc_ver,
+        id: &some_user.rpc_id,
+        method: method_name,
+        params: method_parameter,
+    };
+    // serializing strings, numbers and arrays into JSON cannot fail
+    serde_json::to_string(&envelope).expect("JSON-RPC request is always serializable")
 }
 
 /**
@@ -162,29 +715,244 @@ pub fn generate_body(
             body_input - it has the body of the request provided by the get_body() method.
 *Output: Result of the request
             ie. JSON containing the the output result from the request
-                Error Codes - containing the error in the HTTP request
+                KomodoError - describing why the request failed (see KomodoError)
+* request builds a new HTTP client for every call; use KomodoClient to reuse connections.
 */
 
-pub fn request(some_user: KomodoRPC, body_input: String) -> Result<String, reqwest::Error> {
-    if some_user.get_username().trim() != "" {
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, "text/plain;".parse().unwrap());
-        // assume this is from the struc
-        let some_json_rpc_ver = String::from(some_user.get_json_ver());
-        let some_rpc_id = String::from(some_user.get_rpc_id());
-        let url_post = some_user.get_request_url();
-        let res = reqwest::Client::new()
-            .post(&url_post)
-            .basic_auth(some_user.get_username(), Some(some_user.get_password()))
-            .headers(headers)
-            //.body("{\"jsonrpc\": \"1.0\", \"id\":\"curltest\", \"method\": \"getwalletinfo\", \"params\": [] }")
-            .body(body_input)
-            .send()?
-            .text()?;
-        println!("the result is{}", res);
-        return Ok(res);
-    } else {
-        println!("Error");
-        return Ok("Error".to_string());
+pub fn request(some_user: &KomodoRPC, body_input: String) -> Result<String, KomodoError> {
+    post(&reqwest::Client::new(), some_user, body_input)
+}
+
+/**
+*Function Name: post
+*@params: http - the HTTP client used to send the request
+            some_user - the configuration of the Komodo Daemon to send the request to
+            body_input - the JSON-RPC request body
+*Output: the JSON reply of the daemon, checked by check_response()
+*/
+fn post(
+    http: &reqwest::Client,
+    some_user: &KomodoRPC,
+    body_input: String,
+) -> Result<String, KomodoError> {
+    check_credentials(some_user)?;
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, "text/plain;".parse().unwrap());
+    let url_post = some_user.get_request_url();
+    let mut response = http
+        .post(&url_post)
+        .basic_auth(some_user.get_username(), Some(some_user.get_password()))
+        .headers(headers)
+        .body(body_input)
+        .send()?;
+    let status = response.status();
+    check_status(status)?;
+    let res = response.text()?;
+    check_response(status, res)
+}
+
+/**
+*Function Name: check_credentials
+*@params: some_user - the configuration of the Komodo Daemon
+*Output: KomodoError::Authentication when no RPC username is configured
+*/
+fn check_credentials(some_user: &KomodoRPC) -> Result<(), KomodoError> {
+    if some_user.get_username().trim() == "" {
+        return Err(KomodoError::Authentication(String::from(
+            "no RPC username configured",
+        )));
+    }
+    Ok(())
+}
+
+/**
+*Function Name: check_status
+*@params: status - the HTTP status returned by the Komodo Daemon
+*Output: KomodoError::Authentication when the daemon rejected the credentials (HTTP 401/403)
+*/
+fn check_status(status: StatusCode) -> Result<(), KomodoError> {
+    if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+        return Err(KomodoError::Authentication(format!(
+            "the daemon rejected the RPC credentials ({})",
+            status
+        )));
+    }
+    Ok(())
+}
+
+/**
+*Function Name: check_response
+*@params: status - the HTTP status returned by the Komodo Daemon
+            body - the text of the HTTP response
+*Output: the body unchanged when the daemon answered without an error, otherwise
+*           KomodoError::Rpc when the reply carries a JSON-RPC error object,
+*           KomodoError::HttpStatus for any other non-success status and
+*           KomodoError::Json when a successful reply is not valid JSON.
+* The daemon answers JSON-RPC errors with HTTP 500 (or 404 for unknown methods),
+* so the error object is inspected before the status code.
+*/
+fn check_response(status: StatusCode, body: String) -> Result<String, KomodoError> {
+    match serde_json::from_str::<serde_json::Value>(&body) {
+        Ok(reply) => {
+            if let Some(err) = rpc_error(&reply) {
+                return Err(err);
+            }
+            if !status.is_success() {
+                return Err(KomodoError::HttpStatus {
+                    status: status.as_u16(),
+                    body,
+                });
+            }
+            Ok(body)
+        }
+        Err(err) => {
+            if !status.is_success() {
+                return Err(KomodoError::HttpStatus {
+                    status: status.as_u16(),
+                    body,
+                });
+            }
+            Err(KomodoError::Json(err))
+        }
+    }
+}
+
+/**
+*Function Name: rpc_error
+*@params: reply - one JSON-RPC reply object of the Komodo Daemon
+*Output: KomodoError::Rpc when the reply carries a non-null error object, otherwise None
+*/
+fn rpc_error(reply: &Value) -> Option<KomodoError> {
+    let error = &reply["error"];
+    if error.is_null() {
+        return None;
+    }
+    Some(KomodoError::Rpc {
+        code: error["code"].as_i64().unwrap_or(0),
+        message: error["message"]
+            .as_str()
+            .map(String::from)
+            .unwrap_or_else(|| error.to_string()),
+    })
+}
+
+/**
+ * ResponseEnvelope is the part of the daemon's reply that is kept once
+ * request() has checked the error object: the "result" member.
+ */
+#[derive(Deserialize)]
+struct ResponseEnvelope<T> {
+    result: T,
+}
+
+/**
+*Function Name: parse_result
+*@params: response - the JSON reply returned by request()
+*Output: the "result" member of the reply deserialized into the type T
+*           KomodoError::Json - when the result does not have the shape of T
+*/
+pub fn parse_result<T: DeserializeOwned>(response: String) -> Result<T, KomodoError> {
+    let envelope: ResponseEnvelope<T> = serde_json::from_str(&response)?;
+    Ok(envelope.result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Writes a .conf file to the temp directory; each test uses its own name.
+    fn write_conf(name: &str, text: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("komodorpc-{}-{}.conf", std::process::id(), name));
+        fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn from_conf_skips_comments_and_blank_lines() {
+        let path = write_conf(
+            "comments",
+            "# written by komodod\n\nrpcuser=user123 # the rpc user\n  rpcpassword = pass=word\n\nrpcport=7771\nrpcbind=127.0.0.1\nserver=1\n",
+        );
+        let rpc = KomodoRPC::from_conf(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(rpc.get_username(), "user123");
+        assert_eq!(rpc.get_password(), "pass=word");
+        assert_eq!(rpc.get_request_url(), "http://127.0.0.1:7771/");
+    }
+
+    #[test]
+    fn from_conf_needs_credentials_and_a_numeric_port() {
+        let path = write_conf("nouser", "# rpcuser=commented\nrpcpassword=secret\n");
+        let missing = KomodoRPC::from_conf(&path);
+        fs::remove_file(&path).unwrap();
+        match missing {
+            Err(KomodoError::Config(message)) => assert!(message.ends_with("no rpcuser set")),
+            other => panic!("expected a config error, got {:?}", other),
+        }
+
+        let path = write_conf(
+            "badport",
+            "rpcuser=user\nrpcpassword=secret\nrpcport=seventy\n",
+        );
+        let bad_port = KomodoRPC::from_conf(&path);
+        fs::remove_file(&path).unwrap();
+        match bad_port {
+            Err(KomodoError::Config(message)) => {
+                assert_eq!(message, "rpcport seventy is not a number")
+            }
+            other => panic!("expected a config error, got {:?}", other),
+        }
+
+        match KomodoRPC::from_conf(env::temp_dir().join("komodorpc-no-such-file.conf")) {
+            Err(KomodoError::Config(_)) => {}
+            other => panic!("expected a config error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn env_overrides_the_conf_file() {
+        let path = write_conf(
+            "env",
+            "rpcuser=fileuser\nrpcpassword=filepass\nrpcport=7771\n",
+        );
+        let mut rpc = KomodoRPC::from_conf(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        // no other test reads these variables
+        env::set_var("KOMODO_RPCUSER", "envuser");
+        env::set_var("KOMODO_RPCPORT", "8000");
+        let applied = rpc.apply_env();
+        env::remove_var("KOMODO_RPCUSER");
+        env::remove_var("KOMODO_RPCPORT");
+        applied.unwrap();
+        assert_eq!(rpc.get_username(), "envuser");
+        assert_eq!(rpc.get_password(), "filepass");
+        assert_eq!(rpc.get_request_url(), "http://127.0.0.1:8000/");
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_client_reads_results_and_errors() {
+        use crate::komodo::KomodoApi;
+        use crate::mockkomodod::MockKomodod;
+        use serde_json::json;
+
+        let daemon = MockKomodod::start();
+        daemon.set_error("getinfo", -28, "Loading block index...");
+        let client = AsyncKomodoClient::new(daemon.rpc());
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+
+        let balance = runtime
+            .block_on(client.wallet().get_balance(None, None))
+            .unwrap();
+        assert_eq!(balance, Amount::from_sat(1_050_000_000));
+        assert_eq!(daemon.last_params("getbalance"), json!(["", 1, false]));
+
+        match runtime.block_on(client.control().get_info()) {
+            Err(KomodoError::Rpc { code, message }) => {
+                assert_eq!(code, -28);
+                assert_eq!(message, "Loading block index...");
+            }
+            other => panic!("expected an RPC error, got {:?}", other),
+        }
     }
 }
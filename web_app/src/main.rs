@@ -7,21 +7,30 @@ extern crate rustc_serialize;
 extern crate serde_json;
 extern crate rusqlite;
 
+mod chainregistry;
 mod komodo;
 mod komodorpcutil;
-use komodorpcutil::KomodoRPC;
+#[cfg(test)]
+mod mockkomodod;
+mod operationtracker;
+use chainregistry::ChainRegistry;
+use komodo::{payments, Address, AddressParams, Amount, KomodoApi};
+use komodorpcutil::{KomodoError, KomodoRPC};
+use operationtracker::{OperationOutcome, OperationState, OperationTracker};
 
 use rusqlite::{params, Connection, Result};
-use rustc_serialize::json::Json;
-use serde_json::Value as JsonValue;
-use std::collections::HashMap;
-use serde_json::Value;
+use rustc_serialize::hex::ToHex;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
 
 
-use rocket::http::{Cookie, Cookies};
+use rocket::config::Config;
+use rocket::fairing::AdHoc;
+use rocket::http::{Cookie, Cookies, RawStr};
 use rocket::outcome::IntoOutcome;
-use rocket::request::{self, FlashMessage, Form, FromRequest, Request};
-use rocket::response::{Flash, Redirect};
+use rocket::request::{self, FlashMessage, Form, FromFormValue, FromRequest, Request};
+use rocket::response::{content, Flash, Redirect};
+use rocket::State;
 use rocket_contrib::templates::Template;
 
 #[derive(FromForm)]
@@ -47,10 +56,43 @@ struct Person {
 #[derive(FromForm)]
 struct Sent {
     address: String,
-    amount: f64,
+    amount: Amount,
     comment: String,
 }
 
+#[derive(FromForm)]
+struct SentMany {
+    recipients: String,
+    comment: String,
+    subtract_fee: bool,
+}
+
+#[derive(FromForm)]
+struct PayoutPlan {
+    recipients: String,
+    locked_blocks: u32,
+    min_release: Amount,
+}
+
+#[derive(FromForm)]
+struct PayoutRelease {
+    createtxid: String,
+    amount: Amount,
+}
+
+// Amounts typed into a form are parsed exactly, so "0.1" is 10000000 satoshis.
+impl<'v> FromFormValue<'v> for Amount {
+    type Error = &'v RawStr;
+
+    fn from_form_value(form_value: &'v RawStr) -> std::result::Result<Amount, &'v RawStr> {
+        form_value
+            .url_decode()
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .ok_or(form_value)
+    }
+}
+
 #[derive(Debug)]
 struct User(usize);
 
@@ -132,7 +174,7 @@ fn login(mut cookies: Cookies, login: Form<Login>) -> Result<Redirect, Flash<Red
 
 #[get("/login")]
 fn login_user(_user: User) -> Redirect {
-    Redirect::to(uri!(user_index))
+    Redirect::to(uri!(user_index: _))
 }
 
 #[get("/login", rank = 2)]
@@ -145,43 +187,37 @@ fn login_page(flash: Option<FlashMessage>) -> Template {
     Template::render("login", &context)
 }
 
-#[post("/send_money_post", data = "<send>")]
-fn send_money_handler(send: Form<Sent>) -> Result<Redirect, Flash<Redirect>> {
-    let someAddress = String::from("127.0.0.1");
-    let somePortNum = 13211;
-    let someReqMethod = String::from("POST");
-    let someUserName = String::from("user629608817");
-    let somePassword =
-        String::from("pass6375b5f55e1b166f744d7c66b66354217a6d36645d1de5302c8780bca9600cce49");
-    let someJSONRPCVer = String::from("1.0");
-    let someRPCReqID = String::from("curltest");
-    // CHECK TO SEE IF THERE IS USERNAME IS EMPTY???? TODO
-
-    // Create KomodoRPC type (an 'object') with variables
-    let someUser = KomodoRPC::new(
-        someAddress,
-        somePortNum,
-        someReqMethod,
-        someUserName,
-        somePassword,
-        someJSONRPCVer,
-        someRPCReqID,
-    );
-
-    println!("{}", send.address);
-    println!("{}", send.amount);
-    println!("{}", send.comment);
+#[post("/send_money_post?<chain>", data = "<send>")]
+fn send_money_handler(
+    _user: User,
+    send: Form<Sent>,
+    chain: Option<String>,
+    chains: State<ChainRegistry>,
+) -> Result<Redirect, Flash<Redirect>> {
+    let client = match chains.select(chain.as_deref()) {
+        Ok(client) => client,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
+    let params = match chains.select_address_params(chain.as_deref()) {
+        Ok(params) => params,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
+    // checked offline with the chain's version bytes, so a mistyped address never reaches the daemon
+    let address = match Address::parse(send.address.trim(), params) {
+        Ok(address) => address,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
 
-    komodo::wallet::send_to_address(
-        someUser,
-        send.address.to_string(),
+    match client.wallet().send_to_address(
+        address,
         send.amount,
         Some(send.comment.to_string()),
         Some("alfonso".to_string()),
         Some(true),
-    );
-
-    Ok(Redirect::to(uri!(index)))
+    ) {
+        Ok(_) => Ok(Redirect::to(uri!(index))),
+        Err(err) => Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    }
     /*if login.username == "Sergio" && login.password == "password" {
         cookies.add_private(Cookie::new("user_id", 1.to_string()));
         Ok(Redirect::to(uri!(index)))
@@ -193,6 +229,185 @@ fn send_money_handler(send: Form<Sent>) -> Result<Redirect, Flash<Redirect>> {
     }*/
 }
 
+/**
+ * Reads the recipients of the send-to-many and payout forms: one "address amount"
+ * pair per line, separated by spaces or a comma. Addresses are checked offline with
+ * the params of the selected chain (see Address) and may only be listed once.
+ * The amount is read as T, e.g. an Amount or the whole-number share of a payout.
+ */
+fn parse_recipients<T: FromStr>(
+    recipients: &str,
+    params: &AddressParams,
+) -> std::result::Result<BTreeMap<Address, T>, String> {
+    let mut amounts = BTreeMap::new();
+    for line in recipients.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let mut fields = line
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|field| !field.is_empty());
+        let (address, amount) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(address), Some(amount), None) => (address, amount),
+            _ => return Err(format!("expected \"address amount\", got {:?}", line)),
+        };
+        let address = match Address::parse(address, params) {
+            Ok(address) => address,
+            Err(err) => return Err(err.to_string()),
+        };
+        let amount: T = match amount.parse() {
+            Ok(amount) => amount,
+            Err(_) => return Err(format!("invalid amount {:?} for {}", amount, address)),
+        };
+        if amounts.insert(address.clone(), amount).is_some() {
+            return Err(format!("{} is listed twice", address));
+        }
+    }
+    if amounts.is_empty() {
+        return Err(String::from("no recipients given"));
+    }
+    Ok(amounts)
+}
+
+#[post("/send_many_post?<chain>", data = "<send>")]
+fn send_many_handler(
+    _user: User,
+    send: Form<SentMany>,
+    chain: Option<String>,
+    chains: State<ChainRegistry>,
+) -> Result<Redirect, Flash<Redirect>> {
+    let client = match chains.select(chain.as_deref()) {
+        Ok(client) => client,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
+    let params = match chains.select_address_params(chain.as_deref()) {
+        Ok(params) => params,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
+    let amounts: BTreeMap<Address, Amount> = match parse_recipients(&send.recipients, params) {
+        Ok(amounts) => amounts,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err)),
+    };
+    // with the box ticked every recipient shares the fee
+    let subtract_fee_from = if send.subtract_fee {
+        amounts.keys().cloned().collect()
+    } else {
+        Vec::new()
+    };
+
+    match client.wallet().send_many(
+        amounts,
+        None,
+        Some(send.comment.to_string()),
+        subtract_fee_from,
+    ) {
+        Ok(_) => Ok(Redirect::to(uri!(index))),
+        Err(err) => Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    }
+}
+
+/**
+ * Schedules payouts through the Antara payments contract: every recipient line
+ * ("address share") becomes a paymentstxidopret transaction paying the address's
+ * scriptPubKey, and the plan is created from them. Its createtxid is flashed back,
+ * to fund the plan (paymentsfund) and release payouts with /payouts_release_post.
+ */
+#[post("/payouts_schedule_post?<chain>", data = "<plan>")]
+fn payouts_schedule_handler(
+    _user: User,
+    plan: Form<PayoutPlan>,
+    chain: Option<String>,
+    chains: State<ChainRegistry>,
+) -> Result<Flash<Redirect>, Flash<Redirect>> {
+    let client = match chains.select(chain.as_deref()) {
+        Ok(client) => client,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
+    let params = match chains.select_address_params(chain.as_deref()) {
+        Ok(params) => params,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
+    let shares: BTreeMap<Address, u64> = match parse_recipients(&plan.recipients, params) {
+        Ok(shares) => shares,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err)),
+    };
+    let mut recipients = Vec::new();
+    for (address, share) in shares {
+        match address.script_pub_key() {
+            Some(script_pub_key) => recipients.push((script_pub_key.to_hex(), share)),
+            None => {
+                return Err(Flash::error(
+                    Redirect::to(uri!(index)),
+                    format!("{} is shielded; payouts go to transparent addresses", address),
+                ))
+            }
+        }
+    }
+
+    match payments::schedule_payouts(client, plan.locked_blocks, plan.min_release, &recipients) {
+        Ok(createtxid) => Ok(Flash::success(
+            Redirect::to(uri!(index)),
+            format!("payout plan {} created", createtxid),
+        )),
+        Err(err) => Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    }
+}
+
+/**
+ * Releases an amount of a payout plan to its recipients, split by their shares.
+ */
+#[post("/payouts_release_post?<chain>", data = "<release>")]
+fn payouts_release_handler(
+    _user: User,
+    release: Form<PayoutRelease>,
+    chain: Option<String>,
+    chains: State<ChainRegistry>,
+) -> Result<Flash<Redirect>, Flash<Redirect>> {
+    let client = match chains.select(chain.as_deref()) {
+        Ok(client) => client,
+        Err(err) => return Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    };
+
+    let txid = client
+        .payments()
+        .payments_release(release.createtxid.to_string(), release.amount, None)
+        .and_then(|reply| reply.into_result())
+        .and_then(|transaction| transaction.broadcast(client));
+    match txid {
+        Ok(txid) => Ok(Flash::success(
+            Redirect::to(uri!(index)),
+            format!("payout released in {}", txid),
+        )),
+        Err(err) => Err(Flash::error(Redirect::to(uri!(index)), err.to_string())),
+    }
+}
+
+/**
+ * Reports the progress of a shielded send (the opid returned by z_sendmany) as
+ * JSON, for a page to poll: {"opid": ..., "status": "executing"} while it runs,
+ * then {"opid": ..., "status": "success", "txid": ...} or
+ * {"opid": ..., "status": "failed", "error": ...}.
+ */
+#[get("/operation/<opid>?<chain>")]
+fn operation_status(
+    _user: User,
+    opid: String,
+    chain: Option<String>,
+    chains: State<ChainRegistry>,
+) -> content::Json<String> {
+    let state = chains
+        .select(chain.as_deref())
+        .and_then(|client| OperationTracker::new(client, opid.clone()).poll());
+    let body = match state {
+        Ok(OperationState::Pending(status)) => serde_json::json!({ "opid": opid, "status": status }),
+        Ok(OperationState::Done(OperationOutcome::Success { txid })) => {
+            serde_json::json!({ "opid": opid, "status": "success", "txid": txid })
+        }
+        Ok(OperationState::Done(OperationOutcome::Failure { message })) => {
+            serde_json::json!({ "opid": opid, "status": "failed", "error": message })
+        }
+        Err(err) => serde_json::json!({ "opid": opid, "status": "error", "error": err.to_string() }),
+    };
+    content::Json(body.to_string())
+}
+
 #[post("/logout")]
 fn logout(mut cookies: Cookies) -> Flash<Redirect> {
     cookies.remove_private(Cookie::named("user_id"));
@@ -205,161 +420,89 @@ fn send_page() -> Template {
     Template::render("send_money_page", &context)
 }
 
-#[get("/")]
-fn user_index(user: User) -> Template {
-    let someAddress = String::from("127.0.0.1");
-    let somePortNum = 13211;
-    let someReqMethod = String::from("POST");
-    let someUserName = String::from("user629608817");
-    let somePassword =
-        String::from("pass6375b5f55e1b166f744d7c66b66354217a6d36645d1de5302c8780bca9600cce49");
-    let someJSONRPCVer = String::from("1.0");
-    let someRPCReqID = String::from("curltest");
-
-    // CHECK TO SEE IF THERE IS USERNAME IS EMPTY???? TODO
-
-    // Create KomodoRPC type (an 'object') with variables
-    let someUser = KomodoRPC::new(
-        someAddress,
-        somePortNum,
-        someReqMethod,
-        someUserName,
-        somePassword,
-        someJSONRPCVer,
-        someRPCReqID,
-    );
+// Template keys filled from the getinfo fields of the same name.
+const CONTROL_KEYS: [&str; 13] = [
+    "name",
+    "p2pport",
+    "rpcport",
+    "balance",
+    "premine",
+    "version",
+    "protocolversion",
+    "walletversion",
+    "blocks",
+    "connections",
+    "difficulty",
+    "paytxfee",
+    "relayfee",
+];
+
+// Template keys filled from listaddressgroupings, in display order.
+const GROUPING_KEYS: [(&str, &str); 3] = [
+    ("address1", "balance1"),
+    ("address2", "balance2"),
+    ("address3", "balance3"),
+];
+
+// Template keys filled from listtransactions, one row per transaction.
+const HISTORY_KEYS: [[&str; 4]; 5] = [
+    ["history_0_address", "history_0_amount", "history_0_category", "history_0_txid"],
+    ["history_1_address", "history_1_amount", "history_1_category", "history_1_txid"],
+    ["history_2_address", "history_2_amount", "history_2_category", "history_2_txid"],
+    ["history_3_address", "history_3_amount", "history_3_category", "history_3_txid"],
+    ["history_4_address", "history_4_amount", "history_4_category", "history_4_txid"],
+];
+
+#[get("/?<chain>")]
+fn user_index(user: User, chain: Option<String>, chains: State<ChainRegistry>) -> Template {
     let mut context = HashMap::new();
-    let requested_amount = komodo::wallet::get_balance(someUser.clone(), None, None).unwrap();
-    let json = Json::from_str(&requested_amount).unwrap();
-    context.insert("amount", json.find_path(&["result"]).unwrap().to_string());
-
-    let control_info = komodo::control::get_info(someUser.clone()).unwrap();
-    let control_result = Json::from_str(&control_info).unwrap();
-    let control_json =
-        Json::from_str(&control_result.find_path(&["result"]).unwrap().to_string()).unwrap();
-
-    context.insert(
-        "name",
-        control_json.find_path(&["name"]).unwrap().to_string(),
-    );
-    context.insert(
-        "p2pport",
-        control_json.find_path(&["p2pport"]).unwrap().to_string(),
-    );
-
-    context.insert(
-        "rpcport",
-        control_json.find_path(&["rpcport"]).unwrap().to_string(),
-    );
-
-    context.insert(
-        "balance",
-        control_json.find_path(&["balance"]).unwrap().to_string(),
-    );
-    context.insert(
-        "premine",
-        control_json.find_path(&["premine"]).unwrap().to_string(),
-    );
-    context.insert(
-        "version",
-        control_json.find_path(&["version"]).unwrap().to_string(),
-    );
+    let chain = chain.or_else(|| chains.default_chain().map(String::from));
+    context.insert("chain", chain.clone().unwrap_or_default());
+    let client = match chains.select(chain.as_deref()) {
+        Ok(client) => client,
+        Err(_) => return Template::render("home_page", &context),
+    };
 
-    context.insert(
-        "protocolversion",
-        control_json
-            .find_path(&["protocolversion"])
-            .unwrap()
-            .to_string(),
-    );
+    // the whole dashboard is loaded with one round trip to the daemon
+    let batch = client.batch();
+    let balance = batch.wallet().get_balance(None, None);
+    let control_info = batch.control().get_info();
+    let groupings = batch.wallet().list_address_groupings();
+    let history = batch.wallet().list_transactions(None, None, None, None);
+    let reply = match batch.send() {
+        Ok(reply) => reply,
+        Err(_) => return Template::render("home_page", &context),
+    };
 
-    context.insert(
-        "walletversion",
-        control_json
-            .find_path(&["walletversion"])
-            .unwrap()
-            .to_string(),
-    );
-    context.insert(
-        "blocks",
-        control_json.find_path(&["blocks"]).unwrap().to_string(),
-    );
-    context.insert(
-        "connections",
-        control_json
-            .find_path(&["connections"])
-            .unwrap()
-            .to_string(),
-    );
-    context.insert(
-        "difficulty",
-        control_json.find_path(&["difficulty"]).unwrap().to_string(),
-    );
-    context.insert(
-        "paytxfee",
-        control_json.find_path(&["paytxfee"]).unwrap().to_string(),
-    );
-    context.insert(
-        "relayfee",
-        control_json.find_path(&["relayfee"]).unwrap().to_string(),
-    );
+    if let Ok(amount) = reply.get(balance) {
+        context.insert("amount", amount.to_string());
+    }
 
-    let something = komodo::wallet::list_address_groupings(someUser.clone()).unwrap();
-    let res = serde_json::from_str(&something);
-    if res.is_ok() {
-        let p: JsonValue = res.unwrap();
-        context.insert("address1", p["result"][0][0][0].to_string());
-        context.insert("balance1", p["result"][0][0][1].to_string());
+    if let Ok(control_info) = reply.get(control_info) {
+        for &key in CONTROL_KEYS.iter() {
+            context.insert(key, control_info[key].to_string());
+        }
+    }
 
-        context.insert("address2", p["result"][1][0][0].to_string());
-        context.insert("balance2", p["result"][1][0][1].to_string());
+    if let Ok(groupings) = reply.get(groupings) {
+        let entries = groupings.iter().flatten();
+        for (&(address_key, balance_key), entry) in GROUPING_KEYS.iter().zip(entries) {
+            context.insert(address_key, entry.address.clone());
+            context.insert(balance_key, entry.amount.to_string());
+        }
+    }
 
-        context.insert("address3", p["result"][0][1][0].to_string());
-        context.insert("balance3", p["result"][0][1][1].to_string());
+    //template to add: a getinfo field is shown by adding its name to CONTROL_KEYS
 
-        println!(
-            "{:?} \t\t\t {:?}\n",
-            p["result"][2][0][0].as_str(),
-            p["result"][2][0][1].as_f64()
-        );
+    if let Ok(history) = reply.get(history) {
+        for (keys, entry) in HISTORY_KEYS.iter().zip(history.iter()) {
+            context.insert(keys[0], entry.address.clone().unwrap_or_default());
+            context.insert(keys[1], entry.amount.to_string());
+            context.insert(keys[2], entry.category.clone());
+            context.insert(keys[3], entry.txid.clone().unwrap_or_default());
+        }
     }
 
-    //template to add
-    //context.insert("variable_name",control_json.find_path(&["json_variable_name"]).unwrap().to_string(
-
-	let requested_amount = komodo::wallet::list_transactions(someUser.clone(), None, None, None, None).unwrap();
-   	 let json: Value = serde_json::from_str(&requested_amount).unwrap();
-	
-	context.insert("history_0_address", json["result"][0]["address"].to_string());
-    context.insert("history_0_amount", json["result"][0]["amount"].to_string());
-    context.insert("history_0_category", json["result"][0]["category"].to_string());
-    context.insert("history_0_txid", json["result"][0]["txid"].to_string());
-
-    
-	context.insert("history_1_address", json["result"][1]["address"].to_string());
-    context.insert("history_1_amount", json["result"][1]["amount"].to_string());
-    context.insert("history_1_category", json["result"][1]["category"].to_string());
-    context.insert("history_1_txid", json["result"][1]["txid"].to_string());
-
-    
-	context.insert("history_2_address", json["result"][2]["address"].to_string());
-    context.insert("history_2_amount", json["result"][2]["amount"].to_string());
-    context.insert("history_2_category", json["result"][2]["category"].to_string());
-    context.insert("history_2_txid", json["result"][2]["txid"].to_string());
-    
-    context.insert("history_3_address",  json["result"][3]["address"].to_string());
-    context.insert("history_3_amount",   json["result"][3]["amount"].to_string());
-    context.insert("history_3_category", json["result"][3]["category"].to_string());
-   	context.insert("history_3_txid",     json["result"][3]["txid"].to_string());
-
-
-    
-    context.insert("history_4_address",  json["result"][4]["address"].to_string());
-    context.insert("history_4_amount",   json["result"][4]["amount"].to_string());
-    context.insert("history_4_category", json["result"][4]["category"].to_string());
-    context.insert("history_4_txid",     json["result"][4]["txid"].to_string());
-       
-    
     Template::render("home_page", &context)
 }
 
@@ -369,22 +512,97 @@ fn index() -> Template {
     Template::render("landing_page", &context)
 }
 
+/**
+ * Builds the KomodoRPC of the default chain. The settings are read
+ * from the chain's komodod .conf file, then overridden by the KOMODO_*
+ * environment variables and last by the komodo_* keys of the Rocket config:
+ * komodo_conf, komodo_rpcuser,
+ * komodo_rpcpassword, komodo_rpcport and komodo_rpcbind.
+ */
+fn komodo_rpc(config: &Config, chain: &str) -> std::result::Result<KomodoRPC, KomodoError> {
+    let mut rpc = match config.get_str("komodo_conf") {
+        Ok(path) => KomodoRPC::from_conf(path)?,
+        Err(_) => match komodorpcutil::conf_path(chain) {
+            Some(ref path) if path.exists() => KomodoRPC::from_conf(path)?,
+            _ => KomodoRPC::default(),
+        },
+    };
+    rpc.apply_env()?;
+    rpc.apply_settings(|key| {
+        let name = format!("komodo_{}", key);
+        match config.get_int(&name) {
+            Ok(number) => Some(number.to_string()),
+            Err(_) => config.get_str(&name).ok().map(String::from),
+        }
+    })?;
+    Ok(rpc)
+}
+
+/**
+ * Reads the address version bytes of the default chain from the komodo_pubkey_prefix
+ * and komodo_script_prefix keys of the Rocket config, for a smart chain launched
+ * with its own. The bytes that are not set are those of KMD.
+ */
+fn address_params(config: &Config) -> std::result::Result<AddressParams, KomodoError> {
+    let prefix = |key: &str, default: u8| match config.get_int(key) {
+        Ok(number) if (0..=255).contains(&number) => Ok(number as u8),
+        Ok(number) => Err(KomodoError::Config(format!("{} {} is not a byte", key, number))),
+        Err(_) => Ok(default),
+    };
+    Ok(AddressParams {
+        pubkey_prefix: prefix("komodo_pubkey_prefix", AddressParams::KOMODO.pubkey_prefix)?,
+        script_prefix: prefix("komodo_script_prefix", AddressParams::KOMODO.script_prefix)?,
+        ..AddressParams::KOMODO
+    })
+}
+
+/**
+ * Builds the registry of the chains the app can talk to: every chain found in
+ * the komodod data directory, plus the default chain named by the komodo_chain
+ * key of the Rocket config (default KENNYCOIN), configured by komodo_rpc() and
+ * address_params().
+ * Routes select a chain with the chain query parameter, e.g. /?chain=KMD.
+ */
+fn chain_registry(config: &Config) -> std::result::Result<ChainRegistry, KomodoError> {
+    let chain = config.get_str("komodo_chain").unwrap_or("KENNYCOIN");
+    let mut chains = ChainRegistry::discover();
+    chains.insert(chain, komodo_rpc(config, chain)?);
+    chains.set_address_params(chain, address_params(config)?)?;
+    chains.set_default(chain)?;
+    Ok(chains)
+}
+
 fn rocket() -> rocket::Rocket {
-    rocket::ignite().attach(Template::fairing()).mount(
-        "/",
-        routes![
-            index,
-            user_index,
-            login,
-            logout,
-            login_user,
-            login_page,
-            send_page,
-            send_money_handler,
-            signup,
-            signup_database
-        ],
-    )
+    rocket::ignite()
+        .attach(Template::fairing())
+        .attach(AdHoc::on_attach("Komodo RPC", |rocket| {
+            match chain_registry(rocket.config()) {
+                Ok(chains) => Ok(rocket.manage(chains)),
+                Err(err) => {
+                    eprintln!("the Komodo RPC is not configured: {}", err);
+                    Err(rocket)
+                }
+            }
+        }))
+        .mount(
+            "/",
+            routes![
+                index,
+                user_index,
+                login,
+                logout,
+                login_user,
+                login_page,
+                send_page,
+                send_money_handler,
+                send_many_handler,
+                payouts_schedule_handler,
+                payouts_release_handler,
+                operation_status,
+                signup,
+                signup_database
+            ],
+        )
 }
 
 fn main() {
@@ -0,0 +1,486 @@
+//!
+//! An in-process stand-in for komodod, used by the tests of the komodo modules.
+//!
+//! MockKomodod listens on a free local port and answers JSON-RPC requests,
+//! single or batched, from canned fixtures keyed by method name. Every request
+//! body is recorded, so a test can check both how a wrapper encodes its
+//! parameters and how it parses the reply:
+//! ```
+//! let daemon = MockKomodod::start();
+//! let balance = daemon.client().wallet().get_balance(Some(6), None).unwrap();
+//! assert_eq!(balance, Amount::from_sat(1_050_000_000));
+//! assert_eq!(daemon.last_params("getbalance"), json!(["", 6, false]));
+//! ```
+//!
+
+use super::komodorpcutil::{KomodoClient, KomodoRPC};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// RPC username of MockKomodod::rpc(); the mock accepts any basic credentials.
+pub const MOCK_RPC_USER: &str = "mockuser";
+
+/// A canned answer of the mock daemon.
+#[derive(Debug, Clone)]
+pub enum Fixture {
+    /// Answer with this value as "result"
+    Result(Value),
+    /// Answer with a JSON-RPC error object and HTTP 500, as komodod does
+    Error { code: i64, message: String },
+}
+
+/**
+ * MockKomodod is a local HTTP JSON-RPC server that answers like komodod.
+ * It is shut down when dropped.
+ */
+pub struct MockKomodod {
+    port: u16,
+    fixtures: Arc<Mutex<HashMap<String, Fixture>>>,
+    requests: Arc<Mutex<Vec<Value>>>,
+    connections: Arc<AtomicUsize>,
+    reversed: Arc<AtomicBool>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl MockKomodod {
+    /**
+     *Function Name: start
+     *@params: none
+     *Output: a running mock daemon answering from default_fixtures()
+     */
+    pub fn start() -> MockKomodod {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").expect("mock komodod can bind a local port");
+        let daemon = MockKomodod {
+            port: listener.local_addr().unwrap().port(),
+            fixtures: Arc::new(Mutex::new(default_fixtures())),
+            requests: Arc::new(Mutex::new(Vec::new())),
+            connections: Arc::new(AtomicUsize::new(0)),
+            reversed: Arc::new(AtomicBool::new(false)),
+            stopped: Arc::new(AtomicBool::new(false)),
+        };
+        let fixtures = daemon.fixtures.clone();
+        let requests = daemon.requests.clone();
+        let connections = daemon.connections.clone();
+        let reversed = daemon.reversed.clone();
+        let stopped = daemon.stopped.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    connections.fetch_add(1, Ordering::SeqCst);
+                    let reversed = reversed.load(Ordering::SeqCst);
+                    let _ = serve(stream, &fixtures, &requests, reversed);
+                }
+            }
+        });
+        daemon
+    }
+
+    /**
+    *Function Name: set_result
+    *@params: method - the RPC method, e.g. getbalance
+                result - the value returned as "result" from now on
+    *Output: none
+    */
+    pub fn set_result(&self, method: &str, result: Value) {
+        self.fixtures
+            .lock()
+            .unwrap()
+            .insert(method.to_string(), Fixture::Result(result));
+    }
+
+    /**
+    *Function Name: set_error
+    *@params: method - the RPC method, e.g. sendtoaddress
+                code, message - the JSON-RPC error returned from now on
+    *Output: none
+    */
+    pub fn set_error(&self, method: &str, code: i64, message: &str) {
+        self.fixtures.lock().unwrap().insert(
+            method.to_string(),
+            Fixture::Error {
+                code,
+                message: message.to_string(),
+            },
+        );
+    }
+
+    /**
+     *Function Name: reverse_batch_replies
+     *@params: the instance of the struct MockKomodod
+     *Output: none; from now on the replies of a batch are sent last call first,
+     *           which JSON-RPC allows, so that clients must match them by id
+     */
+    pub fn reverse_batch_replies(&self) {
+        self.reversed.store(true, Ordering::SeqCst);
+    }
+
+    /**
+     *Function Name: rpc
+     *@params: the instance of the struct MockKomodod
+     *Output: a KomodoRPC configured for the mock daemon
+     */
+    pub fn rpc(&self) -> KomodoRPC {
+        KomodoRPC::new(
+            String::from("127.0.0.1"),
+            self.port as i32,
+            String::from("POST"),
+            String::from(MOCK_RPC_USER),
+            String::from("mockpassword"),
+            String::from("1.0"),
+            String::from("mocktest"),
+        )
+    }
+
+    /**
+     *Function Name: client
+     *@params: the instance of the struct MockKomodod
+     *Output: a KomodoClient talking to the mock daemon
+     */
+    pub fn client(&self) -> KomodoClient {
+        KomodoClient::new(self.rpc())
+    }
+
+    /**
+     *Function Name: requests
+     *@params: the instance of the struct MockKomodod
+     *Output: every request object received so far, in order; the calls of a batch
+     *           are recorded one by one
+     */
+    pub fn requests(&self) -> Vec<Value> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /**
+     *Function Name: connections
+     *@params: the instance of the struct MockKomodod
+     *Output: the number of HTTP connections accepted so far; each carries one request
+     */
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /**
+     *Function Name: last_params
+     *@params: method - the RPC method
+     *Output: the "params" of the last request for the method
+     */
+    pub fn last_params(&self, method: &str) -> Value {
+        self.requests()
+            .into_iter()
+            .rev()
+            .find(|request| request["method"] == method)
+            .map(|request| request["params"].clone())
+            .unwrap_or_else(|| panic!("mock komodod received no {} request", method))
+    }
+}
+
+impl Drop for MockKomodod {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // wake the accept loop so that it sees the flag
+        let _ = TcpStream::connect(("127.0.0.1", self.port));
+    }
+}
+
+/**
+*Function Name: default_fixtures
+*@params: none
+*Output: replies for the methods used by the web app, shaped like those of komodod
+*/
+pub fn default_fixtures() -> HashMap<String, Fixture> {
+    let results = vec![
+        ("getbalance", json!(10.5)),
+        ("getblockcount", json!(1201)),
+        ("getbestblockhash", json!(MOCK_BLOCK_HASH)),
+        (
+            "getinfo",
+            json!({
+                "version": 3000300,
+                "protocolversion": 170009,
+                "KMDversion": "0.5.0",
+                "notarized": 0,
+                "walletversion": 60000,
+                "balance": 10.5,
+                "blocks": 1201,
+                "longestchain": 1201,
+                "connections": 2,
+                "difficulty": 1.000002,
+                "testnet": false,
+                "paytxfee": 0.0,
+                "relayfee": 0.000001,
+                "errors": "",
+                "name": "KENNYCOIN",
+                "p2pport": 13210,
+                "rpcport": 13211,
+                "premine": 1000000
+            }),
+        ),
+        (
+            "getwalletinfo",
+            json!({
+                "walletversion": 60000,
+                "balance": 10.5,
+                "unconfirmed_balance": 0.0,
+                "immature_balance": 0.0,
+                "txcount": 2,
+                "keypoololdest": 1585000000,
+                "keypoolsize": 101,
+                "paytxfee": 0.0
+            }),
+        ),
+        ("sendtoaddress", json!(MOCK_TXID)),
+        (
+            "listtransactions",
+            json!([
+                {
+                    "account": "",
+                    "address": "RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4",
+                    "category": "receive",
+                    "amount": 12.0,
+                    "vout": 0,
+                    "confirmations": 20,
+                    "rawconfirmations": 20,
+                    "blockhash": MOCK_BLOCK_HASH,
+                    "blockindex": 1,
+                    "blocktime": 1585000100,
+                    "txid": MOCK_TXID,
+                    "time": 1585000090,
+                    "timereceived": 1585000090,
+                    "size": 226
+                },
+                {
+                    "account": "",
+                    "address": "RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp",
+                    "category": "send",
+                    "amount": -1.5,
+                    "vout": 1,
+                    "fee": -0.0001,
+                    "confirmations": 3,
+                    "txid": MOCK_TXID,
+                    "time": 1585000200,
+                    "timereceived": 1585000200,
+                    "size": 225
+                }
+            ]),
+        ),
+        (
+            "listaddressgroupings",
+            json!([
+                [["RBQ1XwmzduHvciRJbXbWY9YBSNtaqZvfC4", 10.5, ""]],
+                [["RXEXoa1nRmKhMbuZovpcYwQMsicwzccZBp", 0.0]]
+            ]),
+        ),
+        (
+            "getblock",
+            json!({
+                "hash": MOCK_BLOCK_HASH,
+                "confirmations": 2,
+                "rawconfirmations": 2,
+                "size": 1770,
+                "height": 1200,
+                "version": 4,
+                "merkleroot": "d9e0d8ae4fd8af3d0b2ae5da4e2c8d1d7b0cf9bb7f4bfc9e1ff72c1c47d23d1f",
+                "tx": [MOCK_TXID],
+                "time": 1585000100,
+                "nonce": "0000d6d31d3b2c0000000000000000000000000000000000000000000000000b",
+                "bits": "200f0f0f",
+                "difficulty": 1.000002,
+                "chainwork": "00000000000000000000000000000000000000000000000000000000000004b1",
+                "previousblockhash": "027e3758c3a65b12aa1046462b486d0a63bfa1beae327897f56c5cfb7daaae71"
+            }),
+        ),
+        ("gettxout", Value::Null),
+    ];
+    results
+        .into_iter()
+        .map(|(method, result)| (method.to_string(), Fixture::Result(result)))
+        .collect()
+}
+
+/// Hash of the block in the default fixtures.
+pub const MOCK_BLOCK_HASH: &str =
+    "0a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f809";
+
+/// Transaction id used by the default fixtures.
+pub const MOCK_TXID: &str = "c5c0b1b9e1f2f0e5a8c4d3b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a392";
+
+/**
+*Function Name: serve
+*@params: stream - one HTTP connection
+            fixtures - the canned answers by method
+            requests - where the received request objects are recorded
+            reversed - whether the replies of a batch are sent in reverse order
+*Output: none; answers one request and closes the connection
+*/
+fn serve(
+    stream: TcpStream,
+    fixtures: &Mutex<HashMap<String, Fixture>>,
+    requests: &Mutex<Vec<Value>>,
+    reversed: bool,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut content_length = 0;
+    let mut authorized = false;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line == "\r\n" {
+            break;
+        }
+        let lower = line.to_lowercase();
+        if let Some(length) = lower.strip_prefix("content-length:") {
+            content_length = length.trim().parse().unwrap_or(0);
+        }
+        if lower.starts_with("authorization: basic") {
+            authorized = true;
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (status, reply) = if !authorized {
+        ("401 Unauthorized", String::new())
+    } else {
+        match serde_json::from_slice::<Value>(&body) {
+            Ok(Value::Array(calls)) => {
+                let mut replies: Vec<Value> = calls
+                    .into_iter()
+                    .map(|call| answer(call, fixtures, requests).1)
+                    .collect();
+                if reversed {
+                    replies.reverse();
+                }
+                ("200 OK", Value::Array(replies).to_string())
+            }
+            Ok(call) => {
+                let (status, reply) = answer(call, fixtures, requests);
+                (status, reply.to_string())
+            }
+            Err(_) => ("400 Bad Request", String::new()),
+        }
+    };
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reply.len(),
+        reply
+    )?;
+    stream.flush()
+}
+
+/**
+*Function Name: answer
+*@params: call - one JSON-RPC request object
+*Output: the HTTP status komodod would use and the reply object for the call
+*/
+fn answer(
+    call: Value,
+    fixtures: &Mutex<HashMap<String, Fixture>>,
+    requests: &Mutex<Vec<Value>>,
+) -> (&'static str, Value) {
+    let id = call["id"].clone();
+    let method = call["method"].as_str().unwrap_or("").to_string();
+    requests.lock().unwrap().push(call);
+    match fixtures.lock().unwrap().get(&method) {
+        Some(Fixture::Result(result)) => (
+            "200 OK",
+            json!({ "result": result, "error": null, "id": id }),
+        ),
+        Some(Fixture::Error { code, message }) => (
+            "500 Internal Server Error",
+            json!({ "result": null, "error": { "code": code, "message": message }, "id": id }),
+        ),
+        None => (
+            "404 Not Found",
+            json!({ "result": null, "error": { "code": -32601, "message": "Method not found" }, "id": id }),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::komodo::{Amount, KomodoApi};
+    use crate::komodorpcutil::KomodoError;
+
+    #[test]
+    fn records_request_bodies() {
+        let daemon = MockKomodod::start();
+        let count = daemon.client().blockchain().get_block_count().unwrap();
+        assert_eq!(count, json!(1201));
+        let requests = daemon.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["method"], "getblockcount");
+        assert_eq!(requests[0]["id"], "mocktest");
+        assert_eq!(requests[0]["params"], json!([]));
+    }
+
+    #[test]
+    fn unknown_method_is_an_rpc_error() {
+        let daemon = MockKomodod::start();
+        match daemon.client().network().get_connection_count() {
+            Err(KomodoError::Rpc { code, .. }) => assert_eq!(code, -32601),
+            other => panic!("expected an RPC error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn error_fixture_is_an_rpc_error() {
+        let daemon = MockKomodod::start();
+        daemon.set_error("getbalance", -4, "Insufficient funds");
+        match daemon.client().wallet().get_balance(None, None) {
+            Err(KomodoError::Rpc { code, message }) => {
+                assert_eq!(code, -4);
+                assert_eq!(message, "Insufficient funds");
+            }
+            other => panic!("expected an RPC error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn batch_calls_share_one_request() {
+        let daemon = MockKomodod::start();
+        let client = daemon.client();
+        let batch = client.batch();
+        let balance = batch.wallet().get_balance(None, None);
+        let info = batch.control().get_info();
+        let reply = batch.send().unwrap();
+        assert_eq!(reply.get(balance).unwrap(), Amount::from_sat(1_050_000_000));
+        assert_eq!(reply.get(info).unwrap()["name"], "KENNYCOIN");
+        assert_eq!(daemon.requests().len(), 2);
+        assert_eq!(daemon.connections(), 1);
+    }
+
+    #[test]
+    fn batch_replies_are_matched_by_id() {
+        let daemon = MockKomodod::start();
+        daemon.reverse_batch_replies();
+        daemon.set_error("getblockcount", -8, "Block height out of range");
+        let client = daemon.client();
+        let batch = client.batch();
+        let balance = batch.wallet().get_balance(None, None);
+        let count = batch.blockchain().get_block_count();
+        let info = batch.control().get_info();
+        let reply = batch.send().unwrap();
+        assert_eq!(daemon.connections(), 1);
+        assert_eq!(reply.get(info).unwrap()["name"], "KENNYCOIN");
+        match reply.get(count) {
+            Err(KomodoError::Rpc { code, message }) => {
+                assert_eq!(code, -8);
+                assert_eq!(message, "Block height out of range");
+            }
+            other => panic!("expected an RPC error, got {:?}", other),
+        }
+        assert_eq!(reply.get(balance).unwrap(), Amount::from_sat(1_050_000_000));
+    }
+}
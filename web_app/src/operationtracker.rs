@@ -0,0 +1,275 @@
+use super::komodo::wallet::OperationStatus;
+use super::komodo::KomodoApi;
+use super::komodorpcutil::{KomodoClient, KomodoError};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/**
+ * OperationTracker follows an asynchronous z_ operation, such as the opid
+ * returned by z_sendmany or z_shieldcoinbase, with z_getoperationstatus until
+ * the daemon reports a terminal state. The operation stays in the daemon's
+ * memory, so several trackers (or page reloads) may follow the same opid.
+ *
+ * let opid = client.wallet().z_send_many(from_address, recipients, None, None)?;
+ * match OperationTracker::new(&client, opid).timeout(Duration::from_secs(300)).wait()? {
+ *     OperationOutcome::Success { txid } => println!("sent in {}", txid),
+ *     OperationOutcome::Failure { message } => println!("not sent: {}", message),
+ * }
+ */
+pub struct OperationTracker<'a> {
+    client: &'a KomodoClient,
+    opid: String,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+    cancel: CancelHandle,
+}
+
+/// How a z_ operation ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationOutcome {
+    /// the operation succeeded and created the transaction with this id
+    Success { txid: String },
+    /// the operation failed or was cancelled by the daemon
+    Failure { message: String },
+}
+
+/// The state of a z_ operation at the time it was polled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationState {
+    /// the operation is still queued or executing; holds the daemon's status
+    Pending(String),
+    /// the operation reached a terminal state
+    Done(OperationOutcome),
+}
+
+/// Stops OperationTracker::wait from another thread. Clones share the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancelHandle(Arc<AtomicBool>);
+
+impl CancelHandle {
+    /// The cancel method makes the tracker's wait return OperationCancelled at its next poll.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// The is_cancelled method tells whether cancel was called.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl From<OperationStatus> for OperationState {
+    fn from(status: OperationStatus) -> Self {
+        match status.status.as_str() {
+            "success" => OperationState::Done(match status.result {
+                Some(result) => OperationOutcome::Success { txid: result.txid },
+                None => OperationOutcome::Failure {
+                    message: String::from("the operation succeeded without a txid"),
+                },
+            }),
+            "failed" => OperationState::Done(OperationOutcome::Failure {
+                message: status
+                    .error
+                    .map(|error| error.message)
+                    .unwrap_or_else(|| String::from("the operation failed")),
+            }),
+            "cancelled" => OperationState::Done(OperationOutcome::Failure {
+                message: String::from("the operation was cancelled"),
+            }),
+            _ => OperationState::Pending(status.status),
+        }
+    }
+}
+
+impl<'a> OperationTracker<'a> {
+    /**
+     *Function Name: new
+     *@params: client - the client of the daemon running the operation
+     *            opid - the operation id
+     *Output: a tracker polling once a second without a timeout
+     */
+    pub fn new(client: &'a KomodoClient, opid: String) -> OperationTracker<'a> {
+        OperationTracker {
+            client,
+            opid,
+            poll_interval: Duration::from_secs(1),
+            timeout: None,
+            cancel: CancelHandle::default(),
+        }
+    }
+
+    /// The poll_interval method sets the time wait sleeps between two polls.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The timeout method sets how long wait follows the operation before giving up.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The cancel_handle method returns a handle that stops wait, e.g. from another thread.
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+
+    /// The opid method returns the id of the followed operation.
+    pub fn opid(&self) -> &str {
+        &self.opid
+    }
+
+    /**
+     *Function Name: status
+     *@params: the instance of the struct OperationTracker
+     *Output: the status of the operation as reported by z_getoperationstatus
+     *           KomodoError::UnknownOperation - when the daemon does not know the opid
+     */
+    pub fn status(&self) -> Result<OperationStatus, KomodoError> {
+        self.client
+            .wallet()
+            .z_get_operation_status(Some(self.opid.clone()))?
+            .into_iter()
+            .find(|status| status.id == self.opid)
+            .ok_or_else(|| KomodoError::UnknownOperation(self.opid.clone()))
+    }
+
+    /**
+     *Function Name: poll
+     *@params: the instance of the struct OperationTracker
+     *Output: the state of the operation after one z_getoperationstatus call;
+     *           it does not wait, so it suits a page that refreshes the progress
+     */
+    pub fn poll(&self) -> Result<OperationState, KomodoError> {
+        self.status().map(OperationState::from)
+    }
+
+    /**
+     *Function Name: wait
+     *@params: the instance of the struct OperationTracker
+     *Output: the outcome of the operation, once the daemon reports it finished
+     *           KomodoError::OperationTimeout - when the timeout passes first
+     *           KomodoError::OperationCancelled - when the cancel handle is used first
+     */
+    pub fn wait(&self) -> Result<OperationOutcome, KomodoError> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            if self.cancel.is_cancelled() {
+                return Err(KomodoError::OperationCancelled(self.opid.clone()));
+            }
+            if let OperationState::Done(outcome) = self.poll()? {
+                return Ok(outcome);
+            }
+            let mut sleep = self.poll_interval;
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(KomodoError::OperationTimeout(self.opid.clone()));
+                }
+                sleep = sleep.min(deadline - now);
+            }
+            thread::sleep(sleep);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mockkomodod::{MockKomodod, MOCK_TXID};
+    use serde_json::json;
+
+    const OPID: &str = "opid-0f3c1a52-7d2e-4b8f-9a61-5c2d8e4f7b10";
+
+    fn set_status(daemon: &MockKomodod, status: serde_json::Value) {
+        daemon.set_result("z_getoperationstatus", json!([status]));
+    }
+
+    #[test]
+    fn wait_returns_the_txid_of_a_successful_operation() {
+        let daemon = MockKomodod::start();
+        set_status(
+            &daemon,
+            json!({
+                "id": OPID,
+                "status": "success",
+                "creation_time": 1585000300,
+                "result": { "txid": MOCK_TXID },
+                "execution_secs": 12.5,
+                "method": "z_sendmany"
+            }),
+        );
+        let client = daemon.client();
+        let outcome = OperationTracker::new(&client, OPID.to_string())
+            .wait()
+            .unwrap();
+        assert_eq!(
+            outcome,
+            OperationOutcome::Success {
+                txid: MOCK_TXID.to_string()
+            }
+        );
+        assert_eq!(daemon.last_params("z_getoperationstatus"), json!([[OPID]]));
+    }
+
+    #[test]
+    fn failed_operation_carries_the_error_message() {
+        let daemon = MockKomodod::start();
+        set_status(
+            &daemon,
+            json!({
+                "id": OPID,
+                "status": "failed",
+                "creation_time": 1585000300,
+                "error": { "code": -6, "message": "Insufficient funds" }
+            }),
+        );
+        let client = daemon.client();
+        let state = OperationTracker::new(&client, OPID.to_string())
+            .poll()
+            .unwrap();
+        assert_eq!(
+            state,
+            OperationState::Done(OperationOutcome::Failure {
+                message: String::from("Insufficient funds")
+            })
+        );
+    }
+
+    #[test]
+    fn wait_stops_at_the_timeout_or_when_cancelled() {
+        let daemon = MockKomodod::start();
+        set_status(
+            &daemon,
+            json!({ "id": OPID, "status": "executing", "creation_time": 1585000300 }),
+        );
+        let client = daemon.client();
+        let tracker = OperationTracker::new(&client, OPID.to_string())
+            .poll_interval(Duration::from_millis(10))
+            .timeout(Duration::from_millis(50));
+        match tracker.wait() {
+            Err(KomodoError::OperationTimeout(opid)) => assert_eq!(opid, OPID),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+
+        tracker.cancel_handle().cancel();
+        match tracker.wait() {
+            Err(KomodoError::OperationCancelled(opid)) => assert_eq!(opid, OPID),
+            other => panic!("expected a cancellation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_opid_is_an_error() {
+        let daemon = MockKomodod::start();
+        daemon.set_result("z_getoperationstatus", json!([]));
+        let client = daemon.client();
+        match OperationTracker::new(&client, OPID.to_string()).poll() {
+            Err(KomodoError::UnknownOperation(opid)) => assert_eq!(opid, OPID),
+            other => panic!("expected an unknown operation, got {:?}", other),
+        }
+    }
+}